@@ -0,0 +1,364 @@
+use std::{
+    collections::VecDeque,
+    fmt::Display,
+    io::Write,
+    process::Stdio,
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local};
+use k8s_openapi::api::core::v1::{ContainerStatus, PodSpec, PodStatus};
+
+use crate::kubernetes::api::object::RtObject;
+
+const MAX_HISTORY: usize = 256;
+const MAX_TOASTS: usize = 3;
+const TOAST_TTL: Duration = Duration::from_secs(5);
+const IMAGE_PULL_REASONS: [&str; 3] = ["ErrImagePull", "ImagePullBackOff", "InvalidImageName"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlertKind {
+    Restarted,
+    OOMKilled,
+    FailedScheduling,
+    ImagePull,
+}
+
+impl Display for AlertKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            AlertKind::Restarted => "Restarted",
+            AlertKind::OOMKilled => "OOMKilled",
+            AlertKind::FailedScheduling => "FailedScheduling",
+            AlertKind::ImagePull => "ImagePullError",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Alert {
+    pub kind: AlertKind,
+    pub namespace: String,
+    pub name: String,
+    pub message: String,
+    pub time: DateTime<Local>,
+}
+
+type PodObject = RtObject<PodSpec, PodStatus>;
+// a rule compares the previous and the current version of a pod
+type Rule = fn(&PodObject, &PodObject) -> Vec<(AlertKind, String)>;
+
+const POD_RULES: [Rule; 4] = [
+    restarted_rule,
+    oom_killed_rule,
+    unschedulable_rule,
+    image_pull_rule,
+];
+
+pub struct Notifier {
+    history: VecDeque<Alert>,
+    toasts: VecDeque<(Instant, Alert)>,
+    // KSRE_ALERT_BELL=1 rings the terminal bell on every alert
+    bell: bool,
+    // KSRE_ALERT_COMMAND is run by `sh -c` with the alert exported as KSRE_ALERT_* env
+    command: Option<String>,
+}
+
+impl Notifier {
+    pub fn from_env() -> Self {
+        Self {
+            history: VecDeque::with_capacity(MAX_HISTORY),
+            toasts: VecDeque::with_capacity(MAX_TOASTS),
+            bell: std::env::var("KSRE_ALERT_BELL")
+                .map(|s| s == "1")
+                .unwrap_or(false),
+            command: std::env::var("KSRE_ALERT_COMMAND")
+                .ok()
+                .filter(|cmd| !cmd.is_empty()),
+        }
+    }
+
+    // only state transitions raise alerts, so pods already broken at startup stay quiet
+    pub fn on_pod_update(&mut self, old: &PodObject, new: &PodObject) {
+        let namespace = new.0.metadata.namespace.as_deref().unwrap_or_default();
+        let name = new.0.metadata.name.as_deref().unwrap_or_default();
+        for rule in POD_RULES.iter() {
+            for (kind, message) in rule(old, new) {
+                self.raise(Alert {
+                    kind,
+                    namespace: namespace.to_string(),
+                    name: name.to_string(),
+                    message,
+                    time: Local::now(),
+                });
+            }
+        }
+    }
+
+    fn raise(&mut self, alert: Alert) {
+        tracing::info!(
+            "alert {} {}/{}: {}",
+            alert.kind,
+            alert.namespace,
+            alert.name,
+            alert.message
+        );
+        if self.bell {
+            let _ = std::io::stderr().write_all(b"\x07");
+        }
+        if let Some(command) = self.command.as_deref() {
+            run_command(command, &alert);
+        }
+
+        if self.history.len() == MAX_HISTORY {
+            self.history.pop_back();
+        }
+        self.history.push_front(alert.clone());
+        if self.toasts.len() == MAX_TOASTS {
+            self.toasts.pop_back();
+        }
+        self.toasts.push_front((Instant::now(), alert));
+    }
+
+    // newest first
+    pub fn history(&self) -> impl Iterator<Item = &Alert> {
+        self.history.iter()
+    }
+
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+        self.toasts.clear();
+    }
+
+    // toasts still on screen, newest first
+    pub fn toasts(&self) -> impl Iterator<Item = &Alert> {
+        self.toasts
+            .iter()
+            .filter(|(created, _)| created.elapsed() < TOAST_TTL)
+            .map(|(_, alert)| alert)
+    }
+}
+
+fn run_command(command: &str, alert: &Alert) {
+    let spawned = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .env("KSRE_ALERT_KIND", alert.kind.to_string())
+        .env("KSRE_ALERT_NAMESPACE", alert.namespace.as_str())
+        .env("KSRE_ALERT_POD", alert.name.as_str())
+        .env("KSRE_ALERT_MESSAGE", alert.message.as_str())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+    if let Err(err) = spawned {
+        tracing::warn!("run alert command failed: {}", err);
+    }
+}
+
+#[inline]
+fn container_statuses(obj: &PodObject) -> &[ContainerStatus] {
+    obj.0
+        .status
+        .as_ref()
+        .and_then(|status| status.container_statuses.as_deref())
+        .unwrap_or_default()
+}
+
+// pairs of (old, new) status of every container present in the new pod
+fn paired_containers<'a>(
+    old: &'a PodObject,
+    new: &'a PodObject,
+) -> impl Iterator<Item = (Option<&'a ContainerStatus>, &'a ContainerStatus)> {
+    let old_containers = container_statuses(old);
+    container_statuses(new).iter().map(move |container| {
+        let previous = old_containers.iter().find(|x| x.name == container.name);
+        (previous, container)
+    })
+}
+
+#[inline]
+fn terminated_reason(container: &ContainerStatus) -> Option<&str> {
+    container
+        .state
+        .as_ref()
+        .and_then(|state| state.terminated.as_ref())
+        .and_then(|terminated| terminated.reason.as_deref())
+}
+
+#[inline]
+fn last_terminated_reason(container: &ContainerStatus) -> Option<&str> {
+    container
+        .last_state
+        .as_ref()
+        .and_then(|state| state.terminated.as_ref())
+        .and_then(|terminated| terminated.reason.as_deref())
+}
+
+#[inline]
+fn waiting_reason(container: &ContainerStatus) -> Option<&str> {
+    container
+        .state
+        .as_ref()
+        .and_then(|state| state.waiting.as_ref())
+        .and_then(|waiting| waiting.reason.as_deref())
+}
+
+fn restarted_rule(old: &PodObject, new: &PodObject) -> Vec<(AlertKind, String)> {
+    paired_containers(old, new)
+        .filter_map(|(previous, container)| {
+            let previous = previous?;
+            // oom restarts are reported by their own rule
+            if container.restart_count > previous.restart_count
+                && last_terminated_reason(container) != Some("OOMKilled")
+            {
+                Some((
+                    AlertKind::Restarted,
+                    format!(
+                        "container {} restarted ({} times), last reason {}",
+                        container.name,
+                        container.restart_count,
+                        last_terminated_reason(container).unwrap_or("<none>")
+                    ),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn oom_killed_rule(old: &PodObject, new: &PodObject) -> Vec<(AlertKind, String)> {
+    paired_containers(old, new)
+        .filter_map(|(previous, container)| {
+            let previous = previous?;
+            let restarted_by_oom = container.restart_count > previous.restart_count
+                && last_terminated_reason(container) == Some("OOMKilled");
+            let terminated_by_oom = terminated_reason(container) == Some("OOMKilled")
+                && terminated_reason(previous) != Some("OOMKilled");
+            if restarted_by_oom || terminated_by_oom {
+                Some((
+                    AlertKind::OOMKilled,
+                    format!("container {} was OOMKilled", container.name),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn unschedulable_rule(old: &PodObject, new: &PodObject) -> Vec<(AlertKind, String)> {
+    let unschedulable = |obj: &PodObject| {
+        obj.0
+            .status
+            .as_ref()
+            .and_then(|status| status.conditions.as_ref())
+            .and_then(|conditions| {
+                conditions.iter().find(|condition| {
+                    condition.type_ == "PodScheduled"
+                        && condition.status == "False"
+                        && condition.reason.as_deref() == Some("Unschedulable")
+                })
+            })
+            .map(|condition| condition.message.clone().unwrap_or_default())
+    };
+    match (unschedulable(old), unschedulable(new)) {
+        (None, Some(message)) => vec![(AlertKind::FailedScheduling, message)],
+        _ => Vec::new(),
+    }
+}
+
+fn image_pull_rule(old: &PodObject, new: &PodObject) -> Vec<(AlertKind, String)> {
+    paired_containers(old, new)
+        .filter_map(|(previous, container)| {
+            let reason = waiting_reason(container)?;
+            let was_failing = previous
+                .and_then(waiting_reason)
+                .map(|x| IMAGE_PULL_REASONS.contains(&x))
+                .unwrap_or(false);
+            if IMAGE_PULL_REASONS.contains(&reason) && !was_failing {
+                Some((
+                    AlertKind::ImagePull,
+                    format!(
+                        "container {} can not pull image {}: {}",
+                        container.name, container.image, reason
+                    ),
+                ))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::core::v1::{
+        ContainerState, ContainerStateTerminated, ContainerStateWaiting, Pod,
+    };
+    use kube::api::ObjectMeta;
+
+    use super::*;
+
+    fn pod_with(container: ContainerStatus) -> PodObject {
+        Pod {
+            metadata: ObjectMeta {
+                name: Some("web-0".to_string()),
+                namespace: Some("default".to_string()),
+                ..Default::default()
+            },
+            spec: Some(PodSpec::default()),
+            status: Some(PodStatus {
+                container_statuses: Some(vec![container]),
+                ..Default::default()
+            }),
+        }
+        .into()
+    }
+
+    fn container(restart_count: i32, last_reason: Option<&str>) -> ContainerStatus {
+        ContainerStatus {
+            name: "web".to_string(),
+            image: "nginx:1.25".to_string(),
+            restart_count,
+            last_state: last_reason.map(|reason| ContainerState {
+                terminated: Some(ContainerStateTerminated {
+                    reason: Some(reason.to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_oom_restart_only_raises_oom() {
+        let old = pod_with(container(0, None));
+        let new = pod_with(container(1, Some("OOMKilled")));
+        assert!(restarted_rule(&old, &new).is_empty());
+        assert_eq!(AlertKind::OOMKilled, oom_killed_rule(&old, &new)[0].0);
+
+        let new = pod_with(container(1, Some("Error")));
+        assert_eq!(AlertKind::Restarted, restarted_rule(&old, &new)[0].0);
+        assert!(oom_killed_rule(&old, &new).is_empty());
+    }
+
+    #[test]
+    fn test_image_pull_alert_on_transition_only() {
+        let mut waiting = container(0, None);
+        waiting.state = Some(ContainerState {
+            waiting: Some(ContainerStateWaiting {
+                reason: Some("ImagePullBackOff".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        let old = pod_with(container(0, None));
+        let new = pod_with(waiting.clone());
+        assert_eq!(1, image_pull_rule(&old, &new).len());
+        assert!(image_pull_rule(&new, &pod_with(waiting)).is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+use std::{
+    io::{IsTerminal, Write},
+    path::PathBuf,
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::Local;
+
+// many terminals drop OSC 52 sequences longer than this, bigger texts go to a file
+const MAX_OSC52: usize = 100_000;
+
+// where copied text ended up, shown in the status line
+pub enum Copied {
+    Clipboard,
+    File(PathBuf),
+}
+
+impl Copied {
+    pub fn message(&self, what: &str) -> String {
+        match self {
+            Copied::Clipboard => format!("copied {} to clipboard", what),
+            Copied::File(path) => format!("{} saved to {}", what, path.display()),
+        }
+    }
+}
+
+// copy asks the terminal to set the system clipboard by OSC 52, it works over ssh too.
+// KSRE_CLIPBOARD=file skips it for terminals that ignore the sequence
+pub fn copy(text: &[u8]) -> std::io::Result<Copied> {
+    let encoded = STANDARD.encode(text);
+    let to_file = std::env::var("KSRE_CLIPBOARD").is_ok_and(|x| x == "file");
+    if to_file || encoded.len() > MAX_OSC52 || !std::io::stderr().is_terminal() {
+        return save(text).map(Copied::File);
+    }
+    match write_osc52(&encoded) {
+        Ok(_) => Ok(Copied::Clipboard),
+        Err(_) => save(text).map(Copied::File),
+    }
+}
+
+fn write_osc52(encoded: &str) -> std::io::Result<()> {
+    let mut stderr = std::io::stderr();
+    // tmux swallows the sequence unless it is wrapped in a passthrough
+    if std::env::var_os("TMUX").is_some() {
+        write!(stderr, "\x1bPtmux;\x1b\x1b]52;c;{}\x07\x1b\\", encoded)?;
+    } else {
+        write!(stderr, "\x1b]52;c;{}\x07", encoded)?;
+    }
+    stderr.flush()
+}
+
+fn save(text: &[u8]) -> std::io::Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!(
+        "ksre-{}.txt",
+        Local::now().format("%Y%m%d-%H%M%S%3f")
+    ));
+    std::fs::write(&path, text)?;
+    Ok(path)
+}
@@ -1,87 +1,268 @@
-use std::rc::Rc;
+use std::{
+    collections::HashMap,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use color_eyre::eyre::Result;
-use k8s_openapi::api::core::v1::{Namespace, PodSpec, PodStatus};
+use k8s_openapi::api::{
+    autoscaling::v2::{HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus},
+    core::v1::{Namespace, NodeSpec, NodeStatus, PodSpec, PodStatus, ServiceSpec, ServiceStatus},
+};
 use kube::{api::ListParams, Api, Client as KubeClient, Resource, ResourceExt};
-use nucleo_matcher::pattern::{Atom, AtomKind, CaseMatching, Normalization};
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, mpsc};
+use tokio_util::sync::CancellationToken;
 
 use crate::event::{CusKey, Event, KubeEvent};
 use crate::kubernetes::{
-    api::{object::RtObject, pod::PodDescribe},
+    api::{
+        autoscale::HpaSample,
+        config::{ConfigData, ConfigKind},
+        deployment::rollout_history,
+        object::RtObject,
+        pod::{is_pod_ready, pod_restarts, PodDescribe},
+    },
+    indexer::StoreIndex,
     metrics::pod::{MetricClient, PodMetrics},
+    owner::{Health, OwnerTree, TreeEntry},
+    reflector::{dynamic::DynamicEvent, ClusterEvents},
 };
 use crate::tui::Tui;
 
 use super::{
-    handler::keybind::{DEPLOYMENT_KEYMAPS, NODE_KEYMAPS, POD_KEYMAPS},
-    state::{AppState, Executor, Mode, TabPage},
+    handler::{
+        config,
+        keybind::{
+            overlay_keymap, tabpage_keymap, FAVORITE_KEYMAPS, GLOBAL_KEYMAPS, INPUT_KEYMAPS,
+        },
+        mouse, resource, search, storage, workload,
+    },
+    job::{cluster_probe, until_cancelled, JobKind},
+    session::{self, Session},
+    state::{AppState, CycledCache, Executor, Mode, Overlay, Route, TabPage},
     ui::home::ui_main,
 };
 
+const METRICS_PERIOD: Duration = Duration::from_secs(15);
+// changes kept per autoscaler, a sample is only added when replicas or a metric changed,
+// so how long this covers depends on how busy the autoscaler is
+const HPA_HISTORY: usize = 120;
+// nothing changed, the screen is still redrawn for ages, toasts and status messages
+const IDLE_REFRESH: Duration = Duration::from_secs(1);
+// views fed by background tasks, e.g. log tail or drain progress
+const LIVE_REFRESH: Duration = Duration::from_millis(100);
+
 pub struct App {
     tui: Tui,
     kube_client: KubeClient,
-    pod_event_rx: broadcast::Receiver<KubeEvent<PodSpec, PodStatus>>,
+    cluster_events: ClusterEvents,
+    // objects of the kind watched in resource tabpage
+    resource_event_rx: broadcast::Receiver<DynamicEvent>,
+    metrics_rx: mpsc::Receiver<Vec<PodMetrics>>,
     app_state: AppState,
-    pod_metrics_api: MetricClient,
+    // the only namespace watched when the user has namespaced rbac
+    watch_namespace: Option<String>,
+    // watch events only mark the caches stale, bursts are folded into one resync per frame
+    stale: bool,
+    // the screen is redrawn on the next tick
+    dirty: bool,
+    last_draw: Instant,
 }
 
 impl App {
     pub fn new(
         tui: Tui,
-        kube_event: broadcast::Receiver<KubeEvent<PodSpec, PodStatus>>,
+        cluster_events: ClusterEvents,
         kube_client: KubeClient,
+        watch_namespace: Option<String>,
+        context: Option<String>,
     ) -> Self {
-        let pod_metrics_api = MetricClient::new(kube_client.clone());
+        let (metrics_tx, metrics_rx) = mpsc::channel(1);
+        let mut app_state = AppState::new(kube_client.clone());
+        if let Some(context) = context {
+            app_state.context = context;
+        }
+        let cancel = CancellationToken::new();
+        let poll = MetricClient::new(kube_client.clone(), watch_namespace.as_deref())
+            .poll(metrics_tx, METRICS_PERIOD);
+        app_state.jobs.spawn(
+            JobKind::Metrics,
+            "metrics-server",
+            cancel.clone(),
+            until_cancelled(cancel, poll),
+        );
+        resource::spawn_discovery(&mut app_state);
+        let cancel = CancellationToken::new();
+        let probe =
+            cluster_probe::probe_cluster(kube_client.clone(), app_state.cluster_info.clone());
+        app_state.jobs.spawn(
+            JobKind::ClusterProbe,
+            "api server",
+            cancel.clone(),
+            until_cancelled(cancel, probe),
+        );
         Self {
             tui,
-            pod_event_rx: kube_event,
-            app_state: AppState::new(kube_client.clone()),
+            cluster_events,
+            resource_event_rx: app_state.resource_tx.subscribe(),
+            metrics_rx,
+            app_state,
             kube_client,
-            pod_metrics_api,
+            watch_namespace,
+            stale: true,
+            dirty: true,
+            last_draw: Instant::now(),
         }
     }
 
     pub async fn run(&mut self) -> Result<()> {
-        let namespace: Api<Namespace> = Api::all(self.kube_client.clone());
-
-        for ns in namespace.list(&ListParams::default()).await.unwrap() {
+        for namespace in self.namespaces().await {
             self.app_state
                 .namespace_cache
-                .push(Rc::from(ns.name_any().as_str()));
+                .push(Rc::from(namespace.as_str()));
+        }
+        if let Some(session) = session::load(&self.app_state.context) {
+            session.restore(&mut self.app_state);
         }
 
         loop {
             let mut executor: Option<&mut Executor> = None;
+            let mut redraw = false;
             tokio::select! {
                 tui_event = self.tui.next()=> {
-                    if let Some(event) = tui_event {
-                        executor = self.dispatch_tui_keyevents(event);
+                    match tui_event {
+                        Some(Event::Tick) => redraw = self.redraw_due(),
+                        Some(event) => {
+                            executor = self.dispatch_tui_keyevents(event);
+                            redraw = true;
+                        }
+                        None => {}
                     }
                 },
-                kube_event = self.pod_event_rx.recv() => {
+                kube_event = self.cluster_events.pod.recv() => {
                     if let Ok(event) = kube_event{
                         executor = self.dispatch_pod_events(event);
                     }
                 },
+                kube_event = self.cluster_events.node.recv() => {
+                    if let Ok(event) = kube_event{
+                        executor = self.dispatch_node_events(event);
+                    }
+                },
+                kube_event = self.cluster_events.service.recv() => {
+                    if let Ok(event) = kube_event{
+                        executor = self.dispatch_service_events(event);
+                    }
+                },
+                kube_event = self.cluster_events.configmap.recv() => {
+                    if let Ok(event) = kube_event{
+                        executor = self.dispatch_config_events(event);
+                    }
+                },
+                kube_event = self.cluster_events.secret.recv() => {
+                    if let Ok(event) = kube_event{
+                        executor = self.dispatch_config_events(event);
+                    }
+                },
+                kube_event = self.cluster_events.deployment.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.deployment_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.replicaset.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.replicaset_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.statefulset.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.statefulset_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.daemonset.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.daemonset_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.job.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.job_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.cronjob.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.cronjob_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.pvc.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.pvc_storage, event);
+                        self.stale = true;
+                    }
+                },
+                kube_event = self.cluster_events.hpa.recv() => {
+                    if let Ok(event) = kube_event{
+                        self.dispatch_hpa_events(event);
+                    }
+                },
+                kube_event = self.cluster_events.pv.recv() => {
+                    if let Ok(event) = kube_event{
+                        store_event(&mut self.app_state.pv_storage, event);
+                        self.stale = true;
+                    }
+                },
+                metrics = self.metrics_rx.recv() => {
+                    if let Some(metrics) = metrics {
+                        Self::add_metrics(&mut self.app_state, metrics);
+                        self.dirty = true;
+                    }
+                },
+                kube_event = self.resource_event_rx.recv() => {
+                    if let Ok(event) = kube_event{
+                        executor = self.dispatch_resource_events(event);
+                    }
+                },
             }
 
             if let Some(executor) = executor.take() {
                 executor.execute();
             }
 
+            for failure in self.app_state.jobs.reap() {
+                tracing::warn!("{}", failure);
+                self.dirty = true;
+                if let Ok(mut status_message) = self.app_state.status_message.try_write() {
+                    *status_message = failure;
+                }
+            }
+
             if self.app_state.should_quit() {
                 break;
             }
 
-            self.draw_ui().await;
+            if redraw {
+                self.draw_ui().await;
+            }
         }
 
+        self.app_state.jobs.stop_all();
+        let session = Session::capture(&self.app_state);
+        if let Err(err) = session::save(&self.app_state.context, session) {
+            tracing::warn!("save session failed: {}", err);
+        }
         Ok(())
     }
 
     async fn draw_ui(&mut self) {
+        self.sync_caches();
+        self.dirty = false;
+        self.last_draw = Instant::now();
         let stdout_buffer = self.app_state.stdout_buffer.clone();
         let reader = stdout_buffer.read().await;
         self.tui
@@ -98,118 +279,310 @@ impl App {
         match event {
             KubeEvent::OnAdd(obj) => {
                 self.pod_on_add(obj);
-                self.resync_caches();
+                self.stale = true;
             }
             KubeEvent::OnDel(obj) => {
                 self.pod_on_del(obj);
-                self.resync_caches();
+                self.stale = true;
             }
         }
         None
     }
 
+    fn dispatch_node_events(
+        &mut self,
+        event: KubeEvent<NodeSpec, NodeStatus>,
+    ) -> Option<&mut Executor> {
+        match event {
+            KubeEvent::OnAdd(obj) => {
+                self.app_state
+                    .node_storage
+                    .add(obj)
+                    .expect("add object failed");
+            }
+            KubeEvent::OnDel(obj) => {
+                let name = obj.0.meta().name.as_deref().unwrap_or_default();
+                self.app_state
+                    .node_storage
+                    .delete("", name)
+                    .expect("del obj failed");
+            }
+        }
+        self.stale = true;
+        None
+    }
+
+    fn dispatch_hpa_events(
+        &mut self,
+        event: KubeEvent<HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus>,
+    ) {
+        let state = &mut self.app_state;
+        match &event {
+            KubeEvent::OnAdd(obj) => {
+                let sample = HpaSample::new(obj);
+                let history = state
+                    .hpa_history
+                    .entry(obj.resource_name())
+                    .or_insert_with(|| CycledCache::with_capacity(HPA_HISTORY));
+                if !history.last().is_some_and(|x| x.same_values(&sample)) {
+                    history.append(sample);
+                }
+            }
+            KubeEvent::OnDel(obj) => {
+                state.hpa_history.remove(&obj.resource_name());
+            }
+        }
+        store_event(&mut state.hpa_storage, event);
+        self.stale = true;
+    }
+
+    fn dispatch_service_events(
+        &mut self,
+        event: KubeEvent<ServiceSpec, ServiceStatus>,
+    ) -> Option<&mut Executor> {
+        match event {
+            KubeEvent::OnAdd(obj) => {
+                self.app_state
+                    .service_storage
+                    .add(obj)
+                    .expect("add object failed");
+            }
+            KubeEvent::OnDel(obj) => {
+                let name = obj.0.meta().name.as_deref().unwrap_or_default();
+                let namespace = obj.0.meta().namespace.as_deref().unwrap_or_default();
+                self.app_state
+                    .service_storage
+                    .delete(namespace, name)
+                    .expect("del obj failed");
+            }
+        }
+        self.stale = true;
+        None
+    }
+
+    // configmaps and secrets share one dispatcher, the object knows its kind
+    fn dispatch_config_events(
+        &mut self,
+        event: KubeEvent<ConfigData, ()>,
+    ) -> Option<&mut Executor> {
+        let (KubeEvent::OnAdd(obj) | KubeEvent::OnDel(obj)) = &event;
+        let storage = match obj.0.spec.kind {
+            ConfigKind::ConfigMap => &mut self.app_state.configmap_storage,
+            ConfigKind::Secret => &mut self.app_state.secret_storage,
+        };
+        match event {
+            KubeEvent::OnAdd(obj) => {
+                storage.add(obj).expect("add object failed");
+            }
+            KubeEvent::OnDel(obj) => {
+                let name = obj.0.meta().name.as_deref().unwrap_or_default();
+                let namespace = obj.0.meta().namespace.as_deref().unwrap_or_default();
+                storage.delete(namespace, name).expect("del obj failed");
+            }
+        }
+        self.stale = true;
+        None
+    }
+
+    fn dispatch_resource_events(&mut self, event: DynamicEvent) -> Option<&mut Executor> {
+        let (KubeEvent::OnAdd(obj) | KubeEvent::OnDel(obj)) = &event;
+        // events of a kind that is no longer watched
+        let watched = self.app_state.resource_watch.as_ref();
+        if !watched.is_some_and(|watch| watch.kind.matches(obj)) {
+            return None;
+        }
+        match event {
+            KubeEvent::OnAdd(obj) => {
+                self.app_state
+                    .resource_storage
+                    .add(obj)
+                    .expect("add object failed");
+            }
+            KubeEvent::OnDel(obj) => {
+                let name = obj.0.meta().name.as_deref().unwrap_or_default();
+                let namespace = obj.0.meta().namespace.as_deref().unwrap_or_default();
+                self.app_state
+                    .resource_storage
+                    .delete(namespace, name)
+                    .expect("del obj failed");
+            }
+        }
+        self.stale = true;
+        None
+    }
+
     fn dispatch_tui_keyevents(&mut self, event: Event) -> Option<&mut Executor> {
-        self.resync_caches();
+        self.sync_caches();
+        // whatever the handler changes is picked up before the next draw
+        self.stale = true;
         match event {
             Event::Key(key_char) => {
+                // 弹出层打开时独占所有按键
+                if let Some(overlay) = self.app_state.overlay {
+                    if let (Overlay::Search, Mode::Insert) = (overlay, self.app_state.get_mode()) {
+                        search::type_key(&mut self.app_state, key_char);
+                        return None;
+                    }
+                    return match overlay_keymap(overlay).get(key_char.as_ref()) {
+                        Some(binding) => (binding.handler)(&mut self.app_state),
+                        None => None,
+                    };
+                }
                 // 优先处理用户输入
                 if Mode::Insert as i32 == self.app_state.get_mode() as i32
                     && !self.handle_user_input(key_char)
                 {
                     return None;
                 }
-                // 第二优先级处理tab键
-                if let CusKey::Tab = key_char {
-                    self.app_state.next_route();
-                    return None;
+                // 第二优先级处理全局按键(tab, help)
+                if let Some(binding) = GLOBAL_KEYMAPS.get(key_char.as_ref()) {
+                    return (binding.handler)(&mut self.app_state);
+                }
+                if let Some(binding) = FAVORITE_KEYMAPS.get(key_char.as_ref()) {
+                    return (binding.handler)(&mut self.app_state);
                 }
                 // 第三开始dispatch到具体窗口handler来处理对应的keyevent
-                let handler = match self.app_state.get_tabpage() {
-                    TabPage::Pod => POD_KEYMAPS.get(key_char.as_ref()),
-                    TabPage::Deploy => DEPLOYMENT_KEYMAPS.get(key_char.as_ref()),
-                    TabPage::Node => NODE_KEYMAPS.get(key_char.as_ref()),
-                };
-
-                if let Some(handler) = handler {
-                    handler(&mut self.app_state)
-                } else {
-                    None
+                let keymap = tabpage_keymap(self.app_state.get_tabpage());
+                match keymap.get(key_char.as_ref()) {
+                    Some(binding) if binding.is_valid(self.app_state.get_route()) => {
+                        if let Some(permission) = binding.missing_permission(&self.app_state) {
+                            if let Ok(mut status_message) =
+                                self.app_state.status_message.try_write()
+                            {
+                                *status_message = format!(
+                                    "{} is forbidden: can not {}",
+                                    binding.desc, permission
+                                );
+                            }
+                            return None;
+                        }
+                        (binding.handler)(&mut self.app_state)
+                    }
+                    _ => None,
                 }
             }
+            Event::Mouse(mouse_event) => {
+                mouse::handle_mouse_event(&mut self.app_state, mouse_event)
+            }
             _ => None,
         }
     }
 
+    // listing namespaces is a cluster permission, without it only the context's namespace is shown
+    async fn namespaces(&self) -> Vec<String> {
+        if let Some(namespace) = self.watch_namespace.as_ref() {
+            return vec![namespace.clone()];
+        }
+        let api: Api<Namespace> = Api::all(self.kube_client.clone());
+        match api.list(&ListParams::default()).await {
+            Ok(namespaces) => namespaces.iter().map(|x| x.name_any()).collect(),
+            Err(err) => {
+                tracing::warn!("list namespaces failed: {}", err);
+                vec![self.kube_client.default_namespace().to_string()]
+            }
+        }
+    }
+
     #[inline]
     fn handle_user_input(&mut self, key: CusKey) -> bool {
         // true ,input has done
-        match key {
-            CusKey::Backspace => {
-                self.app_state.user_input.pop();
-                false
-            }
-            CusKey::Enter | CusKey::Esc => {
-                self.app_state.user_input.complete();
-                self.app_state.set_mode(Mode::Normal);
-                true
-            }
-            _ => {
-                self.app_state.user_input.push(key.char());
-                false
-            }
+        if let Some(binding) = INPUT_KEYMAPS.get(key.as_ref()) {
+            (binding.handler)(&mut self.app_state);
+        } else {
+            self.app_state.user_input.push(key.char());
         }
+        matches!(self.app_state.get_mode(), Mode::Normal)
     }
 }
 
 impl App {
-    fn add_metrics(app_state: &mut AppState, pod_metrics: PodMetrics) {
-        if let Some(container_metric) = pod_metrics.containers.into_iter().next() {
-            let cpu = container_metric.usage.cpu.0;
-            let mem = container_metric.usage.memory.0;
-            let metric_line = format!(
-                "{:<24}{:<12}{:<12}",
-                pod_metrics
-                    .metadata
-                    .creation_timestamp
-                    .unwrap()
-                    .0
-                    .format("%Y-%m-%d %H:%M:%S"),
-                cpu,
-                mem,
-            );
-            app_state.metrics_buffer.insert_str(metric_line);
-            app_state.metrics_buffer.insert_newline();
+    fn add_metrics(app_state: &mut AppState, pod_metrics: Vec<PodMetrics>) {
+        let mut usage: HashMap<String, HashMap<String, PodMetrics>> = HashMap::new();
+        for metrics in pod_metrics {
+            let namespace = metrics.metadata.namespace.clone().unwrap_or_default();
+            let name = metrics.metadata.name.clone().unwrap_or_default();
+            usage.entry(namespace).or_default().insert(name, metrics);
         }
+        app_state.pod_usage = usage;
     }
 }
 
 impl App {
+    #[inline]
+    fn sync_caches(&mut self) {
+        if self.stale {
+            self.stale = false;
+            self.resync_caches();
+        }
+    }
+
+    // ticks only redraw what changed, or what may have changed behind our back
+    fn redraw_due(&self) -> bool {
+        self.dirty || self.stale || self.last_draw.elapsed() >= self.refresh_period()
+    }
+
+    fn refresh_period(&self) -> Duration {
+        let live = matches!(
+            self.app_state.get_route(),
+            Route::PodLog | Route::PodTerm | Route::PodFiles | Route::PodUpload | Route::NodeDrain
+        ) || matches!(self.app_state.overlay, Some(Overlay::Jobs | Overlay::Debug));
+        if live {
+            LIVE_REFRESH
+        } else {
+            IDLE_REFRESH
+        }
+    }
+
     fn resync_caches(&mut self) {
+        if let Some(namespace) = self.app_state.namespace_cache.get() {
+            self.app_state.review_permissions(namespace.as_ref());
+        }
         match self.app_state.get_tabpage() {
+            // overview is computed from storages at draw time, only the pinned list is cached
+            TabPage::Cluster => self.resync_cluster_caches(),
             TabPage::Pod => self.resync_pod_caches(),
             TabPage::Deploy => self.resync_deployment_caches(),
             TabPage::Node => self.resync_nodes_caches(),
+            TabPage::Service => self.resync_service_caches(),
+            TabPage::Config => self.resync_config_caches(),
+            TabPage::Batch => self.resync_batch_caches(),
+            TabPage::Storage => self.resync_storage_caches(),
+            TabPage::Autoscale => self.resync_autoscale_caches(),
+            TabPage::Resource => self.resync_resource_caches(),
+        }
+        if let Some(focus) = self.app_state.focus.take() {
+            self.app_state.select_in_list(&focus);
         }
     }
     #[inline]
-    fn resync_pod_caches(&mut self) {
-        let namespace = self.app_state.namespace_cache.get().unwrap();
-        let items = self.app_state.pod_storage.list(namespace.as_ref());
-        if !self.app_state.user_input.is_empty() {
-            let filter_items = Atom::new(
-                self.app_state.user_input.as_str(),
-                CaseMatching::Ignore,
-                Normalization::Smart,
-                AtomKind::Fuzzy,
-                false,
-            )
-            .match_list(items, &mut self.app_state.fuzz_matcher)
-            .into_iter()
-            .map(|x| x.0)
+    fn resync_cluster_caches(&mut self) {
+        let items = self
+            .app_state
+            .favorites
+            .iter()
+            .map(|x| Rc::from(x.to_string().as_str()))
             .collect::<Vec<Rc<str>>>();
-            self.app_state.cache_items.replace(filter_items);
-        } else {
-            self.app_state.cache_items.replace(items);
+        self.app_state.favorites_cache.replace(items);
+    }
+    #[inline]
+    fn resync_pod_caches(&mut self) {
+        let state = &mut self.app_state;
+        let namespace = state.namespace_cache.get().unwrap();
+        let storage = &state.pod_storage;
+        // the input line takes terminal commands there, not a filter
+        let query = match state.get_route() {
+            Route::PodTerm | Route::PodUpload => "",
+            _ => state.user_input.as_str(),
+        };
+        let items = state.pod_filter.apply(
+            namespace.as_ref(),
+            query,
+            storage.generation(),
+            || storage.list(namespace.as_ref()),
+            &mut state.fuzz_matcher,
+        );
+        if let Some(items) = items {
+            state.cache_items.replace(items);
         }
 
         if !self.app_state.user_input.is_completed() {
@@ -218,9 +591,168 @@ impl App {
     }
 
     #[inline]
-    fn resync_deployment_caches(&mut self) {}
+    fn resync_deployment_caches(&mut self) {
+        let state = &mut self.app_state;
+        let namespace = state.namespace_cache.get().unwrap_or_default();
+        let pods = state.pod_storage.iter().map(|obj| TreeEntry {
+            kind: "Pod",
+            meta: &obj.0.metadata,
+            health: Some(Health {
+                ready: is_pod_ready(obj) as usize,
+                total: 1,
+                restarts: pod_restarts(obj),
+            }),
+        });
+        let owners = owner_entries("Deployment", &state.deployment_storage)
+            .chain(owner_entries("ReplicaSet", &state.replicaset_storage))
+            .chain(owner_entries("StatefulSet", &state.statefulset_storage))
+            .chain(owner_entries("DaemonSet", &state.daemonset_storage))
+            .chain(owner_entries("CronJob", &state.cronjob_storage))
+            .chain(owner_entries("Job", &state.job_storage));
+        let entries = owners
+            .chain(pods)
+            .filter(|entry| entry.meta.namespace.as_deref() == Some(namespace.as_ref()));
+        state.workload_tree = OwnerTree::build(entries);
+
+        // jumped here from another page or from a child, the target must be visible
+        let focus = state.workload_focus.take();
+        if let Some(focus) = focus.as_ref() {
+            for uid in state.workload_tree.ancestors(focus) {
+                state.workload_collapsed.remove(&uid);
+            }
+        }
+        let rows = state
+            .workload_tree
+            .visible(&state.workload_collapsed)
+            .iter()
+            .map(|uid| Rc::from(uid.as_str()))
+            .collect::<Vec<Rc<str>>>();
+        let index = focus.and_then(|focus| rows.iter().position(|uid| uid.as_ref() == focus));
+        state.workload_rows.replace(rows);
+        if let Some(index) = index {
+            state.workload_rows.select(index);
+        }
+
+        let revisions = workload::selected_deployment(state)
+            .map(|deploy| {
+                rollout_history(&deploy, state.replicaset_storage.iter())
+                    .iter()
+                    .map(|x| Rc::from(x.number.to_string().as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        state.rollout_history.replace(revisions);
+    }
+    #[inline]
+    fn resync_nodes_caches(&mut self) {
+        let mut items = self.app_state.node_storage.list("");
+        items.sort();
+        self.app_state.nodes_cache.replace(items);
+    }
     #[inline]
-    fn resync_nodes_caches(&mut self) {}
+    fn resync_service_caches(&mut self) {
+        let mut items = self
+            .app_state
+            .service_storage
+            .iter()
+            .map(|obj| {
+                let namespace = obj.0.metadata.namespace.as_deref().unwrap_or_default();
+                let name = obj.0.metadata.name.as_deref().unwrap_or_default();
+                Rc::from(format!("{}/{}", namespace, name).as_str())
+            })
+            .collect::<Vec<Rc<str>>>();
+        items.sort();
+        self.app_state.services_cache.replace(items);
+    }
+    #[inline]
+    fn resync_config_caches(&mut self) {
+        let namespace = self.app_state.namespace_cache.get().unwrap_or_default();
+        let mut configmaps = self.app_state.configmap_storage.list(namespace.as_ref());
+        let mut secrets = self.app_state.secret_storage.list(namespace.as_ref());
+        configmaps.sort();
+        secrets.sort();
+        let items = configmaps
+            .iter()
+            .map(|name| Rc::from(format!("ConfigMap/{}", name).as_str()))
+            .chain(
+                secrets
+                    .iter()
+                    .map(|name| Rc::from(format!("Secret/{}", name).as_str())),
+            )
+            .collect::<Vec<Rc<str>>>();
+        self.app_state.configs_cache.replace(items);
+
+        let keys = config::selected_config(&self.app_state)
+            .map(|obj| {
+                obj.0
+                    .spec
+                    .entries
+                    .keys()
+                    .map(|key| Rc::from(key.as_str()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.app_state.config_keys_cache.replace(keys);
+    }
+    #[inline]
+    fn resync_batch_caches(&mut self) {
+        let namespace = self.app_state.namespace_cache.get().unwrap_or_default();
+        let mut cronjobs = self.app_state.cronjob_storage.list(namespace.as_ref());
+        let mut jobs = self.app_state.job_storage.list(namespace.as_ref());
+        cronjobs.sort();
+        jobs.sort();
+        let items = cronjobs
+            .iter()
+            .map(|name| Rc::from(format!("CronJob/{}", name).as_str()))
+            .chain(
+                jobs.iter()
+                    .map(|name| Rc::from(format!("Job/{}", name).as_str())),
+            )
+            .collect::<Vec<Rc<str>>>();
+        self.app_state.batch_cache.replace(items);
+    }
+    #[inline]
+    fn resync_storage_caches(&mut self) {
+        storage::spawn_volume_stats(&mut self.app_state, self.watch_namespace.clone());
+        let namespace = self.app_state.namespace_cache.get().unwrap_or_default();
+        let mut claims = self.app_state.pvc_storage.list(namespace.as_ref());
+        let mut volumes = self.app_state.pv_storage.list("");
+        claims.sort();
+        volumes.sort();
+        self.app_state.claims_cache.replace(claims);
+        self.app_state.volumes_cache.replace(volumes);
+    }
+    #[inline]
+    fn resync_autoscale_caches(&mut self) {
+        let namespace = self.app_state.namespace_cache.get().unwrap_or_default();
+        let mut items = self.app_state.hpa_storage.list(namespace.as_ref());
+        items.sort();
+        self.app_state.autoscalers_cache.replace(items);
+    }
+    #[inline]
+    fn resync_resource_caches(&mut self) {
+        if let Ok(kinds) = self.app_state.resource_kinds.try_read() {
+            if kinds.len() != self.app_state.resource_kinds_cache.list().len() {
+                let ids = kinds
+                    .iter()
+                    .map(|kind| Rc::from(kind.id().as_str()))
+                    .collect();
+                self.app_state.resource_kinds_cache.replace(ids);
+            }
+        }
+        let mut items = self
+            .app_state
+            .resource_storage
+            .iter()
+            .map(|obj| {
+                let namespace = obj.0.metadata.namespace.as_deref().unwrap_or_default();
+                let name = obj.0.metadata.name.as_deref().unwrap_or_default();
+                Rc::from(format!("{}/{}", namespace, name).as_str())
+            })
+            .collect::<Vec<Rc<str>>>();
+        items.sort();
+        self.app_state.resources_cache.replace(items);
+    }
 }
 
 // app tempoary task relative
@@ -230,6 +762,14 @@ impl App {
     fn pod_on_add(&mut self, obj: RtObject<PodSpec, PodStatus>) {
         let name = obj.0.meta().name.as_deref().unwrap_or_default();
         let namespace = obj.0.meta().namespace.as_deref().unwrap_or_default();
+        // keep the previous version alive until rules compared it with the new one
+        let previous = self.app_state.pod_storage.get(namespace, name);
+        if let Some(previous) = previous {
+            self.app_state.notifier.on_pod_update(&previous, &obj);
+            self.app_state
+                .pod_previous
+                .add(namespace.to_string(), name.to_string(), previous);
+        }
         self.app_state.pod_describes.add(
             namespace.to_string(),
             name.to_string(),
@@ -250,6 +790,9 @@ impl App {
             .as_deref()
             .unwrap_or_default()
             .to_string();
+        // describe points into the stored object, so it must go first
+        self.app_state.pod_describes.delete(&namespace, &pod_name);
+        self.app_state.pod_previous.delete(&namespace, &pod_name);
         self.app_state
             .pod_storage
             .delete(&namespace, &pod_name)
@@ -257,6 +800,32 @@ impl App {
     }
 }
 
+// objects that only need to be kept in their storage
+fn store_event<P: Clone, U: Clone>(storage: &mut StoreIndex<P, U>, event: KubeEvent<P, U>) {
+    match event {
+        KubeEvent::OnAdd(obj) => {
+            storage.add(obj).expect("add object failed");
+        }
+        KubeEvent::OnDel(obj) => {
+            let name = obj.0.meta().name.as_deref().unwrap_or_default();
+            let namespace = obj.0.meta().namespace.as_deref().unwrap_or_default();
+            storage.delete(namespace, name).expect("del obj failed");
+        }
+    }
+}
+
+// owners have no health of their own, it is rolled up from their pods
+fn owner_entries<'a, P: Clone, U: Clone>(
+    kind: &'static str,
+    storage: &'a StoreIndex<P, U>,
+) -> impl Iterator<Item = TreeEntry<'a>> {
+    storage.iter().map(move |x| TreeEntry {
+        kind,
+        meta: &x.0.metadata,
+        health: None,
+    })
+}
+
 impl Drop for App {
     fn drop(&mut self) {}
 }
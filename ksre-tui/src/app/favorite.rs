@@ -0,0 +1,220 @@
+use std::{fmt::Display, rc::Rc};
+
+use k8s_openapi::{
+    api::core::v1::{PodSpec, PodStatus},
+    apimachinery::pkg::apis::meta::v1::ObjectMeta,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::kubernetes::api::{object::RtObject, pod::is_pod_ready};
+
+type PodObject = RtObject<PodSpec, PodStatus>;
+
+// label the deployment controller adds to its replicasets and pods
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+// Favorite is a pinned object, kept in the session file of the context
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Favorite {
+    Namespace {
+        name: String,
+    },
+    Deployment {
+        namespace: String,
+        name: String,
+    },
+    // a recreated pod gets a new name, it is found again by its owner,
+    // or by the name pattern when nothing owns it
+    Pod {
+        namespace: String,
+        owner: Option<String>,
+        pattern: String,
+    },
+}
+
+impl Display for Favorite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Favorite::Namespace { name } => write!(f, "namespace {}", name),
+            Favorite::Deployment { namespace, name } => {
+                write!(f, "deployment {}/{}", namespace, name)
+            }
+            Favorite::Pod {
+                namespace,
+                owner: Some(owner),
+                ..
+            } => write!(f, "pods of {}/{}", namespace, owner),
+            Favorite::Pod {
+                namespace, pattern, ..
+            } => write!(f, "pod {}/{}", namespace, pattern),
+        }
+    }
+}
+
+impl Favorite {
+    pub fn for_pod(pod: &PodObject) -> Self {
+        let meta = &pod.0.metadata;
+        Favorite::Pod {
+            namespace: meta.namespace.clone().unwrap_or_default(),
+            owner: pod_owner(meta),
+            pattern: name_pattern(meta),
+        }
+    }
+
+    // the pod to open for a pod favorite, ready ones first, then the newest
+    pub fn resolve_pod<'a>(
+        &self,
+        pods: impl Iterator<Item = &'a Rc<PodObject>>,
+    ) -> Option<Rc<PodObject>> {
+        let Favorite::Pod {
+            namespace,
+            owner,
+            pattern,
+        } = self
+        else {
+            return None;
+        };
+        pods.filter(|pod| pod.0.metadata.namespace.as_ref() == Some(namespace))
+            .filter(|pod| match owner {
+                Some(owner) => pod_owner(&pod.0.metadata).as_ref() == Some(owner),
+                None => {
+                    matches_pattern(pattern, pod.0.metadata.name.as_deref().unwrap_or_default())
+                }
+            })
+            .max_by_key(|pod| (is_pod_ready(pod), pod.0.metadata.creation_timestamp.clone()))
+            .cloned()
+    }
+}
+
+// Kind/name of the controller, a replicaset stands for its deployment, which outlives rollouts
+fn pod_owner(meta: &ObjectMeta) -> Option<String> {
+    let owner = meta
+        .owner_references
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .find(|x| x.controller.unwrap_or(false))?;
+    let hash = meta
+        .labels
+        .as_ref()
+        .and_then(|x| x.get(POD_TEMPLATE_HASH_LABEL));
+    match (owner.kind.as_str(), hash) {
+        ("ReplicaSet", Some(hash)) => match owner.name.strip_suffix(&format!("-{}", hash)) {
+            Some(deployment) => Some(format!("Deployment/{}", deployment)),
+            None => Some(format!("ReplicaSet/{}", owner.name)),
+        },
+        (kind, _) => Some(format!("{}/{}", kind, owner.name)),
+    }
+}
+
+// name without the generated suffix and template hash, e.g. web-* for web-5d9f7c8b-x2x4z
+fn name_pattern(meta: &ObjectMeta) -> String {
+    let name = meta.name.clone().unwrap_or_default();
+    let Some(generate_name) = meta.generate_name.as_ref() else {
+        return name;
+    };
+    let hash = meta
+        .labels
+        .as_ref()
+        .and_then(|x| x.get(POD_TEMPLATE_HASH_LABEL));
+    let prefix = hash
+        .and_then(|hash| generate_name.strip_suffix(&format!("{}-", hash)))
+        .unwrap_or(generate_name);
+    format!("{}*", prefix)
+}
+
+#[inline]
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => pattern == name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::{
+        api::core::v1::Pod,
+        apimachinery::pkg::apis::meta::v1::{OwnerReference, Time},
+    };
+
+    use super::*;
+
+    fn pod(name: &str, hash: Option<&str>, owner: Option<&str>, age: i64) -> Rc<PodObject> {
+        Rc::new(PodObject::from(Pod {
+            metadata: ObjectMeta {
+                name: Some(name.to_string()),
+                namespace: Some("prod".to_string()),
+                generate_name: owner.map(|_| name[..name.rfind('-').unwrap() + 1].to_string()),
+                labels: hash.map(|x| [(POD_TEMPLATE_HASH_LABEL.to_string(), x.to_string())].into()),
+                owner_references: owner.map(|x| {
+                    vec![OwnerReference {
+                        kind: "ReplicaSet".to_string(),
+                        name: x.to_string(),
+                        controller: Some(true),
+                        ..Default::default()
+                    }]
+                }),
+                creation_timestamp: Some(Time(
+                    chrono::Utc::now() - chrono::TimeDelta::try_seconds(age).unwrap(),
+                )),
+                ..Default::default()
+            },
+            spec: Some(PodSpec::default()),
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn test_favorite_survives_recreate() {
+        let favorite = Favorite::for_pod(&pod(
+            "web-5d9f7c8b-x2x4z",
+            Some("5d9f7c8b"),
+            Some("web-5d9f7c8b"),
+            60,
+        ));
+        assert_eq!(
+            Favorite::Pod {
+                namespace: "prod".to_string(),
+                owner: Some("Deployment/web".to_string()),
+                pattern: "web-*".to_string(),
+            },
+            favorite
+        );
+        // after a rollout the replicaset and the pod names are new
+        let pods = [
+            pod(
+                "web-7c6b5a49-aaaaa",
+                Some("7c6b5a49"),
+                Some("web-7c6b5a49"),
+                30,
+            ),
+            pod(
+                "web-7c6b5a49-bbbbb",
+                Some("7c6b5a49"),
+                Some("web-7c6b5a49"),
+                10,
+            ),
+            pod(
+                "webhook-6f5e4d3c-ccccc",
+                Some("6f5e4d3c"),
+                Some("webhook-6f5e4d3c"),
+                5,
+            ),
+        ];
+        let resolved = favorite.resolve_pod(pods.iter()).unwrap();
+        assert_eq!(
+            Some("web-7c6b5a49-bbbbb"),
+            resolved.0.metadata.name.as_deref()
+        );
+
+        let bare = Favorite::for_pod(&pod("debug", None, None, 60));
+        assert_eq!(
+            Some("debug"),
+            bare.resolve_pod([pod("debug", None, None, 1)].iter())
+                .and_then(|x| x.0.metadata.name.clone())
+                .as_deref()
+        );
+    }
+}
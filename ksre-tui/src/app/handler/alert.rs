@@ -0,0 +1,16 @@
+use crate::app::state::{AppState, Executor, Overlay};
+
+pub fn toggle_alerts(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay_scroll = 0;
+    app_state.overlay = match app_state.overlay {
+        Some(Overlay::Alerts) => None,
+        _ => Some(Overlay::Alerts),
+    };
+    None
+}
+
+pub fn clear_alerts(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.notifier.clear_history();
+    app_state.overlay_scroll = 0;
+    None
+}
@@ -0,0 +1,62 @@
+use std::rc::Rc;
+
+use crate::app::state::{AppState, Executor, TabPage};
+use crate::kubernetes::api::autoscale::HpaObject;
+
+// items of autoscalers_cache are names in the selected namespace
+pub fn selected_hpa(app_state: &AppState) -> Option<Rc<HpaObject>> {
+    let name = app_state.autoscalers_cache.get()?;
+    let namespace = app_state.namespace_cache.get()?;
+    app_state.hpa_storage.get(namespace.as_ref(), name.as_ref())
+}
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.autoscalers_cache.next();
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.autoscalers_cache.prev();
+    None
+}
+
+pub fn next_namespace(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.namespace_cache.next();
+    app_state.autoscalers_cache.reindex();
+    None
+}
+
+// open the scaled workload in the workload tree
+pub fn show_target(app_state: &mut AppState) -> Option<&mut Executor> {
+    let hpa = selected_hpa(app_state)?;
+    let namespace = hpa.0.metadata.namespace.clone().unwrap_or_default();
+    let target = &hpa.0.spec.scale_target_ref;
+    let uid = match target.kind.as_str() {
+        "Deployment" => app_state
+            .deployment_storage
+            .get(&namespace, &target.name)
+            .and_then(|x| x.0.metadata.uid.clone()),
+        "StatefulSet" => app_state
+            .statefulset_storage
+            .get(&namespace, &target.name)
+            .and_then(|x| x.0.metadata.uid.clone()),
+        "ReplicaSet" => app_state
+            .replicaset_storage
+            .get(&namespace, &target.name)
+            .and_then(|x| x.0.metadata.uid.clone()),
+        _ => None,
+    };
+    let Some(uid) = uid else {
+        if let Ok(mut status_message) = app_state.status_message.try_write() {
+            *status_message = format!(
+                "{}/{} is not in the workload tree",
+                target.kind, target.name
+            );
+        }
+        return None;
+    };
+    app_state.switch_tabpage(TabPage::Deploy);
+    app_state.workload_trail.clear();
+    app_state.workload_focus = Some(uid);
+    None
+}
@@ -0,0 +1,138 @@
+use std::{fmt::Debug, rc::Rc};
+
+use k8s_openapi::api::batch::v1::{CronJob, Job};
+use kube::Api;
+use serde::de::DeserializeOwned;
+
+use crate::app::{
+    handler::{pod, report},
+    state::{AppState, Executor, TabPage},
+};
+use crate::kubernetes::api::{
+    access,
+    batch::{self, cronjob_history, is_suspended, latest_job_pod, CronJobObject, JobObject},
+};
+
+// the row under the cursor, items of batch_cache are Kind/name in the selected namespace
+pub enum SelectedBatch {
+    CronJob(Rc<CronJobObject>),
+    Job(Rc<JobObject>),
+}
+
+pub fn selected_batch(app_state: &AppState) -> Option<SelectedBatch> {
+    let item = app_state.batch_cache.get()?;
+    let namespace = app_state.namespace_cache.get()?;
+    match item.split_once('/')? {
+        ("CronJob", name) => app_state
+            .cronjob_storage
+            .get(namespace.as_ref(), name)
+            .map(SelectedBatch::CronJob),
+        ("Job", name) => app_state
+            .job_storage
+            .get(namespace.as_ref(), name)
+            .map(SelectedBatch::Job),
+        _ => None,
+    }
+}
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.batch_cache.next();
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.batch_cache.prev();
+    None
+}
+
+pub fn next_namespace(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.namespace_cache.next();
+    app_state.batch_cache.reindex();
+    None
+}
+
+pub fn run_now(app_state: &mut AppState) -> Option<&mut Executor> {
+    let Some(SelectedBatch::CronJob(cronjob)) = selected_batch(app_state) else {
+        return None;
+    };
+    let cronjob = cronjob.as_ref().clone();
+    let kube_client = app_state.kube_client();
+    let status_message = app_state.status_message.clone();
+    tokio::spawn(async move {
+        let name = cronjob.0.metadata.name.clone().unwrap_or_default();
+        let message = match batch::run_now(kube_client, &cronjob).await {
+            Ok(job) => Ok(format!("created job {} from cronjob {}", job, name)),
+            Err(err) => Err(format!("run cronjob {} failed: {}", name, err)),
+        };
+        report(&status_message, message).await;
+    });
+    None
+}
+
+pub fn toggle_suspend(app_state: &mut AppState) -> Option<&mut Executor> {
+    let selected = selected_batch(app_state)?;
+    let permission = match selected {
+        SelectedBatch::CronJob(_) => access::PATCH_CRONJOBS,
+        SelectedBatch::Job(_) => access::PATCH_JOBS,
+    };
+    if !app_state.is_permitted(&permission) {
+        if let Ok(mut status_message) = app_state.status_message.try_write() {
+            *status_message = format!("suspend/resume is forbidden: can not {}", permission);
+        }
+        return None;
+    }
+    let namespace = app_state.namespace_cache.get()?.to_string();
+    let kube_client = app_state.kube_client();
+    match selected {
+        SelectedBatch::CronJob(x) => spawn_set_suspend(
+            app_state,
+            Api::<CronJob>::namespaced(kube_client, &namespace),
+            "cronjob",
+            x.0.metadata.name.clone().unwrap_or_default(),
+            !is_suspended(&x),
+        ),
+        SelectedBatch::Job(x) => spawn_set_suspend(
+            app_state,
+            Api::<Job>::namespaced(kube_client, &namespace),
+            "job",
+            x.0.metadata.name.clone().unwrap_or_default(),
+            !x.0.spec.suspend.unwrap_or(false),
+        ),
+    }
+    None
+}
+
+fn spawn_set_suspend<K>(
+    app_state: &AppState,
+    api: Api<K>,
+    kind: &'static str,
+    name: String,
+    suspend: bool,
+) where
+    K: Clone + DeserializeOwned + Debug + Send + Sync + 'static,
+{
+    let status_message = app_state.status_message.clone();
+    tokio::spawn(async move {
+        let action = if suspend { "suspend" } else { "resume" };
+        let message = match batch::set_suspend(api, &name, suspend).await {
+            Ok(_) => Ok(format!("{} {} {} done", action, kind, name)),
+            Err(err) => Err(format!("{} {} {} failed: {}", action, kind, name, err)),
+        };
+        report(&status_message, message).await;
+    });
+}
+
+// logs of the newest pod of the selected job, or of the most recent run of a cronjob
+pub fn show_latest_logs(app_state: &mut AppState) -> Option<&mut Executor> {
+    let job = match selected_batch(app_state)? {
+        SelectedBatch::Job(job) => job,
+        SelectedBatch::CronJob(cronjob) => cronjob_history(&cronjob, app_state.job_storage.iter())
+            .into_iter()
+            .next()?,
+    };
+    let pod = latest_job_pod(&job, app_state.pod_storage.iter())?;
+    let pod_name = pod.0.metadata.name.clone()?;
+    app_state.switch_tabpage(TabPage::Pod);
+    app_state.focus = Some(pod_name.clone());
+    pod::tail_pod_log(app_state, &pod_name)
+}
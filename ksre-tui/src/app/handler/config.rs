@@ -0,0 +1,90 @@
+use std::rc::Rc;
+
+use crate::app::{
+    clipboard,
+    state::{AppState, Executor, Route},
+};
+use crate::kubernetes::api::config::ConfigObject;
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::ConfigIndex => {
+            app_state.configs_cache.next();
+            forget_selected_keys(app_state);
+        }
+        Route::ConfigKeys => app_state.config_keys_cache.next(),
+        _ => {}
+    }
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::ConfigIndex => {
+            app_state.configs_cache.prev();
+            forget_selected_keys(app_state);
+        }
+        Route::ConfigKeys => app_state.config_keys_cache.prev(),
+        _ => {}
+    }
+    None
+}
+
+pub fn handle_enter_key(app_state: &mut AppState) -> Option<&mut Executor> {
+    if !app_state.config_keys_cache.list().is_empty() {
+        app_state.set_route(Route::ConfigKeys);
+    }
+    None
+}
+
+pub fn handle_esc_key(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.set_route(Route::ConfigIndex);
+    None
+}
+
+pub fn next_namespace(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.namespace_cache.next();
+    app_state.configs_cache.reindex();
+    forget_selected_keys(app_state);
+    None
+}
+
+pub fn toggle_reveal(app_state: &mut AppState) -> Option<&mut Executor> {
+    if let Some(key) = app_state.config_keys_cache.get() {
+        if !app_state.config_revealed.remove(key.as_ref()) {
+            app_state.config_revealed.insert(key.to_string());
+        }
+    }
+    None
+}
+
+pub fn copy_value(app_state: &mut AppState) -> Option<&mut Executor> {
+    let key = app_state.config_keys_cache.get()?;
+    let obj = selected_config(app_state)?;
+    let value = obj.0.spec.entries.get(key.as_ref())?;
+    let message = match clipboard::copy(value) {
+        Ok(copied) => copied.message(&key),
+        Err(err) => format!("copy {} failed: {}", key, err),
+    };
+    if let Ok(mut status_message) = app_state.status_message.try_write() {
+        *status_message = message;
+    }
+    None
+}
+
+// object under the cursor of configs_cache, items are Kind/name in the selected namespace
+pub fn selected_config(app_state: &AppState) -> Option<Rc<ConfigObject>> {
+    let item = app_state.configs_cache.get()?;
+    let namespace = app_state.namespace_cache.get()?;
+    match item.split_once('/')? {
+        ("ConfigMap", name) => app_state.configmap_storage.get(namespace.as_ref(), name),
+        ("Secret", name) => app_state.secret_storage.get(namespace.as_ref(), name),
+        _ => None,
+    }
+}
+
+// revealed secrets are hidden again once another object is selected
+fn forget_selected_keys(app_state: &mut AppState) {
+    app_state.config_keys_cache.reindex();
+    app_state.config_revealed.clear();
+}
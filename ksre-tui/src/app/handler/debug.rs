@@ -0,0 +1,17 @@
+use crate::app::state::{AppState, Executor, Overlay};
+use crate::logging;
+
+pub fn toggle_debug(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay_scroll = 0;
+    app_state.overlay = match app_state.overlay {
+        Some(Overlay::Debug) => None,
+        _ => Some(Overlay::Debug),
+    };
+    None
+}
+
+pub fn clear_records(app_state: &mut AppState) -> Option<&mut Executor> {
+    logging::clear();
+    app_state.overlay_scroll = 0;
+    None
+}
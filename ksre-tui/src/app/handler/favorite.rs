@@ -0,0 +1,119 @@
+use crate::app::{
+    favorite::Favorite,
+    handler::workload::selected_deployment,
+    state::{AppState, Executor, Route, TabPage},
+};
+
+// pin or unpin what is under the cursor: a pod, a namespace or a deployment
+pub fn toggle_favorite(app_state: &mut AppState) -> Option<&mut Executor> {
+    let favorite = match app_state.get_route() {
+        Route::PodNamespace => Favorite::Namespace {
+            name: app_state.namespace_cache.get()?.to_string(),
+        },
+        Route::PodIndex | Route::PodList => {
+            let namespace = app_state.namespace_cache.get()?;
+            let pod_name = app_state.cache_items.get()?;
+            let pod = app_state.pod_storage.get(&namespace, pod_name.as_ref())?;
+            Favorite::for_pod(&pod)
+        }
+        // pinned under the namespace the object is in, not whatever the cursor is on
+        Route::DeployIndex => {
+            let deployment = selected_deployment(app_state)?;
+            Favorite::Deployment {
+                namespace: deployment.0.metadata.namespace.clone()?,
+                name: deployment.0.metadata.name.clone()?,
+            }
+        }
+        _ => return None,
+    };
+    let message = match app_state.favorites.iter().position(|x| *x == favorite) {
+        Some(index) => {
+            app_state.favorites.remove(index);
+            format!("unpinned {}", favorite)
+        }
+        None => {
+            app_state.favorites.push(favorite);
+            format!(
+                "pinned {} as [{}]",
+                app_state.favorites.last()?,
+                app_state.favorites.len()
+            )
+        }
+    };
+    if let Ok(mut status_message) = app_state.status_message.try_write() {
+        *status_message = message;
+    }
+    None
+}
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.favorites_cache.next();
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.favorites_cache.prev();
+    None
+}
+
+pub fn unpin_selected(app_state: &mut AppState) -> Option<&mut Executor> {
+    let index = app_state.favorites_cache.index();
+    if index < app_state.favorites.len() {
+        app_state.favorites.remove(index);
+        app_state.favorites_cache.prev();
+    }
+    None
+}
+
+pub fn open_selected(app_state: &mut AppState) -> Option<&mut Executor> {
+    jump(app_state, app_state.favorites_cache.index())
+}
+
+// favorites are numbered in the pinned list, 1 to 9 open them from every tabpage
+macro_rules! jump_handlers {
+    ($($name:ident => $index:literal),*) => {
+        $(pub fn $name(app_state: &mut AppState) -> Option<&mut Executor> {
+            jump(app_state, $index)
+        })*
+    };
+}
+
+jump_handlers! {
+    jump_1 => 0, jump_2 => 1, jump_3 => 2, jump_4 => 3, jump_5 => 4,
+    jump_6 => 5, jump_7 => 6, jump_8 => 7, jump_9 => 8
+}
+
+fn jump(app_state: &mut AppState, index: usize) -> Option<&mut Executor> {
+    let favorite = app_state.favorites.get(index)?.clone();
+    match &favorite {
+        Favorite::Namespace { name } => {
+            app_state.switch_tabpage(TabPage::Pod);
+            app_state.select_namespace(name);
+        }
+        Favorite::Deployment { namespace, name } => {
+            let Some(deploy) = app_state.deployment_storage.get(namespace, name) else {
+                return not_found(app_state, &favorite);
+            };
+            app_state.switch_tabpage(TabPage::Deploy);
+            app_state.select_namespace(namespace);
+            app_state.workload_trail.clear();
+            app_state.workload_focus = deploy.0.metadata.uid.clone();
+        }
+        Favorite::Pod { namespace, .. } => {
+            let Some(pod) = favorite.resolve_pod(app_state.pod_storage.iter()) else {
+                return not_found(app_state, &favorite);
+            };
+            app_state.switch_tabpage(TabPage::Pod);
+            app_state.select_namespace(namespace);
+            app_state.focus = pod.0.metadata.name.clone();
+        }
+    }
+    None
+}
+
+fn not_found<'a>(app_state: &mut AppState, favorite: &Favorite) -> Option<&'a mut Executor> {
+    if let Ok(mut status_message) = app_state.status_message.try_write() {
+        *status_message = format!("{} matches nothing now", favorite);
+    }
+    None
+}
@@ -0,0 +1,25 @@
+use crate::app::state::{AppState, Executor, Overlay};
+
+pub fn next_tabpage(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.next_route();
+    None
+}
+
+pub fn toggle_help(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay_scroll = 0;
+    app_state.overlay = match app_state.overlay {
+        Some(Overlay::Help) => None,
+        _ => Some(Overlay::Help),
+    };
+    None
+}
+
+pub fn scroll_down(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay_scroll = app_state.overlay_scroll.saturating_add(1);
+    None
+}
+
+pub fn scroll_up(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay_scroll = app_state.overlay_scroll.saturating_sub(1);
+    None
+}
@@ -0,0 +1,12 @@
+use crate::app::state::{AppState, Executor, Mode};
+
+pub fn delete_char(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.user_input.pop();
+    None
+}
+
+pub fn complete(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.user_input.complete();
+    app_state.set_mode(Mode::Normal);
+    None
+}
@@ -0,0 +1,29 @@
+use crate::app::state::{AppState, Executor, Overlay};
+
+pub fn toggle_jobs(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay = match app_state.overlay {
+        Some(Overlay::Jobs) => None,
+        _ => Some(Overlay::Jobs),
+    };
+    None
+}
+
+pub fn select_next_job(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.jobs.select_next();
+    None
+}
+
+pub fn select_prev_job(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.jobs.select_prev();
+    None
+}
+
+pub fn kill_job(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.jobs.stop_selected();
+    None
+}
+
+pub fn clear_finished(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.jobs.clear_finished();
+    None
+}
@@ -2,53 +2,293 @@ use std::collections::HashMap;
 
 use lazy_static::lazy_static;
 
-use crate::app::state::{AppState, Executor};
+use crate::app::state::{AppState, Executor, Mode, Overlay, Route, TabPage};
+use crate::kubernetes::api::access::{self, Permission};
 
 /* use super::state::{AppState, Executor}; */
 /* pub type HandleFn = fn(&mut App) -> Pin<Box<dyn Future<Output = ()>>>; */
 /* pub type HandleFn = fn(&mut AppState); */
 pub type Handler = fn(&mut AppState) -> Option<&mut Executor>;
 
+pub struct KeyBinding {
+    pub key: &'static str,
+    pub handler: Handler,
+    pub desc: &'static str,
+    // routes where the binding takes effect, empty means every route
+    pub routes: &'static [Route],
+    // what rbac has to allow for the action, it is greyed out and refused otherwise
+    pub needs: &'static [Permission],
+}
+
+impl KeyBinding {
+    #[inline]
+    pub fn is_valid(&self, route: Route) -> bool {
+        self.routes.is_empty() || self.routes.contains(&route)
+    }
+
+    pub fn missing_permission(&self, app_state: &AppState) -> Option<Permission> {
+        self.needs
+            .iter()
+            .find(|x| !app_state.is_permitted(x))
+            .copied()
+    }
+}
+
+// bindings keep their declaration order, so help and hints are listed the way they are written
+pub struct KeyMap {
+    bindings: Vec<KeyBinding>,
+    index: HashMap<&'static str, usize>,
+}
+
+impl KeyMap {
+    fn new(bindings: Vec<KeyBinding>) -> Self {
+        let index = bindings
+            .iter()
+            .enumerate()
+            .map(|(idx, binding)| (binding.key, idx))
+            .collect();
+        Self { bindings, index }
+    }
+
+    #[inline]
+    pub fn get(&self, key: &str) -> Option<&KeyBinding> {
+        self.index.get(key).map(|idx| &self.bindings[*idx])
+    }
+
+    pub fn valid_bindings(&self, route: Route) -> impl Iterator<Item = &KeyBinding> {
+        self.bindings
+            .iter()
+            .filter(move |binding| binding.is_valid(route))
+    }
+}
+
 macro_rules! key_binding {
-    ($($key: literal : $value:path),*) => {
+    ($($key: literal : $value:path => $desc:literal $(in [$($route:path),*])? $(needs [$($need:path),*])?),* $(,)?) => {
         {
-            let mut keymaps = HashMap::new();
-            $(keymaps.insert($key, $value as Handler));* ;
-            keymaps
+            KeyMap::new(vec![$(KeyBinding {
+                key: $key,
+                handler: $value as Handler,
+                desc: $desc,
+                routes: &[$($($route),*)?],
+                needs: &[$($($need),*)?],
+            }),*])
         }
     };
 }
 
-use super::pod;
+use super::{
+    alert, autoscale, batch, config, debug, favorite, help, input, jobs, node, pod, resource,
+    search, service, storage, workload, yank,
+};
 lazy_static! {
-    pub static ref POD_KEYMAPS: HashMap<&'static str, Handler> = key_binding! {
-        "e": pod::trigger_userinput,
-        "q": pod::handle_quit,
-        "n": pod::trigger_namespace_select,
-        "j": pod::select_next_item,
-        "k": pod::select_prev_item,
-        "l": pod::show_pod_log,
-        "Esc": pod::handle_esc_key,
-        "Enter": pod::handle_enter_key
-    };
-    pub static ref DEPLOYMENT_KEYMAPS: HashMap<&'static str, Handler> = key_binding! {
-        "e": pod::trigger_userinput,
-        "q": pod::handle_quit,
-        "n": pod::trigger_namespace_select,
-        "j": pod::select_next_item,
-        "k": pod::select_prev_item,
-        "l": pod::show_pod_log,
-        "Esc": pod::handle_esc_key,
-        "Enter": pod::handle_enter_key
-    };
-    pub static ref NODE_KEYMAPS: HashMap<&'static str, Handler> = key_binding! {
-        "e": pod::trigger_userinput,
-        "q": pod::handle_quit,
-        "n": pod::trigger_namespace_select,
-        "j": pod::select_next_item,
-        "k": pod::select_prev_item,
-        "l": pod::show_pod_log,
-        "Esc": pod::handle_esc_key,
-        "Enter": pod::handle_enter_key
+    // handled before any tabpage keymap
+    pub static ref GLOBAL_KEYMAPS: KeyMap = key_binding! {
+        "Tab": help::next_tabpage => "next tab",
+        "?": help::toggle_help => "show help",
+        "!": alert::toggle_alerts => "alert history",
+        "&": jobs::toggle_jobs => "background jobs",
+        "`": debug::toggle_debug => "debug log",
+        "/": search::open_search => "search everything"
+    };
+    // also global, left out of the hint line, the pinned list shows the numbers
+    pub static ref FAVORITE_KEYMAPS: KeyMap = key_binding! {
+        "1": favorite::jump_1 => "open favorite 1",
+        "2": favorite::jump_2 => "open favorite 2",
+        "3": favorite::jump_3 => "open favorite 3",
+        "4": favorite::jump_4 => "open favorite 4",
+        "5": favorite::jump_5 => "open favorite 5",
+        "6": favorite::jump_6 => "open favorite 6",
+        "7": favorite::jump_7 => "open favorite 7",
+        "8": favorite::jump_8 => "open favorite 8",
+        "9": favorite::jump_9 => "open favorite 9"
+    };
+    pub static ref INPUT_KEYMAPS: KeyMap = key_binding! {
+        "Backspace": input::delete_char => "delete char",
+        "Enter": input::complete => "finish input",
+        "Esc": input::complete => "finish input"
+    };
+    pub static ref HELP_KEYMAPS: KeyMap = key_binding! {
+        "j": help::scroll_down => "scroll down",
+        "k": help::scroll_up => "scroll up",
+        "?": help::toggle_help => "close help",
+        "Esc": help::toggle_help => "close help"
+    };
+    pub static ref ALERT_KEYMAPS: KeyMap = key_binding! {
+        "j": help::scroll_down => "scroll down",
+        "k": help::scroll_up => "scroll up",
+        "c": alert::clear_alerts => "clear history",
+        "!": alert::toggle_alerts => "close alerts",
+        "Esc": alert::toggle_alerts => "close alerts"
+    };
+    pub static ref JOBS_KEYMAPS: KeyMap = key_binding! {
+        "j": jobs::select_next_job => "down",
+        "k": jobs::select_prev_job => "up",
+        "x": jobs::kill_job => "kill job",
+        "c": jobs::clear_finished => "clear finished",
+        "&": jobs::toggle_jobs => "close jobs",
+        "Esc": jobs::toggle_jobs => "close jobs"
+    };
+    pub static ref DEBUG_KEYMAPS: KeyMap = key_binding! {
+        "j": help::scroll_down => "scroll down",
+        "k": help::scroll_up => "scroll up",
+        "c": debug::clear_records => "clear",
+        "`": debug::toggle_debug => "close debug log",
+        "Esc": debug::toggle_debug => "close debug log"
+    };
+    pub static ref YANK_KEYMAPS: KeyMap = key_binding! {
+        "y": yank::yank_name => "pod name",
+        "s": yank::yank_full_name => "namespace/name",
+        "m": yank::yank_yaml => "yaml manifest",
+        "d": yank::yank_describe => "describe",
+        "l": yank::yank_log_lines => "log buffer" in [Route::PodLog],
+        "Esc": yank::toggle_yank => "cancel"
     };
+    // typed keys go to the query first, these work once it is finished
+    pub static ref SEARCH_KEYMAPS: KeyMap = key_binding! {
+        "j": search::select_next_hit => "down",
+        "k": search::select_prev_hit => "up",
+        "Enter": search::jump_to_hit => "open",
+        "/": search::edit_query => "edit query",
+        "Esc": search::close_search => "close search"
+    };
+    pub static ref CLUSTER_KEYMAPS: KeyMap = key_binding! {
+        "j": favorite::select_next_item => "down",
+        "k": favorite::select_prev_item => "up",
+        "Enter": favorite::open_selected => "open favorite",
+        "d": favorite::unpin_selected => "unpin",
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref POD_KEYMAPS: KeyMap = key_binding! {
+        "e": pod::trigger_userinput => "filter pods" in [Route::PodIndex, Route::PodList, Route::PodState],
+        "n": pod::trigger_namespace_select => "select namespace",
+        "j": pod::select_next_item => "down" in [Route::PodNamespace, Route::PodIndex, Route::PodList, Route::PodDiff, Route::PodDebug, Route::PodFiles],
+        "k": pod::select_prev_item => "up" in [Route::PodNamespace, Route::PodIndex, Route::PodList, Route::PodDiff, Route::PodDebug, Route::PodFiles],
+        "l": pod::show_pod_log => "show pod log" needs [access::READ_LOGS],
+        "c": pod::show_capacity => "capacity review",
+        "m": pod::mark_for_diff => "mark for diff" in [Route::PodIndex, Route::PodList, Route::PodState],
+        "d": pod::show_diff => "diff with marked/previous" in [Route::PodIndex, Route::PodList, Route::PodState],
+        "x": pod::trigger_debug => "debug container" in [Route::PodIndex, Route::PodList, Route::PodState] needs [access::DEBUG],
+        "b": pod::browse_files => "browse files" in [Route::PodIndex, Route::PodList, Route::PodState] needs [access::EXEC],
+        "g": pod::download_entry => "download" in [Route::PodFiles],
+        "u": pod::trigger_upload => "upload" in [Route::PodFiles],
+        "o": pod::show_in_tree => "show in workload tree" in [Route::PodIndex, Route::PodList],
+        "f": favorite::toggle_favorite => "pin/unpin" in [Route::PodNamespace, Route::PodIndex, Route::PodList],
+        "y": yank::toggle_yank => "copy" in [Route::PodIndex, Route::PodList, Route::PodState, Route::PodLog, Route::PodCapacity],
+        "Enter": pod::handle_enter_key => "confirm" in [Route::PodNamespace, Route::PodDebug, Route::PodTerm, Route::PodFiles, Route::PodUpload],
+        "Esc": pod::handle_esc_key => "back to describe",
+        // the namespace popup is closed with Esc, q there would quit by surprise
+        "q": pod::handle_quit => "quit" in [Route::PodIndex, Route::PodList, Route::PodState, Route::PodLog, Route::PodTerm, Route::PodCapacity, Route::PodDiff, Route::PodDebug, Route::PodFiles, Route::PodUpload]
+    };
+    pub static ref DEPLOYMENT_KEYMAPS: KeyMap = key_binding! {
+        "j": workload::select_next_item => "down" in [Route::DeployIndex, Route::DeployHistory],
+        "k": workload::select_prev_item => "up" in [Route::DeployIndex, Route::DeployHistory],
+        "Space": workload::toggle_collapse => "fold" in [Route::DeployIndex],
+        "u": workload::goto_owner => "go to owner" in [Route::DeployIndex],
+        "b": workload::go_back => "back" in [Route::DeployIndex],
+        "n": workload::next_namespace => "next namespace" in [Route::DeployIndex],
+        "p": workload::undo_rollout => "undo to previous revision" in [Route::DeployIndex] needs [access::UPDATE_DEPLOYMENTS],
+        "h": workload::show_history => "rollout history" in [Route::DeployIndex],
+        "r": workload::restart_rollout => "rollout restart" in [Route::DeployIndex] needs [access::PATCH_DEPLOYMENTS],
+        "s": workload::trigger_scale => "scale" in [Route::DeployIndex] needs [access::PATCH_DEPLOYMENTS],
+        "f": favorite::toggle_favorite => "pin/unpin" in [Route::DeployIndex],
+        "Enter": workload::handle_enter_key => "confirm" in [Route::DeployHistory, Route::DeployScale],
+        "Esc": workload::back_to_tree => "back to tree" in [Route::DeployHistory, Route::DeployScale],
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref NODE_KEYMAPS: KeyMap = key_binding! {
+        "j": node::select_next_node => "down" in [Route::NodeIndex],
+        "k": node::select_prev_node => "up" in [Route::NodeIndex],
+        "c": node::cordon_node => "cordon node" in [Route::NodeIndex] needs [access::PATCH_NODES],
+        "u": node::uncordon_node => "uncordon node" in [Route::NodeIndex] needs [access::PATCH_NODES],
+        "d": node::trigger_drain => "drain node" in [Route::NodeIndex] needs [access::PATCH_NODES],
+        "y": node::confirm_drain => "confirm drain" in [Route::NodeDrainConfirm],
+        "x": node::cancel_drain => "cancel drain" in [Route::NodeDrain],
+        "Esc": node::handle_esc_key => "back to nodes" in [Route::NodeDrainConfirm, Route::NodeDrain],
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref SERVICE_KEYMAPS: KeyMap = key_binding! {
+        "j": service::select_next_service => "down",
+        "k": service::select_prev_service => "up",
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref CONFIG_KEYMAPS: KeyMap = key_binding! {
+        "j": config::select_next_item => "down",
+        "k": config::select_prev_item => "up",
+        "n": config::next_namespace => "next namespace" in [Route::ConfigIndex],
+        "Enter": config::handle_enter_key => "show keys" in [Route::ConfigIndex],
+        "v": config::toggle_reveal => "reveal value" in [Route::ConfigKeys],
+        "y": config::copy_value => "copy value" in [Route::ConfigKeys],
+        "Esc": config::handle_esc_key => "back" in [Route::ConfigKeys],
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref BATCH_KEYMAPS: KeyMap = key_binding! {
+        "j": batch::select_next_item => "down",
+        "k": batch::select_prev_item => "up",
+        "n": batch::next_namespace => "next namespace",
+        "r": batch::run_now => "run cronjob now" needs [access::CREATE_JOBS],
+        // cronjobs and jobs share the page, toggle_suspend checks the permission of the selected kind
+        "s": batch::toggle_suspend => "suspend/resume",
+        "l": batch::show_latest_logs => "logs of last run" needs [access::READ_LOGS],
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref STORAGE_KEYMAPS: KeyMap = key_binding! {
+        "j": storage::select_next_item => "down",
+        "k": storage::select_prev_item => "up",
+        "n": storage::next_namespace => "next namespace" in [Route::StorageClaims],
+        "v": storage::toggle_view => "claims/volumes",
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref AUTOSCALE_KEYMAPS: KeyMap = key_binding! {
+        "j": autoscale::select_next_item => "down",
+        "k": autoscale::select_prev_item => "up",
+        "n": autoscale::next_namespace => "next namespace",
+        "t": autoscale::show_target => "show scale target",
+        "q": pod::handle_quit => "quit"
+    };
+    pub static ref RESOURCE_KEYMAPS: KeyMap = key_binding! {
+        "j": resource::select_next_item => "down",
+        "k": resource::select_prev_item => "up",
+        "Enter": resource::handle_enter_key => "open" in [Route::ResourceKinds, Route::ResourceIndex],
+        "Esc": resource::handle_esc_key => "back" in [Route::ResourceIndex, Route::ResourceYaml],
+        "r": resource::refresh_kinds => "rediscover kinds" in [Route::ResourceKinds],
+        "y": resource::copy_yaml => "copy yaml" in [Route::ResourceIndex, Route::ResourceYaml],
+        "q": pod::handle_quit => "quit"
+    };
+}
+
+pub fn tabpage_keymap(tabpage: TabPage) -> &'static KeyMap {
+    match tabpage {
+        TabPage::Cluster => &CLUSTER_KEYMAPS,
+        TabPage::Pod => &POD_KEYMAPS,
+        TabPage::Deploy => &DEPLOYMENT_KEYMAPS,
+        TabPage::Node => &NODE_KEYMAPS,
+        TabPage::Service => &SERVICE_KEYMAPS,
+        TabPage::Config => &CONFIG_KEYMAPS,
+        TabPage::Batch => &BATCH_KEYMAPS,
+        TabPage::Storage => &STORAGE_KEYMAPS,
+        TabPage::Autoscale => &AUTOSCALE_KEYMAPS,
+        TabPage::Resource => &RESOURCE_KEYMAPS,
+    }
+}
+
+pub fn overlay_keymap(overlay: Overlay) -> &'static KeyMap {
+    match overlay {
+        Overlay::Help => &HELP_KEYMAPS,
+        Overlay::Alerts => &ALERT_KEYMAPS,
+        Overlay::Jobs => &JOBS_KEYMAPS,
+        Overlay::Debug => &DEBUG_KEYMAPS,
+        Overlay::Yank => &YANK_KEYMAPS,
+        Overlay::Search => &SEARCH_KEYMAPS,
+    }
+}
+
+// every binding the user can press right now, global ones first
+pub fn valid_bindings(tabpage: TabPage, route: Route, mode: Mode) -> Vec<&'static KeyBinding> {
+    match mode {
+        Mode::Insert => INPUT_KEYMAPS.valid_bindings(route).collect(),
+        Mode::Normal => GLOBAL_KEYMAPS
+            .valid_bindings(route)
+            .chain(tabpage_keymap(tabpage).valid_bindings(route))
+            .collect(),
+    }
 }
@@ -1,2 +1,31 @@
+pub(crate) mod alert;
+pub(crate) mod autoscale;
+pub(crate) mod batch;
+pub(crate) mod config;
+pub(crate) mod debug;
+pub(crate) mod favorite;
+pub(crate) mod help;
+pub(crate) mod input;
+pub(crate) mod jobs;
 pub(crate) mod keybind;
+pub(crate) mod mouse;
+pub(crate) mod node;
 pub(crate) mod pod;
+pub(crate) mod resource;
+pub(crate) mod search;
+pub(crate) mod service;
+pub(crate) mod storage;
+pub(crate) mod workload;
+pub(crate) mod yank;
+
+// result of a background action for the header, failures also go to the log and the debug pane
+pub(crate) async fn report(
+    status_message: &tokio::sync::RwLock<String>,
+    result: std::result::Result<String, String>,
+) {
+    let message = result.unwrap_or_else(|err| {
+        tracing::warn!("{}", err);
+        err
+    });
+    *status_message.write().await = message;
+}
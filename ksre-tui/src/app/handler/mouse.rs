@@ -0,0 +1,152 @@
+use crossterm::event::{MouseButton, MouseEvent, MouseEventKind};
+use ratatui::layout::Rect;
+
+use crate::app::state::{AppState, Divider, Executor, Route};
+
+// panes can not be dragged smaller than this percentage
+const MIN_SPLIT: u16 = 20;
+const MAX_SPLIT: u16 = 80;
+const SCROLL_STEP: u16 = 3;
+
+pub fn handle_mouse_event(app_state: &mut AppState, event: MouseEvent) -> Option<&mut Executor> {
+    let (x, y) = (event.column, event.row);
+    match event.kind {
+        MouseEventKind::Down(MouseButton::Left) => on_left_click(app_state, x, y),
+        MouseEventKind::Drag(MouseButton::Left) => on_drag(app_state, x, y),
+        MouseEventKind::Up(MouseButton::Left) => app_state.ui_layout.dragging = None,
+        MouseEventKind::ScrollDown => on_scroll(app_state, x, y, true),
+        MouseEventKind::ScrollUp => on_scroll(app_state, x, y, false),
+        _ => {}
+    }
+    None
+}
+
+fn on_left_click(app_state: &mut AppState, x: u16, y: u16) {
+    let layout = &app_state.ui_layout;
+    if let Some(&(_, tabpage)) = layout.tabs.iter().find(|(area, _)| contains(*area, x, y)) {
+        if tabpage != app_state.get_tabpage() {
+            app_state.switch_tabpage(tabpage);
+        }
+        return;
+    }
+
+    // dividers are the two touching borders between panes
+    if contains(layout.page_body, x, y) {
+        if y == layout.divider_row || y + 1 == layout.divider_row {
+            app_state.ui_layout.dragging = Some(Divider::TopBottom);
+            return;
+        }
+        if y < layout.divider_row && (x == layout.divider_col || x + 1 == layout.divider_col) {
+            app_state.ui_layout.dragging = Some(Divider::LeftRight);
+            return;
+        }
+    }
+
+    // rows start below the top border
+    if let Some(row) = list_row(layout.namespace_list, x, y, 1) {
+        app_state.namespace_cache.select(row);
+        app_state.namespace_cache.confirm();
+        app_state.set_route(Route::PodList);
+        return;
+    }
+
+    // node table has one header line too, and may be scrolled
+    if let Some(row) = list_row(layout.node_list, x, y, 2) {
+        let offset = layout.node_list_offset;
+        app_state.nodes_cache.select(row + offset);
+        return;
+    }
+
+    // pod list has one extra header line
+    if let Some(row) = list_row(layout.pod_list, x, y, 2) {
+        let offset = layout.pod_list_offset;
+        app_state.cache_items.select(row + offset);
+        app_state.describe_scroll = 0;
+        if let Route::PodNamespace = app_state.get_route() {
+            app_state.set_route(Route::PodList);
+        }
+    }
+}
+
+fn on_drag(app_state: &mut AppState, x: u16, y: u16) {
+    let layout = &mut app_state.ui_layout;
+    let page = layout.page_body;
+    match layout.dragging {
+        Some(Divider::TopBottom) if page.height > 0 => {
+            layout.split_vertical = percentage(y.saturating_sub(page.y), page.height);
+        }
+        Some(Divider::LeftRight) if page.width > 0 => {
+            layout.split_horizontal = percentage(x.saturating_sub(page.x), page.width);
+        }
+        _ => {}
+    }
+}
+
+fn on_scroll(app_state: &mut AppState, x: u16, y: u16, down: bool) {
+    let layout = &app_state.ui_layout;
+    if contains(layout.namespace_list, x, y) {
+        if down {
+            app_state.namespace_cache.next();
+        } else {
+            app_state.namespace_cache.prev();
+        }
+    } else if contains(layout.pod_list, x, y) {
+        if down {
+            app_state.cache_items.next();
+        } else {
+            app_state.cache_items.prev();
+        }
+    } else if contains(layout.node_list, x, y) {
+        if down {
+            app_state.nodes_cache.next();
+        } else {
+            app_state.nodes_cache.prev();
+        }
+    } else if contains(layout.bottom_body, x, y) {
+        match app_state.get_route() {
+            Route::PodLog => scroll_logs(app_state, down),
+            _ => {
+                app_state.describe_scroll = if down {
+                    app_state.describe_scroll.saturating_add(SCROLL_STEP)
+                } else {
+                    app_state.describe_scroll.saturating_sub(SCROLL_STEP)
+                };
+            }
+        }
+    }
+}
+
+// scrolling up freezes the log pane, scrolling back past the tail follows new lines again
+fn scroll_logs(app_state: &mut AppState, down: bool) {
+    let visible = app_state.ui_layout.bottom_body.height.saturating_sub(2);
+    let tail = match app_state.stdout_buffer.try_read() {
+        Ok(buffer) => (buffer.lines().len() as u16).saturating_sub(visible),
+        Err(_) => return,
+    };
+    let current = app_state.log_scroll.unwrap_or(tail);
+    app_state.log_scroll = if down {
+        let next = current.saturating_add(SCROLL_STEP);
+        (next < tail).then_some(next)
+    } else {
+        Some(current.saturating_sub(SCROLL_STEP))
+    };
+}
+
+#[inline]
+fn contains(area: Rect, x: u16, y: u16) -> bool {
+    x >= area.x && x < area.x + area.width && y >= area.y && y < area.y + area.height
+}
+
+#[inline]
+fn list_row(area: Rect, x: u16, y: u16, header: u16) -> Option<usize> {
+    if contains(area, x, y) && y >= area.y + header {
+        Some((y - area.y - header) as usize)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn percentage(offset: u16, total: u16) -> u16 {
+    ((offset as u32 * 100 / total as u32) as u16).clamp(MIN_SPLIT, MAX_SPLIT)
+}
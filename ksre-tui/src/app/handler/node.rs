@@ -0,0 +1,161 @@
+use std::{collections::BTreeSet, sync::Arc, time::Duration};
+
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    app::{
+        handler::report,
+        job::{
+            node_drain::{self, DrainProgress},
+            JobKind,
+        },
+        state::{AppState, Executor, Route},
+    },
+    kubernetes::api::access,
+};
+
+pub fn select_next_node(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.nodes_cache.next();
+    None
+}
+
+pub fn select_prev_node(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.nodes_cache.prev();
+    None
+}
+
+pub fn cordon_node(app_state: &mut AppState) -> Option<&mut Executor> {
+    spawn_cordon(app_state, true);
+    None
+}
+
+pub fn uncordon_node(app_state: &mut AppState) -> Option<&mut Executor> {
+    spawn_cordon(app_state, false);
+    None
+}
+
+// drain only asks for confirmation, the drain itself is started by confirm_drain
+pub fn trigger_drain(app_state: &mut AppState) -> Option<&mut Executor> {
+    let running = app_state
+        .drain_progress
+        .as_ref()
+        .and_then(|progress| progress.try_read().ok().map(|x| x.is_running()))
+        .unwrap_or(false);
+    if running {
+        app_state.set_route(Route::NodeDrain);
+    } else if let Some(node_name) = app_state.nodes_cache.get() {
+        // reviewed while the user reads the confirmation, confirm_drain checks them
+        for namespace in pod_namespaces(app_state, node_name.as_ref()) {
+            app_state.review_permissions(&namespace);
+        }
+        app_state.set_route(Route::NodeDrainConfirm);
+    }
+    None
+}
+
+// namespaces of the watched pods a drain of the node evicts
+fn pod_namespaces(app_state: &AppState, node_name: &str) -> BTreeSet<String> {
+    app_state
+        .pod_storage
+        .iter()
+        .filter(|pod| pod.0.spec.node_name.as_deref() == Some(node_name))
+        .filter(|pod| node_drain::skip_reason(&pod.0.metadata).is_none())
+        .filter_map(|pod| pod.0.metadata.namespace.clone())
+        .collect()
+}
+
+pub fn confirm_drain(app_state: &mut AppState) -> Option<&mut Executor> {
+    let Some(node_name) = app_state.nodes_cache.get() else {
+        app_state.set_route(Route::NodeIndex);
+        return None;
+    };
+    // the node is cordoned first, better to refuse before than to leave it half drained
+    let forbidden = pod_namespaces(app_state, node_name.as_ref())
+        .into_iter()
+        .filter(|namespace| !app_state.is_permitted_in(namespace, &access::EVICT))
+        .collect::<Vec<String>>();
+    if !forbidden.is_empty() {
+        app_state.set_route(Route::NodeIndex);
+        if let Ok(mut status_message) = app_state.status_message.try_write() {
+            *status_message = format!("evicting pods is forbidden in {}", forbidden.join(", "));
+        }
+        return None;
+    }
+    app_state.set_route(Route::NodeDrain);
+
+    // KSRE_DRAIN_TIMEOUT is in seconds, drain waits forever when it is absent
+    let timeout = std::env::var("KSRE_DRAIN_TIMEOUT")
+        .ok()
+        .and_then(|x| x.parse::<u64>().ok())
+        .map(Duration::from_secs);
+    let progress = Arc::new(RwLock::new(DrainProgress::new(node_name.as_ref())));
+    let cancellation_token = CancellationToken::new();
+    // failures are reported in the drain progress, the job itself always ends well
+    let drain = node_drain::drain_node(
+        cancellation_token.clone(),
+        app_state.kube_client(),
+        progress.clone(),
+        node_name.to_string(),
+        timeout,
+    );
+    app_state.jobs.spawn(
+        JobKind::NodeDrain,
+        node_name.as_ref(),
+        cancellation_token.clone(),
+        async move {
+            drain.await;
+            Ok(())
+        },
+    );
+    app_state.drain_progress = Some(progress);
+    app_state.node_drain = Some(Executor {
+        normal_task: None,
+        stop_fn: Some(cancellation_token),
+        async_task: None,
+        _type: false,
+    });
+    None
+}
+
+pub fn cancel_drain(app_state: &mut AppState) -> Option<&mut Executor> {
+    if let Some(executor) = app_state.node_drain.as_ref() {
+        if let Some(cancellation_token) = executor.stop_fn.as_ref() {
+            cancellation_token.cancel();
+        }
+    }
+    None
+}
+
+pub fn handle_esc_key(app_state: &mut AppState) -> Option<&mut Executor> {
+    // finished drain is forgotten once its progress was seen
+    if let Route::NodeDrain = app_state.get_route() {
+        let finished = app_state
+            .drain_progress
+            .as_ref()
+            .and_then(|progress| progress.try_read().ok().map(|x| !x.is_running()))
+            .unwrap_or(true);
+        if finished {
+            app_state.node_drain.take();
+            app_state.drain_progress.take();
+        }
+    }
+    app_state.set_route(Route::NodeIndex);
+    None
+}
+
+fn spawn_cordon(app_state: &mut AppState, unschedulable: bool) {
+    let Some(node_name) = app_state.nodes_cache.get().map(|x| x.to_string()) else {
+        return;
+    };
+    let kube_client = app_state.kube_client();
+    let status_message = app_state.status_message.clone();
+    let action = if unschedulable { "cordon" } else { "uncordon" };
+    tokio::spawn(async move {
+        let message = match node_drain::cordon_node(kube_client, &node_name, unschedulable).await {
+            Ok(_) => Ok(format!("{} node {} done", action, node_name)),
+            Err(err) => Err(format!("{} node {} failed: {}", action, node_name, err)),
+        };
+        report(&status_message, message).await;
+    });
+}
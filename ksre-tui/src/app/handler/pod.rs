@@ -1,10 +1,17 @@
-use tokio::sync::mpsc;
+use std::{path::PathBuf, rc::Rc, sync::Arc};
+
+use tokio::sync::{mpsc, RwLock};
 use tokio_util::sync::CancellationToken;
 
 use crate::app::state::Route;
+
 use crate::app::{
-    job::pod_log,
-    state::{AppState, Executor, Mode},
+    job::{
+        pod_cp::{self, RemoteDir, RemoteTarget},
+        pod_debug::{self, DebugArgs, DEFAULT_DEBUG_IMAGE},
+        pod_log, JobKind,
+    },
+    state::{AppState, DiffView, Executor, Mode, TabPage},
 };
 
 pub fn handle_quit(app_state: &mut AppState) -> Option<&mut Executor> {
@@ -18,15 +25,40 @@ pub fn handle_esc_key(app_state: &mut AppState) -> Option<&mut Executor> {
             app_state.stop_executor();
             app_state.set_route(Route::PodIndex);
         }
+        // leaving the terminal ends the debug session, the ephemeral container stays
+        Route::PodTerm => {
+            app_state.stop_executor();
+            app_state.term_input = None;
+            app_state.user_input.clear();
+            app_state.set_route(Route::PodIndex);
+        }
+        Route::PodUpload => {
+            app_state.user_input.clear();
+            app_state.set_route(Route::PodFiles);
+        }
+        Route::PodFiles => {
+            app_state.remote_dir = None;
+            app_state.set_route(Route::PodIndex);
+        }
         _ => app_state.set_route(Route::PodIndex),
     }
     None
 }
 
 pub fn handle_enter_key(app_state: &mut AppState) -> Option<&mut Executor> {
-    if let Route::PodNamespace = app_state.get_route() {
-        app_state.namespace_cache.confirm();
-        app_state.set_route(Route::PodList);
+    match app_state.get_route() {
+        Route::PodNamespace => {
+            app_state.namespace_cache.confirm();
+            app_state.set_route(Route::PodList);
+        }
+        Route::PodDebug => {
+            let target = app_state.debug_targets.get()?;
+            return start_debug(app_state, target.to_string());
+        }
+        Route::PodTerm => send_term_input(app_state),
+        Route::PodFiles => open_entry(app_state),
+        Route::PodUpload => start_upload(app_state),
+        _ => {}
     }
     None
 }
@@ -56,6 +88,11 @@ pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
         Route::PodIndex | Route::PodList => {
             app_state.cache_items.next();
         }
+        Route::PodDebug => app_state.debug_targets.next(),
+        Route::PodFiles => with_remote_dir(app_state, RemoteDir::next),
+        Route::PodDiff => {
+            app_state.describe_scroll = app_state.describe_scroll.saturating_add(1);
+        }
         _ => {}
     }
     None
@@ -69,46 +106,349 @@ pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
         Route::PodIndex | Route::PodList => {
             app_state.cache_items.prev();
         }
+        Route::PodDebug => app_state.debug_targets.prev(),
+        Route::PodFiles => with_remote_dir(app_state, RemoteDir::prev),
+        Route::PodDiff => {
+            app_state.describe_scroll = app_state.describe_scroll.saturating_sub(1);
+        }
         _ => {}
     }
     None
 }
 
 pub fn show_pod_log(app_state: &mut AppState) -> Option<&mut Executor> {
-    if let Some(pod_name) = app_state.cache_items.get() {
-        app_state.set_route(Route::PodLog);
-        let cancellation_token = CancellationToken::default();
-        let kube_client = app_state.kube_client();
-        let namespace = app_state.namespace_cache.get().unwrap();
-        let (log_writer_tx, mut log_reader_rx): (mpsc::Sender<String>, mpsc::Receiver<String>) =
-            mpsc::channel(10);
-        let task0 = tokio::spawn(pod_log::tail_logs(
+    let pod_name = app_state.cache_items.get()?;
+    tail_pod_log(app_state, pod_name.as_ref())
+}
+
+// follow the log of a pod in the selected namespace, also used by other tabpages
+pub fn tail_pod_log<'a>(app_state: &'a mut AppState, pod_name: &str) -> Option<&'a mut Executor> {
+    app_state.set_route(Route::PodLog);
+    app_state.log_scroll = None;
+    let cancellation_token = CancellationToken::default();
+    let kube_client = app_state.kube_client();
+    let namespace = app_state.namespace_cache.get().unwrap();
+    let (log_writer_tx, mut log_reader_rx): (mpsc::Sender<String>, mpsc::Receiver<String>) =
+        mpsc::channel(10);
+    app_state.jobs.spawn(
+        JobKind::PodLog,
+        format!("{}/{}", namespace, pod_name),
+        cancellation_token.clone(),
+        pod_log::tail_logs(
             cancellation_token.clone(),
             kube_client,
             log_writer_tx,
             pod_name.to_string(),
             namespace.to_string(),
-        ));
-        let writer = app_state.stdout_buffer.clone();
-        let task1 = tokio::spawn(async move {
-            {
-                writer.write().await.select_all();
-                writer.write().await.cut();
-            }
-            while let Some(line) = log_reader_rx.recv().await {
-                writer.write().await.insert_str(line.as_str());
-                writer.write().await.insert_newline();
-            }
-        });
-        let executor = Executor {
-            normal_task: None,
-            stop_fn: Some(cancellation_token),
-            async_task: Some(vec![task0, task1]),
-            _type: false,
-        };
-        app_state.executor = Some(executor);
-        app_state.executor.as_mut()
+        ),
+    );
+    let writer = app_state.stdout_buffer.clone();
+    let task1 = tokio::spawn(async move {
+        {
+            writer.write().await.select_all();
+            writer.write().await.cut();
+        }
+        while let Some(line) = log_reader_rx.recv().await {
+            writer.write().await.insert_str(line.as_str());
+            writer.write().await.insert_newline();
+        }
+    });
+    let executor = Executor {
+        normal_task: None,
+        stop_fn: Some(cancellation_token),
+        async_task: Some(vec![task1]),
+        _type: false,
+    };
+    app_state.executor = Some(executor);
+    app_state.executor.as_mut()
+}
+
+pub fn show_capacity(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.stop_executor();
+    app_state.set_route(Route::PodCapacity);
+    None
+}
+
+// distroless images have no shell to exec, a debug container brings its own.
+// with several containers the one whose processes to share is picked first
+pub fn trigger_debug(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (namespace, pod_name) = selected_pod_key(app_state)?;
+    let pod = app_state.pod_storage.get(&namespace, &pod_name)?;
+    let containers = pod
+        .0
+        .spec
+        .containers
+        .iter()
+        .map(|x| Rc::from(x.name.as_str()))
+        .collect::<Vec<Rc<str>>>();
+    if let [container] = containers.as_slice() {
+        return start_debug(app_state, container.to_string());
+    }
+    app_state.debug_targets.replace(containers);
+    app_state.debug_targets.reindex();
+    app_state.set_route(Route::PodDebug);
+    None
+}
+
+// KSRE_DEBUG_IMAGE overrides the image of the debug container
+fn start_debug(app_state: &mut AppState, target: String) -> Option<&mut Executor> {
+    let (namespace, pod_name) = selected_pod_key(app_state)?;
+    app_state.stop_executor();
+    let image =
+        std::env::var("KSRE_DEBUG_IMAGE").unwrap_or_else(|_| DEFAULT_DEBUG_IMAGE.to_string());
+    let writer = app_state.stdout_buffer.clone();
+    if let Ok(mut buffer) = writer.try_write() {
+        buffer.select_all();
+        buffer.cut();
+    }
+    let (input_tx, input_rx) = mpsc::channel(10);
+    let cancellation_token = CancellationToken::new();
+    app_state.jobs.spawn(
+        JobKind::PodExec,
+        format!("{}/{}:{}", namespace, pod_name, target),
+        cancellation_token.clone(),
+        pod_debug::debug_pod(
+            cancellation_token.clone(),
+            writer,
+            input_rx,
+            DebugArgs {
+                kube_client: app_state.kube_client(),
+                namespace,
+                pod_name,
+                target,
+                image,
+            },
+        ),
+    );
+    app_state.term_input = Some(input_tx);
+    app_state.user_input.clear();
+    app_state.set_route(Route::PodTerm);
+    app_state.set_mode(Mode::Insert);
+    app_state.executor = Some(Executor {
+        normal_task: None,
+        stop_fn: Some(cancellation_token),
+        async_task: None,
+        _type: false,
+    });
+    app_state.executor.as_mut()
+}
+
+// the typed line is echoed and sent, the input line stays open for the next one
+fn send_term_input(app_state: &mut AppState) {
+    let line = app_state.user_input.as_str().to_string();
+    app_state.user_input.clear();
+    app_state.set_mode(Mode::Insert);
+    let Some(input) = app_state.term_input.as_ref() else {
+        return;
+    };
+    if input.try_send(line.clone()).is_err() {
+        if let Ok(mut status_message) = app_state.status_message.try_write() {
+            *status_message = "debug session is not running".to_string();
+        }
+        return;
+    }
+    if let Ok(mut buffer) = app_state.stdout_buffer.try_write() {
+        buffer.insert_str(format!("$ {}", line));
+        buffer.insert_newline();
+    }
+}
+
+// browse the files of the default container, e.g. to fetch a heap dump or to drop a config in
+pub fn browse_files(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (namespace, pod_name) = selected_pod_key(app_state)?;
+    let pod = app_state.pod_storage.get(&namespace, &pod_name)?;
+    let container = pod
+        .0
+        .metadata
+        .annotations
+        .as_ref()
+        .and_then(|x| x.get("kubectl.kubernetes.io/default-container").cloned())
+        .or_else(|| pod.0.spec.containers.first().map(|x| x.name.clone()))?;
+    let target = RemoteTarget {
+        namespace,
+        pod_name,
+        container,
+    };
+    let dir = Arc::new(RwLock::new(RemoteDir::new(target, "/")));
+    spawn_list_dir(app_state, dir.clone(), "/".to_string());
+    app_state.stop_executor();
+    app_state.remote_dir = Some(dir);
+    app_state.set_route(Route::PodFiles);
+    None
+}
+
+#[inline]
+fn with_remote_dir(app_state: &mut AppState, f: fn(&mut RemoteDir)) {
+    if let Some(mut dir) = app_state
+        .remote_dir
+        .as_ref()
+        .and_then(|x| x.try_write().ok())
+    {
+        f(&mut dir);
+    }
+}
+
+// the selected entry and its full path, None while a listing runs
+fn selected_entry(app_state: &AppState) -> Option<(RemoteTarget, String, String)> {
+    let dir = app_state.remote_dir.as_ref()?.try_read().ok()?;
+    if dir.loading {
+        return None;
+    }
+    let entry = dir.selected()?;
+    Some((dir.target.clone(), entry.clone(), dir.child(entry)))
+}
+
+fn open_entry(app_state: &mut AppState) {
+    let Some((_, entry, path)) = selected_entry(app_state) else {
+        return;
+    };
+    let Some(dir) = app_state
+        .remote_dir
+        .clone()
+        .filter(|_| entry.ends_with('/'))
+    else {
+        return;
+    };
+    spawn_list_dir(app_state, dir, path);
+}
+
+// a listing of a hanging container shows in the jobs pane and can be stopped there
+fn spawn_list_dir(app_state: &mut AppState, dir: Arc<RwLock<RemoteDir>>, path: String) {
+    let target = match dir.try_read() {
+        Ok(dir) => format!("{}/{}:{}", dir.target.namespace, dir.target.pod_name, path),
+        Err(_) => path.clone(),
+    };
+    let cancellation_token = CancellationToken::new();
+    app_state.jobs.spawn(
+        JobKind::Browse,
+        target,
+        cancellation_token.clone(),
+        pod_cp::list_dir(cancellation_token, app_state.kube_client(), dir, path),
+    );
+}
+
+// KSRE_CP_DIR is where downloads are saved, the working directory if unset
+pub fn download_entry(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (target, entry, path) = selected_entry(app_state)?;
+    if entry == "../" {
+        return None;
+    }
+    let is_dir = entry.ends_with('/');
+    let local_dir = std::env::var_os("KSRE_CP_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("."));
+    let cancellation_token = CancellationToken::new();
+    app_state.jobs.spawn(
+        JobKind::Copy,
+        format!("{}/{}:{}", target.namespace, target.pod_name, path),
+        cancellation_token.clone(),
+        pod_cp::download(
+            cancellation_token,
+            app_state.kube_client(),
+            target,
+            path,
+            is_dir,
+            local_dir,
+            app_state.status_message.clone(),
+        ),
+    );
+    None
+}
+
+pub fn trigger_upload(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.remote_dir.as_ref()?;
+    app_state.user_input.clear();
+    app_state.set_route(Route::PodUpload);
+    app_state.set_mode(Mode::Insert);
+    None
+}
+
+// the typed local file goes into the directory being browsed
+fn start_upload(app_state: &mut AppState) {
+    let input = app_state.user_input.as_str().trim().to_string();
+    app_state.user_input.clear();
+    app_state.set_route(Route::PodFiles);
+    let Some(Ok(dir)) = app_state.remote_dir.as_ref().map(|x| x.try_read()) else {
+        return;
+    };
+    if input.is_empty() {
+        return;
+    }
+    let local = match (input.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(&input),
+    };
+    let (target, remote_dir) = (dir.target.clone(), dir.path.clone());
+    drop(dir);
+    let cancellation_token = CancellationToken::new();
+    app_state.jobs.spawn(
+        JobKind::Copy,
+        format!(
+            "{} -> {}/{}:{}",
+            input, target.namespace, target.pod_name, remote_dir
+        ),
+        cancellation_token.clone(),
+        pod_cp::upload(
+            cancellation_token,
+            app_state.kube_client(),
+            target,
+            local,
+            remote_dir,
+            app_state.status_message.clone(),
+        ),
+    );
+}
+
+// m marks the pod to compare with, d on another pod then shows what differs
+pub fn mark_for_diff(app_state: &mut AppState) -> Option<&mut Executor> {
+    let selected = selected_pod_key(app_state)?;
+    let message = if app_state.diff_mark.as_ref() == Some(&selected) {
+        app_state.diff_mark = None;
+        format!("unmarked {}/{}", selected.0, selected.1)
     } else {
-        None
+        let message = format!(
+            "marked {}/{}, d on another pod compares them",
+            selected.0, selected.1
+        );
+        app_state.diff_mark = Some(selected);
+        message
+    };
+    if let Ok(mut status_message) = app_state.status_message.try_write() {
+        *status_message = message;
     }
+    None
+}
+
+// diff against the marked pod, or against the pod's own version before its last update
+pub fn show_diff(app_state: &mut AppState) -> Option<&mut Executor> {
+    let selected = selected_pod_key(app_state)?;
+    let view = match app_state.diff_mark.clone() {
+        Some(mark) if mark != selected => DiffView::Pods(mark, selected),
+        _ => DiffView::Previous(selected.0, selected.1),
+    };
+    app_state.stop_executor();
+    app_state.describe_scroll = 0;
+    app_state.diff_view = Some(view);
+    app_state.set_route(Route::PodDiff);
+    None
+}
+
+#[inline]
+fn selected_pod_key(app_state: &AppState) -> Option<(String, String)> {
+    let namespace = app_state.namespace_cache.get()?;
+    let pod_name = app_state.cache_items.get()?;
+    Some((namespace.to_string(), pod_name.to_string()))
+}
+
+// open the workload tree at the selected pod
+pub fn show_in_tree(app_state: &mut AppState) -> Option<&mut Executor> {
+    let pod_name = app_state.cache_items.get()?;
+    let namespace = app_state.namespace_cache.get()?;
+    let pod = app_state
+        .pod_storage
+        .get(namespace.as_ref(), pod_name.as_ref())?;
+    app_state.workload_trail.clear();
+    app_state.workload_focus = pod.0.metadata.uid.clone();
+    app_state.switch_tabpage(TabPage::Deploy);
+    None
 }
@@ -0,0 +1,115 @@
+use tokio_util::sync::CancellationToken;
+
+use crate::app::{
+    handler::yank,
+    job::JobKind,
+    state::{AppState, Executor, Route},
+};
+use crate::kubernetes::{
+    api::dynamic::{discover_resources, object_yaml},
+    indexer::StoreIndex,
+    reflector::dynamic::DynamicReflector,
+};
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::ResourceKinds => app_state.resource_kinds_cache.next(),
+        Route::ResourceIndex => app_state.resources_cache.next(),
+        Route::ResourceYaml => {
+            app_state.describe_scroll = app_state.describe_scroll.saturating_add(1);
+        }
+        _ => {}
+    }
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::ResourceKinds => app_state.resource_kinds_cache.prev(),
+        Route::ResourceIndex => app_state.resources_cache.prev(),
+        Route::ResourceYaml => {
+            app_state.describe_scroll = app_state.describe_scroll.saturating_sub(1);
+        }
+        _ => {}
+    }
+    None
+}
+
+pub fn handle_enter_key(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::ResourceKinds => watch_selected_kind(app_state),
+        Route::ResourceIndex if app_state.resources_cache.get().is_some() => {
+            app_state.describe_scroll = 0;
+            app_state.set_route(Route::ResourceYaml);
+        }
+        _ => {}
+    }
+    None
+}
+
+pub fn handle_esc_key(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::ResourceYaml => app_state.set_route(Route::ResourceIndex),
+        _ => app_state.set_route(Route::ResourceKinds),
+    }
+    None
+}
+
+pub fn copy_yaml(app_state: &mut AppState) -> Option<&mut Executor> {
+    let key = app_state.resources_cache.get()?;
+    let (namespace, name) = key.split_once('/')?;
+    let obj = app_state.resource_storage.get(namespace, name)?;
+    yank::copy(app_state, name, &object_yaml(&obj));
+    None
+}
+
+pub fn refresh_kinds(app_state: &mut AppState) -> Option<&mut Executor> {
+    spawn_discovery(app_state);
+    None
+}
+
+// discovery walks every api group, it runs in background and fills resource_kinds
+pub fn spawn_discovery(app_state: &mut AppState) {
+    let kube_client = app_state.kube_client();
+    let resource_kinds = app_state.resource_kinds.clone();
+    let status_message = app_state.status_message.clone();
+    let cancel = CancellationToken::new();
+    let token = cancel.clone();
+    let task = async move {
+        // a stopped discovery keeps the kinds found by the last one
+        let kinds = tokio::select! {
+            _ = token.cancelled() => return Ok(()),
+            kinds = discover_resources(kube_client) => kinds?,
+        };
+        *status_message.write().await = format!("discovered {} resource kinds", kinds.len());
+        *resource_kinds.write().await = kinds;
+        Ok(())
+    };
+    app_state
+        .jobs
+        .spawn(JobKind::Discovery, "api groups", cancel, task);
+}
+
+fn watch_selected_kind(app_state: &mut AppState) {
+    let Some(id) = app_state.resource_kinds_cache.get() else {
+        return;
+    };
+    let kind = match app_state.resource_kinds.try_read() {
+        Ok(kinds) => kinds.iter().find(|kind| kind.id() == id.as_ref()).cloned(),
+        Err(_) => return,
+    };
+    let Some(kind) = kind else {
+        return;
+    };
+
+    // objects of the previous kind still in the channel are dropped by kind matching
+    app_state.resource_watch.take();
+    app_state.resource_storage = StoreIndex::new();
+    app_state.resources_cache.reset();
+    app_state.resource_watch = Some(DynamicReflector::new(
+        app_state.kube_client(),
+        kind,
+        app_state.resource_tx.clone(),
+    ));
+    app_state.set_route(Route::ResourceIndex);
+}
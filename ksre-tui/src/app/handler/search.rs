@@ -0,0 +1,309 @@
+use k8s_openapi::api::core::v1::PodSpec;
+
+use crate::app::{
+    search::{Search, SearchEntry},
+    state::{AppState, Executor, Mode, Overlay, Route, TabPage},
+};
+use crate::event::CusKey;
+use crate::kubernetes::api::{autoscale::scale_target, storage::volume_source};
+
+// / searches every cached store, typing narrows the hits right away
+pub fn open_search(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.search = Some(Search::new(build_index(app_state)));
+    app_state.overlay = Some(Overlay::Search);
+    app_state.set_mode(Mode::Insert);
+    None
+}
+
+pub fn close_search(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.search = None;
+    app_state.overlay = None;
+    app_state.set_mode(Mode::Normal);
+    None
+}
+
+pub fn edit_query(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.set_mode(Mode::Insert);
+    None
+}
+
+// keys typed into the query, enter or esc leave it for j/k to pick a hit
+pub fn type_key(app_state: &mut AppState, key: CusKey) {
+    let Some(search) = app_state.search.as_mut() else {
+        return;
+    };
+    match key {
+        CusKey::Enter | CusKey::Esc => {
+            search.input.complete();
+            app_state.set_mode(Mode::Normal);
+            return;
+        }
+        CusKey::Backspace => search.input.pop(),
+        CusKey::None => return,
+        key => search.input.push(key.char()),
+    }
+    search.update();
+}
+
+pub fn select_next_hit(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.search.as_mut()?.next();
+    None
+}
+
+pub fn select_prev_hit(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.search.as_mut()?.prev();
+    None
+}
+
+// open the page of the hit with it selected
+pub fn jump_to_hit(app_state: &mut AppState) -> Option<&mut Executor> {
+    let search = app_state.search.as_ref()?;
+    let entry = search.selected()?;
+    let (kind, namespace, name, uid) = (
+        entry.kind.clone(),
+        entry.namespace.clone(),
+        entry.name.clone(),
+        entry.uid.clone(),
+    );
+    close_search(app_state);
+    match kind.as_str() {
+        "Pod" => {
+            app_state.switch_tabpage(TabPage::Pod);
+            app_state.select_namespace(&namespace);
+            app_state.focus = Some(name);
+        }
+        "Deployment" | "StatefulSet" | "DaemonSet" => {
+            app_state.switch_tabpage(TabPage::Deploy);
+            app_state.select_namespace(&namespace);
+            app_state.workload_trail.clear();
+            app_state.workload_focus = uid;
+        }
+        "Job" | "CronJob" => {
+            app_state.switch_tabpage(TabPage::Batch);
+            app_state.select_namespace(&namespace);
+            app_state.focus = Some(format!("{}/{}", kind, name));
+        }
+        "ConfigMap" | "Secret" => {
+            app_state.switch_tabpage(TabPage::Config);
+            app_state.select_namespace(&namespace);
+            app_state.focus = Some(format!("{}/{}", kind, name));
+        }
+        "Service" => {
+            app_state.switch_tabpage(TabPage::Service);
+            app_state.focus = Some(format!("{}/{}", namespace, name));
+        }
+        "Node" => {
+            app_state.switch_tabpage(TabPage::Node);
+            app_state.focus = Some(name);
+        }
+        "PersistentVolumeClaim" => {
+            app_state.switch_tabpage(TabPage::Storage);
+            app_state.select_namespace(&namespace);
+            app_state.focus = Some(name);
+        }
+        "HorizontalPodAutoscaler" => {
+            app_state.switch_tabpage(TabPage::Autoscale);
+            app_state.select_namespace(&namespace);
+            app_state.focus = Some(name);
+        }
+        "PersistentVolume" => {
+            app_state.switch_tabpage(TabPage::Storage);
+            app_state.set_route(Route::StorageVolumes);
+            app_state.focus = Some(name);
+        }
+        // objects of the kind watched in the resource page
+        _ => {
+            app_state.switch_tabpage(TabPage::Resource);
+            app_state.set_route(Route::ResourceIndex);
+            app_state.focus = Some(format!("{}/{}", namespace, name));
+        }
+    }
+    None
+}
+
+// a snapshot of the stores, objects changing while the overlay is open are not followed
+fn build_index(app_state: &AppState) -> Vec<SearchEntry> {
+    let mut entries = Vec::new();
+    let mut index = |mut kind_entries: Vec<SearchEntry>| {
+        kind_entries.sort_by(|a, b| (&a.namespace, &a.name).cmp(&(&b.namespace, &b.name)));
+        entries.extend(kind_entries);
+    };
+    index(
+        app_state
+            .pod_storage
+            .iter()
+            .map(|x| {
+                let status = x.0.status.as_ref();
+                let ips = status
+                    .and_then(|x| x.pod_ip.clone())
+                    .into_iter()
+                    .chain(status.and_then(|x| x.host_ip.clone()));
+                SearchEntry::new("Pod", &x.0.metadata)
+                    .with("ip", ips)
+                    .with("image", images(Some(&x.0.spec)))
+                    .with("node", x.0.spec.node_name.clone())
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .deployment_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("Deployment", &x.0.metadata)
+                    .with("image", images(x.0.spec.template.spec.as_ref()))
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .statefulset_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("StatefulSet", &x.0.metadata)
+                    .with("image", images(x.0.spec.template.spec.as_ref()))
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .daemonset_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("DaemonSet", &x.0.metadata)
+                    .with("image", images(x.0.spec.template.spec.as_ref()))
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .cronjob_storage
+            .iter()
+            .map(|x| {
+                let template = x.0.spec.job_template.spec.as_ref();
+                SearchEntry::new("CronJob", &x.0.metadata).with(
+                    "image",
+                    images(template.and_then(|x| x.template.spec.as_ref())),
+                )
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .job_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("Job", &x.0.metadata)
+                    .with("image", images(x.0.spec.template.spec.as_ref()))
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .service_storage
+            .iter()
+            .map(|x| {
+                let spec = &x.0.spec;
+                let ingress =
+                    x.0.status
+                        .as_ref()
+                        .and_then(|x| x.load_balancer.as_ref())
+                        .and_then(|x| x.ingress.as_ref())
+                        .into_iter()
+                        .flatten()
+                        .flat_map(|x| x.ip.clone().into_iter().chain(x.hostname.clone()));
+                let ips = spec
+                    .cluster_ips
+                    .iter()
+                    .flatten()
+                    .chain(spec.external_ips.iter().flatten())
+                    .cloned()
+                    .chain(ingress);
+                SearchEntry::new("Service", &x.0.metadata).with("ip", ips)
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .configmap_storage
+            .iter()
+            .map(|x| SearchEntry::new("ConfigMap", &x.0.metadata))
+            .collect(),
+    );
+    index(
+        app_state
+            .secret_storage
+            .iter()
+            .map(|x| SearchEntry::new("Secret", &x.0.metadata))
+            .collect(),
+    );
+    index(
+        app_state
+            .node_storage
+            .iter()
+            .map(|x| {
+                let addresses =
+                    x.0.status
+                        .as_ref()
+                        .and_then(|x| x.addresses.as_ref())
+                        .into_iter()
+                        .flatten()
+                        .map(|x| x.address.clone());
+                SearchEntry::new("Node", &x.0.metadata).with("address", addresses)
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .pvc_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("PersistentVolumeClaim", &x.0.metadata)
+                    .with("volume", x.0.spec.volume_name.clone())
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .pv_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("PersistentVolume", &x.0.metadata)
+                    .with("source", [volume_source(x)])
+            })
+            .collect(),
+    );
+    index(
+        app_state
+            .hpa_storage
+            .iter()
+            .map(|x| {
+                SearchEntry::new("HorizontalPodAutoscaler", &x.0.metadata)
+                    .with("target", [scale_target(x)])
+            })
+            .collect(),
+    );
+    if let Some(watch) = app_state.resource_watch.as_ref() {
+        let kind = watch.kind.resource.kind.as_str();
+        index(
+            app_state
+                .resource_storage
+                .iter()
+                .map(|x| SearchEntry::new(kind, &x.0.metadata))
+                .collect(),
+        );
+    }
+    entries
+}
+
+fn images(spec: Option<&PodSpec>) -> Vec<String> {
+    let Some(spec) = spec else {
+        return Vec::new();
+    };
+    spec.init_containers
+        .iter()
+        .flatten()
+        .chain(spec.containers.iter())
+        .filter_map(|x| x.image.clone())
+        .collect()
+}
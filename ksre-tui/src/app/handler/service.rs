@@ -0,0 +1,11 @@
+use crate::app::state::{AppState, Executor};
+
+pub fn select_next_service(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.services_cache.next();
+    None
+}
+
+pub fn select_prev_service(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.services_cache.prev();
+    None
+}
@@ -0,0 +1,79 @@
+use std::{collections::HashMap, rc::Rc, sync::Arc};
+
+use tokio_util::sync::CancellationToken;
+
+use crate::app::{
+    job::{volume_stats::poll_volume_stats, JobKind},
+    state::{AppState, Executor, Route},
+};
+use crate::kubernetes::api::storage::{volume_claim, ClaimObject, VolumeObject};
+
+// items of claims_cache are claim names in the selected namespace
+pub fn selected_claim(app_state: &AppState) -> Option<Rc<ClaimObject>> {
+    let name = app_state.claims_cache.get()?;
+    let namespace = app_state.namespace_cache.get()?;
+    app_state.pvc_storage.get(namespace.as_ref(), name.as_ref())
+}
+
+pub fn selected_volume(app_state: &AppState) -> Option<Rc<VolumeObject>> {
+    let name = app_state.volumes_cache.get()?;
+    app_state.pv_storage.get("", name.as_ref())
+}
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::StorageVolumes => app_state.volumes_cache.next(),
+        _ => app_state.claims_cache.next(),
+    }
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::StorageVolumes => app_state.volumes_cache.prev(),
+        _ => app_state.claims_cache.prev(),
+    }
+    None
+}
+
+pub fn next_namespace(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.namespace_cache.next();
+    app_state.claims_cache.reindex();
+    None
+}
+
+// switch between claims and volumes, the other side of the binding gets selected
+pub fn toggle_view(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::StorageVolumes => {
+            let claim = selected_volume(app_state).and_then(|x| volume_claim(&x));
+            app_state.set_route(Route::StorageClaims);
+            if let Some((namespace, name)) = claim.as_deref().and_then(|x| x.split_once('/')) {
+                app_state.select_namespace(namespace);
+                app_state.focus = Some(name.to_string());
+            }
+        }
+        _ => {
+            let volume = selected_claim(app_state).and_then(|x| x.0.spec.volume_name.clone());
+            app_state.set_route(Route::StorageVolumes);
+            app_state.focus = volume;
+        }
+    }
+    None
+}
+
+// kubelet summaries are only polled while someone looks at the storage page,
+// switch_tabpage stops the job
+pub fn spawn_volume_stats(app_state: &mut AppState, namespace: Option<String>) {
+    if app_state.volume_stats.is_some() {
+        return;
+    }
+    let stats = Arc::new(tokio::sync::RwLock::new(HashMap::new()));
+    app_state.volume_stats = Some(stats.clone());
+    let cancel = CancellationToken::new();
+    let poll = poll_volume_stats(cancel.clone(), app_state.kube_client(), namespace, stats);
+    let id = app_state
+        .jobs
+        .spawn(JobKind::VolumeStats, "kubelet stats", cancel, poll);
+    app_state.volume_stats_job = Some(id);
+}
@@ -0,0 +1,195 @@
+use std::rc::Rc;
+
+use crate::app::{
+    handler::report,
+    state::{AppState, Executor, Mode, Route},
+};
+use crate::kubernetes::api::deployment::{
+    self, rollout_history, DeploymentObject, ReplicaSetObject,
+};
+
+// the deployment of the selected row, or the one owning it, e.g. a replicaset or a pod
+pub fn selected_deployment(app_state: &AppState) -> Option<Rc<DeploymentObject>> {
+    let uid = app_state.workload_rows.get()?;
+    let namespace = app_state.namespace_cache.get()?;
+    let tree = &app_state.workload_tree;
+    let node = std::iter::once(uid.to_string())
+        .chain(tree.ancestors(uid.as_ref()))
+        .filter_map(|uid| tree.get(&uid))
+        .find(|node| node.kind == "Deployment")?;
+    app_state
+        .deployment_storage
+        .get(namespace.as_ref(), &node.name)
+}
+
+pub fn select_next_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::DeployHistory => app_state.rollout_history.next(),
+        _ => app_state.workload_rows.next(),
+    }
+    None
+}
+
+pub fn select_prev_item(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::DeployHistory => app_state.rollout_history.prev(),
+        _ => app_state.workload_rows.prev(),
+    }
+    None
+}
+
+pub fn toggle_collapse(app_state: &mut AppState) -> Option<&mut Executor> {
+    let uid = app_state.workload_rows.get()?;
+    let has_children = app_state
+        .workload_tree
+        .get(uid.as_ref())
+        .is_some_and(|node| !node.children.is_empty());
+    if has_children && !app_state.workload_collapsed.remove(uid.as_ref()) {
+        app_state.workload_collapsed.insert(uid.to_string());
+    }
+    // keep the cursor on the toggled row
+    app_state.workload_focus = Some(uid.to_string());
+    None
+}
+
+// jump to the owner of the selected row, b walks the way back
+pub fn goto_owner(app_state: &mut AppState) -> Option<&mut Executor> {
+    let uid = app_state.workload_rows.get()?;
+    let owner = app_state.workload_tree.get(uid.as_ref())?.owner.clone()?;
+    app_state.workload_trail.push(uid.to_string());
+    app_state.workload_focus = Some(owner);
+    None
+}
+
+pub fn go_back(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.workload_focus = Some(app_state.workload_trail.pop()?);
+    None
+}
+
+pub fn next_namespace(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.namespace_cache.next();
+    app_state.workload_rows.reindex();
+    app_state.workload_collapsed.clear();
+    app_state.workload_trail.clear();
+    None
+}
+
+pub fn show_history(app_state: &mut AppState) -> Option<&mut Executor> {
+    selected_deployment(app_state)?;
+    app_state.rollout_history.reindex();
+    app_state.set_route(Route::DeployHistory);
+    None
+}
+
+// Enter reaches here after the input line completed, when scaling
+pub fn handle_enter_key(app_state: &mut AppState) -> Option<&mut Executor> {
+    match app_state.get_route() {
+        Route::DeployHistory => rollback_selected(app_state),
+        Route::DeployScale => apply_scale(app_state),
+        _ => None,
+    }
+}
+
+pub fn back_to_tree(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.user_input.clear();
+    app_state.set_route(Route::DeployIndex);
+    None
+}
+
+pub fn restart_rollout(app_state: &mut AppState) -> Option<&mut Executor> {
+    let deploy = selected_deployment(app_state)?;
+    let (namespace, name) = object_key(&deploy);
+    let kube_client = app_state.kube_client();
+    let status_message = app_state.status_message.clone();
+    tokio::spawn(async move {
+        let message = match deployment::restart(kube_client, &namespace, &name).await {
+            Ok(_) => Ok(format!("deployment {} restarted", name)),
+            Err(err) => Err(format!("restart deployment {} failed: {}", name, err)),
+        };
+        report(&status_message, message).await;
+    });
+    None
+}
+
+// the replica count is typed into the input line and applied by apply_scale on Enter
+pub fn trigger_scale(app_state: &mut AppState) -> Option<&mut Executor> {
+    selected_deployment(app_state)?;
+    app_state.user_input.clear();
+    app_state.set_route(Route::DeployScale);
+    app_state.set_mode(Mode::Insert);
+    None
+}
+
+fn apply_scale(app_state: &mut AppState) -> Option<&mut Executor> {
+    let input = app_state.user_input.as_str().trim().to_string();
+    back_to_tree(app_state);
+    let deploy = selected_deployment(app_state)?;
+    let (namespace, name) = object_key(&deploy);
+    let status_message = app_state.status_message.clone();
+    let Ok(replicas) = input.parse::<i32>() else {
+        if let Ok(mut status_message) = status_message.try_write() {
+            *status_message = format!("invalid replica count: {:?}", input);
+        }
+        return None;
+    };
+    let kube_client = app_state.kube_client();
+    tokio::spawn(async move {
+        let message = match deployment::scale(kube_client, &namespace, &name, replicas).await {
+            Ok(_) => Ok(format!("deployment {} scaled to {}", name, replicas)),
+            Err(err) => Err(format!("scale deployment {} failed: {}", name, err)),
+        };
+        report(&status_message, message).await;
+    });
+    None
+}
+
+// single keystroke undo to the revision before the current one
+pub fn undo_rollout(app_state: &mut AppState) -> Option<&mut Executor> {
+    let deploy = selected_deployment(app_state)?;
+    let history = rollout_history(&deploy, app_state.replicaset_storage.iter());
+    let previous = history.get(1)?;
+    spawn_rollback(app_state, &deploy, previous.number, &previous.replicaset);
+    None
+}
+
+// undo to the revision selected in the history
+fn rollback_selected(app_state: &mut AppState) -> Option<&mut Executor> {
+    let deploy = selected_deployment(app_state)?;
+    let number = app_state.rollout_history.get()?.parse::<i64>().ok()?;
+    let history = rollout_history(&deploy, app_state.replicaset_storage.iter());
+    let revision = history.iter().find(|x| x.number == number)?;
+    spawn_rollback(app_state, &deploy, number, &revision.replicaset);
+    app_state.set_route(Route::DeployIndex);
+    None
+}
+
+fn spawn_rollback(
+    app_state: &AppState,
+    deploy: &DeploymentObject,
+    number: i64,
+    replicaset: &ReplicaSetObject,
+) {
+    let (namespace, name) = object_key(deploy);
+    let replicaset = replicaset.clone();
+    let kube_client = app_state.kube_client();
+    let status_message = app_state.status_message.clone();
+    tokio::spawn(async move {
+        let message = match deployment::rollback(kube_client, &namespace, &name, &replicaset).await
+        {
+            Ok(_) => Ok(format!(
+                "deployment {} rolled back to revision {}",
+                name, number
+            )),
+            Err(err) => Err(format!("rollback deployment {} failed: {}", name, err)),
+        };
+        report(&status_message, message).await;
+    });
+}
+
+#[inline]
+fn object_key(deploy: &DeploymentObject) -> (String, String) {
+    (
+        deploy.0.metadata.namespace.clone().unwrap_or_default(),
+        deploy.0.metadata.name.clone().unwrap_or_default(),
+    )
+}
@@ -0,0 +1,78 @@
+use crate::app::{
+    clipboard,
+    state::{AppState, Executor, Overlay, Route},
+};
+
+// y asks what to copy of the selected pod, the answer closes the menu
+pub fn toggle_yank(app_state: &mut AppState) -> Option<&mut Executor> {
+    app_state.overlay = match app_state.overlay {
+        Some(Overlay::Yank) => None,
+        _ => {
+            app_state.cache_items.get()?;
+            Some(Overlay::Yank)
+        }
+    };
+    None
+}
+
+pub fn yank_name(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (_, name) = selected_pod(app_state)?;
+    copy(app_state, "pod name", &name);
+    None
+}
+
+pub fn yank_full_name(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (namespace, name) = selected_pod(app_state)?;
+    copy(
+        app_state,
+        "namespace/name",
+        &format!("{}/{}", namespace, name),
+    );
+    None
+}
+
+pub fn yank_yaml(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (namespace, name) = selected_pod(app_state)?;
+    let pod = app_state.pod_storage.get(&namespace, &name)?;
+    copy(app_state, "pod yaml", &pod.to_yaml());
+    None
+}
+
+pub fn yank_describe(app_state: &mut AppState) -> Option<&mut Executor> {
+    let (namespace, name) = selected_pod(app_state)?;
+    let describe = app_state.pod_describes.get(&namespace, &name)?.lines();
+    copy(app_state, "describe", &describe.join("\n"));
+    None
+}
+
+// everything the log pane holds, not only the lines in view
+pub fn yank_log_lines(app_state: &mut AppState) -> Option<&mut Executor> {
+    if !matches!(app_state.get_route(), Route::PodLog) {
+        app_state.overlay = None;
+        if let Ok(mut status_message) = app_state.status_message.try_write() {
+            *status_message = "open the log pane first".to_string();
+        }
+        return None;
+    }
+    let text = app_state.stdout_buffer.try_read().ok()?.lines().join("\n");
+    copy(app_state, "log buffer", &text);
+    None
+}
+
+fn selected_pod(app_state: &AppState) -> Option<(String, String)> {
+    let namespace = app_state.namespace_cache.get()?;
+    let name = app_state.cache_items.get()?;
+    Some((namespace.to_string(), name.to_string()))
+}
+
+// the status line tells whether the text is in the clipboard or in a file
+pub(crate) fn copy(app_state: &mut AppState, what: &str, text: &str) {
+    app_state.overlay = None;
+    let message = match clipboard::copy(text.as_bytes()) {
+        Ok(copied) => copied.message(what),
+        Err(err) => format!("copy {} failed: {}", what, err),
+    };
+    if let Ok(mut status_message) = app_state.status_message.try_write() {
+        *status_message = message;
+    }
+}
@@ -0,0 +1,37 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Local;
+use kube::Client as KubeClient;
+use tokio::sync::RwLock;
+
+use crate::kubernetes::api::cluster::{server_health, server_version, warning_events, ClusterInfo};
+
+const PROBE_INTERVAL: Duration = Duration::from_secs(30);
+
+// refresh version, readiness and warning events of the api server for the overview page
+pub async fn probe_cluster(kube_client: KubeClient, info: Arc<RwLock<ClusterInfo>>) {
+    let mut interval = tokio::time::interval(PROBE_INTERVAL);
+    loop {
+        interval.tick().await;
+        let version = server_version(&kube_client).await.ok();
+        let health = server_health(&kube_client)
+            .await
+            .map_err(|err| err.to_string());
+        if let Err(err) = health.as_ref() {
+            tracing::warn!("api server readyz failed: {}", err);
+        }
+        let warnings = warning_events(&kube_client).await;
+        if let Err(err) = warnings.as_ref() {
+            tracing::warn!("list warning events failed: {}", err);
+        }
+
+        let mut info = info.write().await;
+        info.version = version.or(info.version.take());
+        info.health = Some(health);
+        // keep the last known events when listing fails
+        if let Ok(warnings) = warnings {
+            info.warnings = warnings;
+        }
+        info.checked = Some(Local::now());
+    }
+}
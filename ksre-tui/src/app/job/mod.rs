@@ -1,41 +1,337 @@
+pub(super) mod cluster_probe;
+pub(super) mod node_drain;
+pub(super) mod pod_cp;
+pub(super) mod pod_debug;
 pub(super) mod pod_exec;
 pub(super) mod pod_log;
+pub(super) mod volume_stats;
 
+use std::{
+    fmt::Display,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use chrono::{DateTime, Local};
+use color_eyre::eyre::Result;
 use tokio::task::JoinHandle;
 use tokio_util::sync::CancellationToken;
 
-enum JobState {
-    Init,
-    Start,
+// a stopped job that ignores its token is aborted after this
+const STOP_GRACE: Duration = Duration::from_secs(3);
+// finished jobs kept for the jobs pane
+const MAX_FINISHED: usize = 32;
+
+pub type JobId = u64;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JobState {
+    Starting,
     Running,
-    Stop,
-    Terminalted,
+    Stopping,
+    Terminated,
     Failed(String),
 }
 
-// for JobManager run in single thread , so it don't need lock to procted
-struct JobManager {
-    task_0: JoinHandle<()>,
-    task_1: JoinHandle<()>,
-    cancel_fn: CancellationToken,
-    state: JobState,
+impl JobState {
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        matches!(self, JobState::Terminated | JobState::Failed(_))
+    }
+}
+
+impl Display for JobState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobState::Starting => write!(f, "Starting"),
+            JobState::Running => write!(f, "Running"),
+            JobState::Stopping => write!(f, "Stopping"),
+            JobState::Terminated => write!(f, "Terminated"),
+            JobState::Failed(err) => write!(f, "Failed: {}", err),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JobKind {
+    PodLog,
+    PodExec,
+    NodeDrain,
+    Metrics,
+    ClusterProbe,
+    Discovery,
+    Copy,
+    Browse,
+    VolumeStats,
+}
+
+impl Display for JobKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match self {
+            JobKind::PodLog => "log",
+            JobKind::PodExec => "exec",
+            JobKind::NodeDrain => "drain",
+            JobKind::Metrics => "metrics",
+            JobKind::ClusterProbe => "probe",
+            JobKind::Discovery => "discovery",
+            JobKind::Copy => "copy",
+            JobKind::Browse => "ls",
+            JobKind::VolumeStats => "volumes",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+pub struct Job {
+    pub id: JobId,
+    pub kind: JobKind,
+    // what the job works on, e.g. namespace/pod
+    pub target: String,
+    pub started: DateTime<Local>,
+    // written by the task itself, read by the ui
+    state: Arc<Mutex<JobState>>,
+    cancel: CancellationToken,
+    handle: JoinHandle<()>,
+    stop_requested: Option<Instant>,
+    // the end was already seen by reap
+    reaped: bool,
+}
+
+impl Job {
+    pub fn state(&self) -> JobState {
+        self.state
+            .lock()
+            .map(|x| x.clone())
+            .unwrap_or(JobState::Running)
+    }
+
+    fn set_state(&self, state: JobState) {
+        if let Ok(mut x) = self.state.lock() {
+            *x = state;
+        }
+    }
+}
+
+// JobManager owns every long-running background task, it runs on the ui loop so it needs no lock.
+// a task is told to stop by its token and aborted when it does not stop within STOP_GRACE
+#[derive(Default)]
+pub struct JobManager {
+    jobs: Vec<Job>,
+    next_id: JobId,
+    selected: usize,
 }
 
 impl JobManager {
-    pub(super) fn new() -> Self {
-        Self {
-            task_0: tokio::spawn(async {}),
-            task_1: tokio::spawn(async {}),
-            cancel_fn: CancellationToken::new(),
-            state: JobState::Init,
+    // the task gets the token to stop itself, an error marks the job as failed
+    pub fn spawn<F>(
+        &mut self,
+        kind: JobKind,
+        target: impl Into<String>,
+        cancel: CancellationToken,
+        task: F,
+    ) -> JobId
+    where
+        F: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.next_id += 1;
+        let state = Arc::new(Mutex::new(JobState::Starting));
+        let task_state = state.clone();
+        let handle = tokio::spawn(async move {
+            set(&task_state, JobState::Running);
+            let result = task.await;
+            set(
+                &task_state,
+                match result {
+                    Ok(_) => JobState::Terminated,
+                    Err(err) => JobState::Failed(err.to_string()),
+                },
+            );
+        });
+        self.jobs.push(Job {
+            id: self.next_id,
+            kind,
+            target: target.into(),
+            started: Local::now(),
+            state,
+            cancel,
+            handle,
+            stop_requested: None,
+            reaped: false,
+        });
+        self.next_id
+    }
+
+    pub fn stop(&mut self, id: JobId) {
+        if let Some(job) = self.jobs.iter_mut().find(|x| x.id == id) {
+            stop_job(job);
         }
     }
 
-    pub(super) fn starting(&mut self) {}
+    // on quit nothing may outlive the ui
+    pub fn stop_all(&mut self) {
+        for job in self.jobs.iter_mut() {
+            job.cancel.cancel();
+            job.handle.abort();
+        }
+    }
 
-    pub(super) fn await_running(&mut self) {}
+    // abort jobs that ignored the stop request, forget old finished ones.
+    // returns the failures seen since the last call
+    pub fn reap(&mut self) -> Vec<String> {
+        let mut failures = Vec::new();
+        for job in self.jobs.iter_mut() {
+            // cancelled by its owner, e.g. the log executor was dropped
+            if job.cancel.is_cancelled() {
+                stop_job(job);
+            }
+            if job.handle.is_finished() {
+                if job.reaped {
+                    continue;
+                }
+                job.reaped = true;
+                match job.state() {
+                    JobState::Failed(err) => {
+                        failures.push(format!("{} {} failed: {}", job.kind, job.target, err))
+                    }
+                    // aborted before it could tell
+                    state if !state.is_finished() => job.set_state(JobState::Terminated),
+                    _ => {}
+                }
+                continue;
+            }
+            if job
+                .stop_requested
+                .is_some_and(|requested| requested.elapsed() >= STOP_GRACE)
+            {
+                job.handle.abort();
+            }
+        }
+        let finished = self.jobs.iter().filter(|x| x.handle.is_finished()).count();
+        if finished > MAX_FINISHED {
+            let mut excess = finished - MAX_FINISHED;
+            self.jobs.retain(|x| {
+                let drop = excess > 0 && x.handle.is_finished();
+                excess -= drop as usize;
+                !drop
+            });
+            self.selected = self.selected.min(self.jobs.len().saturating_sub(1));
+        }
+        failures
+    }
 
-    pub(super) fn stop(&mut self) {}
+    #[inline]
+    pub fn list(&self) -> &[Job] {
+        &self.jobs
+    }
 
-    pub(super) fn await_terminated(&mut self) {}
+    #[inline]
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.jobs.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn stop_selected(&mut self) {
+        if let Some(id) = self.jobs.get(self.selected).map(|x| x.id) {
+            self.stop(id);
+        }
+    }
+
+    pub fn clear_finished(&mut self) {
+        self.jobs.retain(|x| !x.handle.is_finished());
+        self.selected = self.selected.min(self.jobs.len().saturating_sub(1));
+    }
+}
+
+// for loops that never look at a token, e.g. pollers
+pub async fn until_cancelled(
+    cancel: CancellationToken,
+    task: impl Future<Output = ()>,
+) -> Result<()> {
+    tokio::select! {
+        _ = cancel.cancelled() => {},
+        _ = task => {},
+    }
+    Ok(())
+}
+
+fn stop_job(job: &mut Job) {
+    if job.handle.is_finished() || job.stop_requested.is_some() {
+        return;
+    }
+    job.cancel.cancel();
+    job.stop_requested = Some(Instant::now());
+    job.set_state(JobState::Stopping);
+}
+
+#[inline]
+fn set(state: &Mutex<JobState>, value: JobState) {
+    if let Ok(mut x) = state.lock() {
+        // a stop request is not overwritten by the task reporting it runs
+        if !(value == JobState::Running && *x == JobState::Stopping) {
+            *x = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use color_eyre::eyre::eyre;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_job_lifecycle() {
+        let mut jobs = JobManager::default();
+        let cancel = CancellationToken::new();
+        let token = cancel.clone();
+        let id = jobs.spawn(JobKind::PodLog, "default/web", cancel, async move {
+            token.cancelled().await;
+            Ok(())
+        });
+        jobs.spawn(
+            JobKind::Discovery,
+            "api groups",
+            CancellationToken::new(),
+            async { Err(eyre!("forbidden")) },
+        );
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(JobState::Running, jobs.list()[0].state());
+
+        jobs.stop(id);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_eq!(
+            vec!["discovery api groups failed: forbidden".to_string()],
+            jobs.reap()
+        );
+        assert_eq!(JobState::Terminated, jobs.list()[0].state());
+        // a failure is reported once
+        assert!(jobs.reap().is_empty());
+
+        // the selection follows the list when old finished jobs are dropped
+        for _ in 0..MAX_FINISHED {
+            jobs.spawn(
+                JobKind::Copy,
+                "default/web",
+                CancellationToken::new(),
+                async { Ok(()) },
+            );
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        while jobs.selected() + 1 < jobs.list().len() {
+            jobs.select_next();
+        }
+        jobs.reap();
+        assert_eq!(MAX_FINISHED, jobs.list().len());
+        assert_eq!(MAX_FINISHED - 1, jobs.selected());
+    }
 }
@@ -0,0 +1,234 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use k8s_openapi::{
+    api::core::v1::{Node, Pod},
+    apimachinery::pkg::apis::meta::v1::ObjectMeta,
+};
+use kube::{
+    api::{EvictParams, ListParams, Patch, PatchParams},
+    Api, Client as KubeClient, ResourceExt,
+};
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+
+const MIRROR_POD_ANNOTATION: &str = "kubernetes.io/config.mirror";
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EvictionState {
+    Pending,
+    // eviction accepted, waiting for the pod to go away
+    Evicting,
+    Evicted,
+    // refused by a PodDisruptionBudget, retried until the deadline
+    Blocked(String),
+    Skipped(&'static str),
+    Failed(String),
+}
+
+pub struct Eviction {
+    pub namespace: String,
+    pub name: String,
+    uid: Option<String>,
+    pub state: EvictionState,
+    pub attempts: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DrainState {
+    Cordoning,
+    Evicting,
+    Done,
+    Cancelled,
+    TimedOut,
+    Failed(String),
+}
+
+pub struct DrainProgress {
+    pub node: String,
+    pub state: DrainState,
+    pub evictions: Vec<Eviction>,
+    pub started: Instant,
+}
+
+impl DrainProgress {
+    pub fn new(node: &str) -> Self {
+        Self {
+            node: node.to_string(),
+            state: DrainState::Cordoning,
+            evictions: Vec::new(),
+            started: Instant::now(),
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self.state, DrainState::Cordoning | DrainState::Evicting)
+    }
+
+    // (evicted, total pods that have to be evicted)
+    pub fn counts(&self) -> (usize, usize) {
+        let required = self
+            .evictions
+            .iter()
+            .filter(|x| !matches!(x.state, EvictionState::Skipped(_)));
+        let total = required.clone().count();
+        let evicted = required
+            .filter(|x| x.state == EvictionState::Evicted)
+            .count();
+        (evicted, total)
+    }
+}
+
+// cordon set spec.unschedulable of node, uncordon when unschedulable is false
+pub async fn cordon_node(
+    kube_client: KubeClient,
+    node_name: &str,
+    unschedulable: bool,
+) -> kube::Result<()> {
+    let nodes: Api<Node> = Api::all(kube_client);
+    let patch = serde_json::json!({ "spec": { "unschedulable": unschedulable } });
+    nodes
+        .patch(node_name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map(|_| ())
+}
+
+// drain cordon the node then evict every pod but daemonset and mirror pods,
+// pdb refusals are retried until all pods are gone or the timeout expires
+pub async fn drain_node(
+    cancellation_token: CancellationToken,
+    kube_client: KubeClient,
+    progress: Arc<RwLock<DrainProgress>>,
+    node_name: String,
+    timeout: Option<Duration>,
+) {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    if let Err(err) = cordon_node(kube_client.clone(), &node_name, true).await {
+        progress.write().await.state = DrainState::Failed(format!("cordon failed: {}", err));
+        return;
+    }
+
+    let pods: Api<Pod> = Api::all(kube_client.clone());
+    let list_opts = ListParams::default().fields(&format!("spec.nodeName={}", node_name));
+    let node_pods = match pods.list(&list_opts).await {
+        Ok(node_pods) => node_pods.items,
+        Err(err) => {
+            progress.write().await.state = DrainState::Failed(format!("list pods failed: {}", err));
+            return;
+        }
+    };
+
+    {
+        let mut progress = progress.write().await;
+        progress.state = DrainState::Evicting;
+        progress.evictions = node_pods
+            .iter()
+            .map(|pod| Eviction {
+                namespace: pod.namespace().unwrap_or_default(),
+                name: pod.name_any(),
+                uid: pod.uid(),
+                state: skip_reason(&pod.metadata)
+                    .map(EvictionState::Skipped)
+                    .unwrap_or(EvictionState::Pending),
+                attempts: 0,
+            })
+            .collect();
+    }
+
+    loop {
+        if cancellation_token.is_cancelled() {
+            progress.write().await.state = DrainState::Cancelled;
+            return;
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            progress.write().await.state = DrainState::TimedOut;
+            return;
+        }
+
+        let pending = progress
+            .read()
+            .await
+            .evictions
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| {
+                matches!(
+                    x.state,
+                    EvictionState::Pending | EvictionState::Blocked(_) | EvictionState::Evicting
+                )
+            })
+            .map(|(idx, x)| {
+                let key = (x.namespace.clone(), x.name.clone(), x.uid.clone());
+                (idx, key, x.state.clone())
+            })
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            let mut progress = progress.write().await;
+            let failed = progress
+                .evictions
+                .iter()
+                .filter(|x| matches!(x.state, EvictionState::Failed(_)))
+                .count();
+            progress.state = if failed == 0 {
+                DrainState::Done
+            } else {
+                DrainState::Failed(format!("{} pods failed to evict", failed))
+            };
+            return;
+        }
+
+        for (idx, (namespace, name, uid), state) in pending {
+            let pods: Api<Pod> = Api::namespaced(kube_client.clone(), &namespace);
+            let next_state = match state {
+                // statefulset pods come back with the same name but a new uid
+                EvictionState::Evicting => match pods.get_opt(&name).await {
+                    Ok(Some(pod)) if pod.uid() == uid => EvictionState::Evicting,
+                    Ok(_) => EvictionState::Evicted,
+                    Err(err) => EvictionState::Failed(err.to_string()),
+                },
+                _ => match pods.evict(&name, &EvictParams::default()).await {
+                    Ok(_) => EvictionState::Evicting,
+                    // pod is gone already
+                    Err(kube::Error::Api(resp)) if resp.code == 404 => EvictionState::Evicted,
+                    // disruption budget does not allow the eviction now
+                    Err(kube::Error::Api(resp)) if resp.code == 429 => {
+                        EvictionState::Blocked(resp.message)
+                    }
+                    Err(err) => EvictionState::Failed(err.to_string()),
+                },
+            };
+            let mut progress = progress.write().await;
+            let eviction = &mut progress.evictions[idx];
+            if !matches!(state, EvictionState::Evicting) {
+                eviction.attempts += 1;
+            }
+            eviction.state = next_state;
+        }
+
+        tokio::select! {
+            _ = cancellation_token.cancelled() => {},
+            _ = tokio::time::sleep(POLL_INTERVAL) => {},
+        }
+    }
+}
+
+// pods a drain leaves on the node, like kubectl drain --ignore-daemonsets
+pub fn skip_reason(metadata: &ObjectMeta) -> Option<&'static str> {
+    let annotations = metadata.annotations.as_ref();
+    if annotations.is_some_and(|x| x.contains_key(MIRROR_POD_ANNOTATION)) {
+        return Some("mirror pod");
+    }
+    if metadata
+        .owner_references
+        .iter()
+        .flatten()
+        .any(|owner| owner.kind == "DaemonSet")
+    {
+        return Some("daemonset pod");
+    }
+    None
+}
@@ -0,0 +1,372 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use color_eyre::eyre::{eyre, Result};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{Api, Client as KubeClient};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    sync::RwLock,
+};
+use tokio_util::sync::CancellationToken;
+
+use super::pod_exec::{check_status, exec_output, exec_process, read_all};
+
+const CHUNK_SIZE: usize = 64 * 1024;
+// progress in the status line is refreshed at most this often
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+// name.1 .. name.N are tried when a download would overwrite a local file
+const MAX_NAME_SUFFIX: usize = 100;
+
+// the container whose files are browsed, like kubectl cp it needs sh, ls, cat and tar in there
+#[derive(Clone, Debug)]
+pub struct RemoteTarget {
+    pub namespace: String,
+    pub pod_name: String,
+    pub container: String,
+}
+
+impl RemoteTarget {
+    fn api(&self, kube_client: KubeClient) -> Api<Pod> {
+        Api::namespaced(kube_client, &self.namespace)
+    }
+}
+
+// one directory of the file browser, entries of sub directories end with /
+pub struct RemoteDir {
+    pub target: RemoteTarget,
+    pub path: String,
+    pub entries: Vec<String>,
+    pub selected: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl RemoteDir {
+    pub fn new(target: RemoteTarget, path: &str) -> Self {
+        Self {
+            target,
+            path: path.to_string(),
+            entries: Vec::new(),
+            selected: 0,
+            loading: true,
+            error: None,
+        }
+    }
+
+    pub fn selected(&self) -> Option<&String> {
+        self.entries.get(self.selected)
+    }
+
+    pub fn next(&mut self) {
+        if self.selected + 1 < self.entries.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    // path of an entry of this directory, ../ goes up
+    pub fn child(&self, entry: &str) -> String {
+        if entry == "../" {
+            let parent = Path::new(&self.path).parent().unwrap_or(Path::new("/"));
+            return parent.to_string_lossy().to_string();
+        }
+        Path::new(&self.path)
+            .join(entry.trim_end_matches('/'))
+            .to_string_lossy()
+            .to_string()
+    }
+}
+
+// ls -Ap works the same with coreutils and busybox: no . and .., directories end with /.
+// a failed listing is shown in the browser, not as a failed job
+pub async fn list_dir(
+    cancel: CancellationToken,
+    kube_client: KubeClient,
+    dir: Arc<RwLock<RemoteDir>>,
+    path: String,
+) -> Result<()> {
+    let target = {
+        let mut dir = dir.write().await;
+        dir.loading = true;
+        dir.target.clone()
+    };
+    let command = ["ls", "-1Ap", "--", path.as_str()]
+        .map(String::from)
+        .to_vec();
+    let api = target.api(kube_client);
+    let result = tokio::select! {
+        _ = cancel.cancelled() => Err(eyre!("cancelled")),
+        result = exec_output(&api, &target.pod_name, &target.container, command) => result,
+    };
+    let mut dir = dir.write().await;
+    dir.loading = false;
+    match result {
+        Ok(output) => {
+            dir.entries = parse_ls(&path, &output);
+            dir.path = path;
+            dir.selected = 0;
+            dir.error = None;
+        }
+        Err(err) => dir.error = Some(err.to_string()),
+    }
+    Ok(())
+}
+
+fn parse_ls(path: &str, output: &str) -> Vec<String> {
+    let parent = (path != "/").then(|| "../".to_string());
+    parent
+        .into_iter()
+        .chain(output.lines().filter(|x| !x.is_empty()).map(String::from))
+        .collect()
+}
+
+// a file is copied as is, a directory is saved as name.tar in local_dir.
+// an existing local file is never overwritten, the download gets a numbered name instead
+pub async fn download(
+    cancel: CancellationToken,
+    kube_client: KubeClient,
+    target: RemoteTarget,
+    remote_path: String,
+    is_dir: bool,
+    local_dir: PathBuf,
+    status: Arc<RwLock<String>>,
+) -> Result<()> {
+    let remote = Path::new(&remote_path);
+    let name = remote
+        .file_name()
+        .map(|x| x.to_string_lossy().to_string())
+        .ok_or_else(|| eyre!("nothing to download at {}", remote_path))?;
+    let (command, local) = if is_dir {
+        let parent = remote.parent().unwrap_or(Path::new("/"));
+        let command = vec![
+            "tar".to_string(),
+            "cf".to_string(),
+            "-".to_string(),
+            "-C".to_string(),
+            parent.to_string_lossy().to_string(),
+            name.clone(),
+        ];
+        (command, local_dir.join(format!("{}.tar", name)))
+    } else {
+        (
+            vec!["cat".to_string(), remote_path.clone()],
+            local_dir.join(&name),
+        )
+    };
+
+    let mut process = exec_process(
+        &target.api(kube_client),
+        &target.pod_name,
+        &target.container,
+        command,
+    )
+    .await?;
+    let mut stdout = process.stdout().ok_or_else(|| eyre!("no stdout"))?;
+    let stderr = tokio::spawn(read_all(process.stderr()));
+    let (mut file, local) = create_new(&local).await?;
+    let mut progress = Progress::new(format!("download {}", name), status.clone());
+    let mut buffer = vec![0; CHUNK_SIZE];
+    let copied: Result<()> = async {
+        loop {
+            let read = tokio::select! {
+                _ = cancel.cancelled() => return Err(eyre!("cancelled")),
+                read = stdout.read(&mut buffer) => read?,
+            };
+            if read == 0 {
+                break;
+            }
+            file.write_all(&buffer[..read]).await?;
+            progress.add(read as u64).await;
+        }
+        file.flush().await?;
+        let stderr = stderr.await.unwrap_or_default();
+        check_status(&mut process, &stderr).await
+    }
+    .await;
+    if let Err(err) = copied {
+        // a partial file looks like a good one, e.g. a truncated heap dump. the file was
+        // created by this download, so removing it loses nothing of the user
+        let _ = tokio::fs::remove_file(&local).await;
+        return Err(err);
+    }
+    progress
+        .finish(&format!("saved to {}", local.display()))
+        .await;
+    Ok(())
+}
+
+// path itself when it is free, else path.1, path.2 and so on
+async fn create_new(path: &Path) -> Result<(tokio::fs::File, PathBuf)> {
+    for suffix in 0..=MAX_NAME_SUFFIX {
+        let candidate = match suffix {
+            0 => path.to_path_buf(),
+            n => {
+                let mut name = path.as_os_str().to_owned();
+                name.push(format!(".{}", n));
+                PathBuf::from(name)
+            }
+        };
+        let file = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&candidate)
+            .await;
+        match file {
+            Ok(file) => return Ok((file, candidate)),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Err(eyre!(
+        "{} and {} numbered names exist already",
+        path.display(),
+        MAX_NAME_SUFFIX
+    ))
+}
+
+// the file goes to stdin of head -c, it stops after the exact size without waiting for eof
+pub async fn upload(
+    cancel: CancellationToken,
+    kube_client: KubeClient,
+    target: RemoteTarget,
+    local: PathBuf,
+    remote_dir: String,
+    status: Arc<RwLock<String>>,
+) -> Result<()> {
+    let size = tokio::fs::metadata(&local).await?.len();
+    let name = local
+        .file_name()
+        .map(|x| x.to_string_lossy().to_string())
+        .ok_or_else(|| eyre!("{} is not a file", local.display()))?;
+    let remote = Path::new(&remote_dir)
+        .join(&name)
+        .to_string_lossy()
+        .to_string();
+    let command = vec![
+        "sh".to_string(),
+        "-c".to_string(),
+        "head -c \"$0\" > \"$1\"".to_string(),
+        size.to_string(),
+        remote.clone(),
+    ];
+
+    let mut process = exec_process(
+        &target.api(kube_client),
+        &target.pod_name,
+        &target.container,
+        command,
+    )
+    .await?;
+    let mut stdin = process.stdin().ok_or_else(|| eyre!("no stdin"))?;
+    let stderr = tokio::spawn(read_all(process.stderr()));
+    let mut file = tokio::fs::File::open(&local).await?;
+    let mut progress = Progress::new(format!("upload {}", name), status.clone());
+    let mut buffer = vec![0; CHUNK_SIZE];
+    loop {
+        let read = tokio::select! {
+            _ = cancel.cancelled() => return Err(eyre!("cancelled")),
+            read = file.read(&mut buffer) => read?,
+        };
+        if read == 0 {
+            break;
+        }
+        stdin.write_all(&buffer[..read]).await?;
+        progress.add(read as u64).await;
+    }
+    stdin.flush().await?;
+    let stderr = stderr.await.unwrap_or_default();
+    check_status(&mut process, &stderr).await?;
+    progress.finish(&format!("saved to {}", remote)).await;
+    Ok(())
+}
+
+// bytes moved so far, written to the status line
+struct Progress {
+    action: String,
+    bytes: u64,
+    reported: Instant,
+    status: Arc<RwLock<String>>,
+}
+
+impl Progress {
+    fn new(action: String, status: Arc<RwLock<String>>) -> Self {
+        Self {
+            action,
+            bytes: 0,
+            reported: Instant::now(),
+            status,
+        }
+    }
+
+    async fn add(&mut self, bytes: u64) {
+        self.bytes += bytes;
+        if self.reported.elapsed() >= PROGRESS_INTERVAL {
+            self.reported = Instant::now();
+            *self.status.write().await = format!("{}: {}", self.action, human_size(self.bytes));
+        }
+    }
+
+    async fn finish(&self, result: &str) {
+        *self.status.write().await = format!(
+            "{} done, {} {}",
+            self.action,
+            human_size(self.bytes),
+            result
+        );
+    }
+}
+
+fn human_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_dir() {
+        let target = RemoteTarget {
+            namespace: "default".to_string(),
+            pod_name: "web".to_string(),
+            container: "app".to_string(),
+        };
+        let mut dir = RemoteDir::new(target, "/tmp");
+        dir.entries = parse_ls("/tmp", "dumps/\nheap.hprof\n");
+        assert_eq!(vec!["../", "dumps/", "heap.hprof"], dir.entries);
+        assert_eq!("/", dir.child("../"));
+        assert_eq!("/tmp/dumps", dir.child("dumps/"));
+        assert_eq!("/tmp/heap.hprof", dir.child("heap.hprof"));
+        assert!(parse_ls("/", "tmp/\n").iter().all(|x| x != "../"));
+        assert_eq!("1.5 MiB", human_size(1536 * 1024));
+    }
+
+    #[tokio::test]
+    async fn test_create_new() {
+        let dir = std::env::temp_dir().join(format!("ksre-cp-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("heap.hprof");
+        std::fs::write(&path, "keep").unwrap();
+
+        let (_, created) = create_new(&path).await.unwrap();
+        assert_eq!(dir.join("heap.hprof.1"), created);
+        let (_, created) = create_new(&path).await.unwrap();
+        assert_eq!(dir.join("heap.hprof.2"), created);
+        assert_eq!("keep", std::fs::read_to_string(&path).unwrap());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use color_eyre::eyre::{eyre, Result};
+use k8s_openapi::api::core::v1::Pod;
+use kube::{
+    api::{Patch, PatchParams},
+    Api, Client as KubeClient,
+};
+use serde_json::{json, Value};
+use tokio::sync::{mpsc, RwLock};
+use tokio_util::sync::CancellationToken;
+use tui_textarea::TextArea;
+
+use super::pod_exec::{pod_exec, write_output, PodExecArgs};
+
+pub const DEFAULT_DEBUG_IMAGE: &str = "busybox:1.36";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+const START_TIMEOUT: Duration = Duration::from_secs(120);
+// kubectl debug names its containers debugger-xxxxx from these, no vowels so no words
+const NAME_ALPHABET: &[u8] = b"bcdfghjklmnpqrstvwxz2456789";
+const NAME_SUFFIX_LENGTH: usize = 5;
+
+pub struct DebugArgs {
+    pub kube_client: KubeClient,
+    pub namespace: String,
+    pub pod_name: String,
+    // the debugger joins the process namespace of this container
+    pub target: String,
+    pub image: String,
+}
+
+// like kubectl debug: add an ephemeral container to the pod, wait for it to run and attach to it.
+// its shell reads stdin, so it needs no tty to take commands
+pub async fn debug_pod(
+    cancel: CancellationToken,
+    writer: Arc<RwLock<TextArea<'static>>>,
+    input_reader: mpsc::Receiver<String>,
+    args: DebugArgs,
+) -> Result<()> {
+    let api: Api<Pod> = Api::namespaced(args.kube_client.clone(), &args.namespace);
+    // ephemeral containers can not be removed, every name ever used in the pod is taken
+    let pod = api.get(&args.pod_name).await?;
+    let taken = pod
+        .spec
+        .iter()
+        .flat_map(|x| x.ephemeral_containers.iter().flatten())
+        .map(|x| x.name.as_str())
+        .collect::<Vec<&str>>();
+    let name = debugger_name(&taken);
+    let patch = json!({
+        "spec": { "ephemeralContainers": [debug_container(&name, &args.image, &args.target)] }
+    });
+    write_output(
+        &writer,
+        &format!(
+            "starting {} ({}) targeting {}\n",
+            name, args.image, args.target
+        ),
+    )
+    .await;
+    api.patch_subresource(
+        "ephemeralcontainers",
+        &args.pod_name,
+        &PatchParams::default(),
+        &Patch::Strategic(patch),
+    )
+    .await?;
+
+    let started = Instant::now();
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => return Ok(()),
+            _ = tokio::time::sleep(POLL_INTERVAL) => {},
+        }
+        let pod = api.get(&args.pod_name).await?;
+        if container_running(&pod, &name)? {
+            break;
+        }
+        if started.elapsed() >= START_TIMEOUT {
+            return Err(eyre!("{} did not start within {:?}", name, START_TIMEOUT));
+        }
+    }
+    write_output(
+        &writer,
+        &format!("{} is running, type commands below\n", name),
+    )
+    .await;
+
+    pod_exec(
+        cancel,
+        writer,
+        input_reader,
+        PodExecArgs {
+            kube_client: args.kube_client,
+            namespace: args.namespace,
+            pod_name: args.pod_name,
+            container: Some(name),
+            command: None,
+        },
+    )
+    .await
+}
+
+fn debugger_name(taken: &[&str]) -> String {
+    loop {
+        // every RandomState gets new random keys, enough for a name without a rand dependency
+        let mut seed = RandomState::new().build_hasher().finish();
+        let suffix = (0..NAME_SUFFIX_LENGTH)
+            .map(|_| {
+                let x = NAME_ALPHABET[(seed % NAME_ALPHABET.len() as u64) as usize];
+                seed /= NAME_ALPHABET.len() as u64;
+                x as char
+            })
+            .collect::<String>();
+        let name = format!("debugger-{}", suffix);
+        if !taken.contains(&name.as_str()) {
+            return name;
+        }
+    }
+}
+
+fn debug_container(name: &str, image: &str, target: &str) -> Value {
+    json!({
+        "name": name,
+        "image": image,
+        "stdin": true,
+        "tty": false,
+        "targetContainerName": target,
+        "terminationMessagePolicy": "File",
+    })
+}
+
+// waiting is fine until the image can not be pulled, an ephemeral container is never restarted
+fn container_running(pod: &Pod, name: &str) -> Result<bool> {
+    let status = pod
+        .status
+        .as_ref()
+        .and_then(|x| x.ephemeral_container_statuses.as_ref())
+        .and_then(|x| x.iter().find(|x| x.name == name));
+    let Some(state) = status.and_then(|x| x.state.as_ref()) else {
+        return Ok(false);
+    };
+    if state.running.is_some() {
+        return Ok(true);
+    }
+    if let Some(terminated) = state.terminated.as_ref() {
+        return Err(eyre!(
+            "{} exited: {}",
+            name,
+            terminated.reason.as_deref().unwrap_or("unknown")
+        ));
+    }
+    match state.waiting.as_ref().and_then(|x| x.reason.as_deref()) {
+        Some(reason @ ("ErrImagePull" | "ImagePullBackOff" | "InvalidImageName")) => {
+            Err(eyre!("{} can not start: {}", name, reason))
+        }
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_container_running() {
+        let pod = |state: Value| -> Pod {
+            serde_json::from_value(json!({
+                "metadata": { "name": "web" },
+                "status": { "ephemeralContainerStatuses": [{
+                    "name": "debugger-1", "image": "busybox", "imageID": "", "ready": false,
+                    "restartCount": 0, "state": state
+                }] }
+            }))
+            .unwrap()
+        };
+        assert!(!container_running(&pod(json!({})), "debugger-2").unwrap());
+        assert!(!container_running(
+            &pod(json!({ "waiting": { "reason": "ContainerCreating" } })),
+            "debugger-1"
+        )
+        .unwrap());
+        assert!(container_running(&pod(json!({ "running": {} })), "debugger-1").unwrap());
+        assert!(container_running(
+            &pod(json!({ "waiting": { "reason": "ErrImagePull" } })),
+            "debugger-1"
+        )
+        .is_err());
+
+        let name = debugger_name(&["debugger-bcdfg"]);
+        assert_eq!("debugger-".len() + NAME_SUFFIX_LENGTH, name.len());
+        assert_ne!("debugger-bcdfg", name);
+    }
+}
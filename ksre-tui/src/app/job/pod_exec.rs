@@ -1,54 +1,57 @@
 use std::sync::Arc;
 
+use color_eyre::eyre::{eyre, Result};
 use futures::StreamExt;
 use k8s_openapi::api::core::v1::Pod;
-use kube::api::AttachParams;
+use kube::api::{AttachParams, AttachedProcess};
 use kube::{Api, Client};
-use tokio::{io::AsyncWriteExt, sync::mpsc};
+use tokio::{
+    io::{AsyncRead, AsyncWriteExt},
+    sync::{mpsc, RwLock},
+};
 use tokio_util::sync::CancellationToken;
 use tui_textarea::TextArea;
 
 pub struct PodExecArgs {
     pub kube_client: Client,
-    // namespace:pod
+    pub namespace: String,
     pub pod_name: String,
     pub container: Option<String>,
+    // None attaches to the main process of the container, e.g. the shell of a debug container
+    pub command: Option<Vec<String>>,
 }
 
+// every line from input_reader goes to stdin, stdout and stderr are appended to result_writer
 pub async fn pod_exec(
     cancel: CancellationToken,
-    result_writer: Arc<tokio::sync::RwLock<TextArea<'static>>>,
+    result_writer: Arc<RwLock<TextArea<'static>>>,
     input_reader: mpsc::Receiver<String>,
     request: PodExecArgs,
-) {
-    /* let input = request.input.unwrap(); */
-    let mut pod_ns_name = request.pod_name.split(':');
-    let namespace = pod_ns_name.next().unwrap();
-    let pod_name = pod_ns_name.next().unwrap();
-
-    let pods_api: Api<Pod> = Api::namespaced(request.kube_client, namespace);
+) -> Result<()> {
+    let pods_api: Api<Pod> = Api::namespaced(request.kube_client, &request.namespace);
 
     let mut attach_opts = default_attached_params();
     if let Some(container) = request.container {
         attach_opts = attach_opts.container(container);
     }
-    let mut attached = pods_api
-        .exec(pod_name, vec!["sh"], &attach_opts)
-        .await
-        .unwrap();
+    let mut attached = match request.command {
+        Some(command) => {
+            pods_api
+                .exec(&request.pod_name, command, &attach_opts)
+                .await?
+        }
+        None => pods_api.attach(&request.pod_name, &attach_opts).await?,
+    };
 
-    let attached_stdout = attached.stdout().unwrap();
-    let attached_stdin = attached.stdin().unwrap();
+    let attached_stdout = attached.stdout().ok_or_else(|| eyre!("no stdout"))?;
+    let attached_stderr = attached.stderr().ok_or_else(|| eyre!("no stderr"))?;
+    let attached_stdin = attached.stdin().ok_or_else(|| eyre!("no stdin"))?;
 
-    let _writer = result_writer.clone();
     let task_0 = tokio::spawn(async move {
         let mut input = input_reader;
         let mut stdin_writer = attached_stdin;
-        // if cmd is clear then don't send, only clear buffer
         while let Some(cmd) = input.recv().await {
-            if cmd.eq("clear") {
-                /* writer.write().await.clear(); */
-            } else if stdin_writer
+            if stdin_writer
                 .write_all(format!("{}\n", cmd).as_bytes())
                 .await
                 .is_err()
@@ -57,36 +60,109 @@ pub async fn pod_exec(
             }
         }
     });
+    let task_1 = tokio::spawn(copy_output(attached_stdout, result_writer.clone()));
+    let task_2 = tokio::spawn(copy_output(attached_stderr, result_writer));
 
-    let task1 = tokio::spawn(async move {
-        let writer = result_writer;
-        let mut stdout_stream = tokio_util::io::ReaderStream::new(attached_stdout);
-        while let Some(next_output) = stdout_stream.next().await {
-            let mut stdout = String::from_utf8(next_output.unwrap().to_vec()).unwrap();
-            trim_newline(&mut stdout);
-            writer.write().await.insert_str(&stdout);
+    // the session ends when it is stopped or the process exits
+    let status = attached.take_status();
+    let exited = async {
+        match status {
+            Some(status) => {
+                status.await;
+            }
+            None => futures::future::pending::<()>().await,
         }
-    });
-    cancel.cancelled().await;
+    };
+    tokio::select! {
+        _ = cancel.cancelled() => {},
+        _ = exited => {},
+    }
+    for task in [task_0, task_1, task_2] {
+        if !task.is_finished() {
+            task.abort();
+        }
+    }
+    Ok(())
+}
+
+async fn copy_output(reader: impl AsyncRead + Unpin, writer: Arc<RwLock<TextArea<'static>>>) {
+    let mut stream = tokio_util::io::ReaderStream::new(reader);
+    while let Some(Ok(chunk)) = stream.next().await {
+        let output = String::from_utf8_lossy(&chunk).replace('\r', "");
+        write_output(&writer, &output).await;
+    }
+}
 
-    if !task1.is_finished() {
-        task1.abort();
+// TextArea takes no newline in insert_str, lines are inserted one by one
+pub async fn write_output(writer: &RwLock<TextArea<'static>>, output: &str) {
+    let mut writer = writer.write().await;
+    let mut lines = output.split('\n');
+    if let Some(first) = lines.next() {
+        writer.insert_str(first);
+    }
+    for line in lines {
+        writer.insert_newline();
+        writer.insert_str(line);
     }
-    if !task_0.is_finished() {
-        task_0.abort();
+}
+
+// a one-shot command whose stdout is read as a stream, e.g. cat or tar of a file transfer
+pub async fn exec_process(
+    api: &Api<Pod>,
+    pod_name: &str,
+    container: &str,
+    command: Vec<String>,
+) -> Result<AttachedProcess> {
+    let params = default_attached_params().container(container);
+    Ok(api.exec(pod_name, command, &params).await?)
+}
+
+// stdout of a finished command, a non-zero exit turns stderr into the error
+pub async fn exec_output(
+    api: &Api<Pod>,
+    pod_name: &str,
+    container: &str,
+    command: Vec<String>,
+) -> Result<String> {
+    let mut process = exec_process(api, pod_name, container, command).await?;
+    let (stdout, stderr) = (process.stdout(), process.stderr());
+    let (stdout, stderr) = tokio::join!(read_all(stdout), read_all(stderr));
+    check_status(&mut process, &stderr).await?;
+    Ok(stdout)
+}
+
+pub async fn read_all(reader: Option<impl AsyncRead + Unpin>) -> String {
+    let mut output = String::new();
+    if let Some(reader) = reader {
+        let mut stream = tokio_util::io::ReaderStream::new(reader);
+        while let Some(Ok(chunk)) = stream.next().await {
+            output.push_str(&String::from_utf8_lossy(&chunk));
+        }
     }
+    output
 }
 
-fn trim_newline(s: &mut String) {
-    if s.ends_with('\r') {
-        s.pop();
+pub async fn check_status(process: &mut AttachedProcess, stderr: &str) -> Result<()> {
+    let Some(status) = process.take_status() else {
+        return Ok(());
+    };
+    match status.await {
+        Some(status) if status.status.as_deref() == Some("Failure") => {
+            let message = match stderr.trim() {
+                "" => status.message.unwrap_or_default(),
+                stderr => stderr.to_string(),
+            };
+            Err(eyre!(message))
+        }
+        _ => Ok(()),
     }
 }
 
+// no tty: a shell without one prints no prompt and no escape sequences
 pub fn default_attached_params() -> AttachParams {
     AttachParams::default()
         .stdin(true)
         .stdout(true)
-        .stderr(false)
-        .tty(true)
+        .stderr(true)
+        .tty(false)
 }
@@ -1,3 +1,4 @@
+use color_eyre::eyre::Result;
 use futures::{AsyncBufReadExt, TryStreamExt};
 use k8s_openapi::api::core::v1::Pod;
 use kube::api::LogParams;
@@ -11,23 +12,24 @@ pub async fn tail_logs(
     writer: mpsc::Sender<String>,
     pod_name: String,
     namespace: String,
-) {
+) -> Result<()> {
     let pods: Api<Pod> = Api::namespaced(kube_client, namespace.as_str());
     let log_opts = LogParams::default();
-    let mut log_stream = pods
-        .log_stream(pod_name.as_str(), &log_opts)
-        .await
-        .unwrap()
-        .lines();
+    let mut log_stream = pods.log_stream(pod_name.as_str(), &log_opts).await?.lines();
 
     loop {
         tokio::select! {
             _ = cancellation_token.cancelled() => break,
             maybe_log = log_stream.try_next() => {
-                if let Ok(Some(line)) = maybe_log {
-                    writer.send(line).await.unwrap();
+                match maybe_log? {
+                    // the reader is gone once the log page was left
+                    Some(line) => if writer.send(line).await.is_err() {
+                        break;
+                    },
+                    None => break,
                 }
             }
         }
     }
+    Ok(())
 }
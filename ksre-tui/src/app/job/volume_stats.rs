@@ -0,0 +1,118 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+
+use color_eyre::eyre::{eyre, Report, Result};
+use futures::StreamExt;
+use k8s_openapi::api::core::v1::Pod;
+use kube::{api::ListParams, Api, Client as KubeClient};
+use tokio::sync::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::kubernetes::api::{
+    access::can_i,
+    storage::{node_volume_stats, VolumeStats},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+// kubelet summaries fetched at the same time
+const MAX_CONCURRENT_NODES: usize = 4;
+
+// usage of the mounted claims of the watched namespace, or of every namespace, from the kubelet
+// summary of the nodes running their pods. a node that can not be reached keeps nothing, its
+// claims show no usage until it answers again. the job ends when nodes/proxy is forbidden
+pub async fn poll_volume_stats(
+    cancel: CancellationToken,
+    kube_client: KubeClient,
+    namespace: Option<String>,
+    stats: Arc<RwLock<HashMap<String, VolumeStats>>>,
+) -> Result<()> {
+    // a failed review lets the requests try
+    if !can_i(kube_client.clone(), "get", "", "nodes/proxy", None)
+        .await
+        .unwrap_or(true)
+    {
+        return Err(eyre!("get nodes/proxy is forbidden, claims show no usage"));
+    }
+    let mut interval = tokio::time::interval(POLL_INTERVAL);
+    loop {
+        let polled = tokio::select! {
+            _ = cancel.cancelled() => return Ok(()),
+            polled = async {
+                interval.tick().await;
+                poll_once(&kube_client, namespace.as_deref()).await
+            } => polled?,
+        };
+        *stats.write().await = polled;
+    }
+}
+
+// only a forbidden node fails the round, other failures leave out the node
+async fn poll_once(
+    kube_client: &KubeClient,
+    namespace: Option<&str>,
+) -> Result<HashMap<String, VolumeStats>> {
+    let nodes = match claim_nodes(kube_client, namespace).await {
+        Ok(nodes) => nodes,
+        Err(err) => {
+            tracing::warn!("list pods for volume stats failed: {}", err);
+            return Ok(HashMap::new());
+        }
+    };
+    let mut results = futures::stream::iter(nodes)
+        .map(|node| async move {
+            let result = node_volume_stats(kube_client, &node).await;
+            (node, result)
+        })
+        .buffer_unordered(MAX_CONCURRENT_NODES);
+    let mut polled = HashMap::new();
+    while let Some((node, result)) = results.next().await {
+        match result {
+            // the summary has the pods of every namespace on the node
+            Ok(node_stats) => {
+                polled.extend(node_stats.into_iter().filter(|(claim, _)| match namespace {
+                    Some(namespace) => claim.split_once('/').is_some_and(|(x, _)| x == namespace),
+                    None => true,
+                }))
+            }
+            Err(err) if is_forbidden(&err) => {
+                return Err(eyre!("volume stats of node {} are forbidden", node));
+            }
+            Err(err) => tracing::warn!("volume stats of node {} failed: {}", node, err),
+        }
+    }
+    Ok(polled)
+}
+
+// nodes running a pod with a claim, other nodes have no usage to show
+async fn claim_nodes(
+    kube_client: &KubeClient,
+    namespace: Option<&str>,
+) -> Result<BTreeSet<String>> {
+    let api: Api<Pod> = match namespace {
+        Some(namespace) => Api::namespaced(kube_client.clone(), namespace),
+        None => Api::all(kube_client.clone()),
+    };
+    let pods = api
+        .list(&ListParams::default().fields("status.phase=Running"))
+        .await?;
+    Ok(pods
+        .items
+        .into_iter()
+        .filter(|pod| {
+            pod.spec
+                .iter()
+                .flat_map(|x| x.volumes.iter().flatten())
+                .any(|volume| {
+                    volume.persistent_volume_claim.is_some() || volume.ephemeral.is_some()
+                })
+        })
+        .filter_map(|pod| pod.spec?.node_name)
+        .collect())
+}
+
+fn is_forbidden(err: &Report) -> bool {
+    matches!(err.downcast_ref::<kube::Error>(), Some(kube::Error::Api(x)) if x.code == 403)
+}
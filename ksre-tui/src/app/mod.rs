@@ -1,8 +1,13 @@
 #![allow(dead_code)]
 
+pub(super) mod alert;
+pub(super) mod clipboard;
+pub(super) mod favorite;
 pub(super) mod handler;
 pub(super) mod job;
 pub(super) mod metrics;
+pub(super) mod search;
+pub(super) mod session;
 pub(super) mod state;
 pub(super) mod ui;
 
@@ -0,0 +1,171 @@
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+use super::state::UserInput;
+
+// a one letter query would list the whole cluster
+const MAX_HITS: usize = 200;
+
+// one object of a cached store and the values it can be found by
+pub struct SearchEntry {
+    pub kind: String,
+    pub namespace: String,
+    pub name: String,
+    pub uid: Option<String>,
+    // e.g. ("ip", "10.2.3.4") or ("label", "app=web")
+    fields: Vec<(&'static str, String)>,
+}
+
+impl SearchEntry {
+    pub fn new(kind: &str, meta: &ObjectMeta) -> Self {
+        let pairs = |x: Option<&std::collections::BTreeMap<String, String>>| {
+            x.into_iter()
+                .flatten()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect::<Vec<String>>()
+        };
+        Self {
+            kind: kind.to_string(),
+            namespace: meta.namespace.clone().unwrap_or_default(),
+            name: meta.name.clone().unwrap_or_default(),
+            uid: meta.uid.clone(),
+            fields: Vec::new(),
+        }
+        .with("label", pairs(meta.labels.as_ref()))
+        .with("annotation", pairs(meta.annotations.as_ref()))
+    }
+
+    pub fn with(mut self, field: &'static str, values: impl IntoIterator<Item = String>) -> Self {
+        self.fields.extend(
+            values
+                .into_iter()
+                .filter(|x| !x.is_empty())
+                .map(|x| (field, x)),
+        );
+        self
+    }
+
+    // namespace/name, cluster scoped objects have only a name
+    pub fn key(&self) -> String {
+        if self.namespace.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.namespace, self.name)
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SearchHit {
+    // position in Search.entries
+    pub entry: usize,
+    pub field: &'static str,
+    pub value: String,
+}
+
+// state of the search overlay, entries are a snapshot of the stores taken when it opens
+pub struct Search {
+    pub entries: Vec<SearchEntry>,
+    pub input: UserInput,
+    pub hits: Vec<SearchHit>,
+    pub selected: usize,
+}
+
+impl Search {
+    pub fn new(entries: Vec<SearchEntry>) -> Self {
+        Self {
+            entries,
+            input: UserInput::default(),
+            hits: Vec::new(),
+            selected: 0,
+        }
+    }
+
+    pub fn update(&mut self) {
+        self.hits = find(&self.entries, self.input.as_str());
+        self.selected = 0;
+    }
+
+    pub fn selected(&self) -> Option<&SearchEntry> {
+        let hit = self.hits.get(self.selected)?;
+        self.entries.get(hit.entry)
+    }
+
+    pub fn next(&mut self) {
+        if self.selected + 1 < self.hits.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+}
+
+// case insensitive substring of the name or of any field, one hit per object.
+// hits keep the order of the entries, which are indexed kind by kind
+fn find(entries: &[SearchEntry], query: &str) -> Vec<SearchHit> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let matches = |x: &str| x.to_lowercase().contains(&query);
+    entries
+        .iter()
+        .enumerate()
+        .filter_map(|(entry, x)| {
+            if matches(&x.name) {
+                return Some(SearchHit {
+                    entry,
+                    field: "name",
+                    value: x.name.clone(),
+                });
+            }
+            let (field, value) = x.fields.iter().find(|(_, value)| matches(value))?;
+            Some(SearchHit {
+                entry,
+                field,
+                value: value.clone(),
+            })
+        })
+        .take(MAX_HITS)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let meta = |namespace: &str, name: &str| ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            labels: Some([("app".to_string(), "web".to_string())].into()),
+            ..Default::default()
+        };
+        let entries = vec![
+            SearchEntry::new("Pod", &meta("default", "web-1"))
+                .with("ip", ["10.2.3.4".to_string()])
+                .with("image", ["foo:1.2".to_string()]),
+            SearchEntry::new("Pod", &meta("default", "api-1")).with("ip", ["10.2.3.5".to_string()]),
+            SearchEntry::new("Deployment", &meta("default", "web"))
+                .with("image", ["FOO:1.2".to_string()]),
+        ];
+
+        let hits = find(&entries, "10.2.3.4");
+        assert_eq!(1, hits.len());
+        assert_eq!(("ip", "10.2.3.4"), (hits[0].field, hits[0].value.as_str()));
+
+        let hits = find(&entries, "foo:1.2");
+        assert_eq!(vec![0, 2], hits.iter().map(|x| x.entry).collect::<Vec<_>>());
+
+        // the name wins over the fields that also match
+        let hits = find(&entries, "web");
+        assert_eq!(
+            vec!["name", "label", "name"],
+            hits.iter().map(|x| x.field).collect::<Vec<_>>()
+        );
+        assert!(find(&entries, " ").is_empty());
+        assert_eq!("default/web", entries[2].key());
+    }
+}
@@ -0,0 +1,138 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use color_eyre::eyre::{eyre, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{
+    favorite::Favorite,
+    state::{AppState, TabPage},
+};
+
+const STATE_FILE: &str = "session.json";
+// context name when running inside a pod with the service account
+pub const IN_CLUSTER_CONTEXT: &str = "in-cluster";
+// names the kubeconfig context to start in, instead of the one of the last run
+const CONTEXT_ENV: &str = "KSRE_CONTEXT";
+
+// Session is what the ui remembers of one cluster context between runs
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Session {
+    pub tabpage: Option<TabPage>,
+    // by name, the namespace list is ordered differently on every run
+    pub namespace: Option<String>,
+    pub filter: String,
+    pub split_vertical: Option<u16>,
+    pub split_horizontal: Option<u16>,
+    #[serde(default)]
+    pub favorites: Vec<Favorite>,
+}
+
+impl Session {
+    pub fn capture(app_state: &AppState) -> Self {
+        Self {
+            tabpage: Some(app_state.get_tabpage()),
+            namespace: app_state.namespace_cache.get().map(|x| x.to_string()),
+            // only the pod tabpage filters by the input, elsewhere it is a prompt
+            filter: match app_state.get_tabpage() {
+                TabPage::Pod => app_state.user_input.as_str().to_string(),
+                _ => String::new(),
+            },
+            split_vertical: Some(app_state.ui_layout.split_vertical),
+            split_horizontal: Some(app_state.ui_layout.split_horizontal),
+            favorites: app_state.favorites.clone(),
+        }
+    }
+
+    // the namespace list must be filled before restoring
+    pub fn restore(&self, app_state: &mut AppState) {
+        if let Some(tabpage) = self.tabpage {
+            app_state.switch_tabpage(tabpage);
+        }
+        let index = self.namespace.as_ref().and_then(|namespace| {
+            app_state
+                .namespace_cache
+                .list()
+                .iter()
+                .position(|x| x.as_ref() == namespace)
+        });
+        if let Some(index) = index {
+            app_state.namespace_cache.select(index);
+        }
+        // switch_tabpage cleared the input, the filter is applied but not being edited
+        for c in self.filter.chars() {
+            app_state.user_input.push(c);
+        }
+        app_state.user_input.complete();
+        app_state.favorites = self.favorites.clone();
+        let layout = &mut app_state.ui_layout;
+        layout.split_vertical = self.split_vertical.unwrap_or(layout.split_vertical);
+        layout.split_horizontal = self.split_horizontal.unwrap_or(layout.split_horizontal);
+    }
+}
+
+// everything kept in the state file, sessions by context name
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct StateFile {
+    last_context: Option<String>,
+    #[serde(default)]
+    sessions: HashMap<String, Session>,
+}
+
+// KSRE_CONTEXT, else the context of the last run while the kubeconfig still has it, else the
+// current context of the kubeconfig. None when there is no kubeconfig, e.g. inside a pod
+pub fn startup_context() -> Option<String> {
+    let kubeconfig = kube::config::Kubeconfig::read().ok()?;
+    let known = |name: &String| kubeconfig.contexts.iter().any(|x| &x.name == name);
+    std::env::var(CONTEXT_ENV)
+        .ok()
+        .or_else(|| read_state().last_context.filter(known))
+        .or(kubeconfig.current_context.clone())
+}
+
+// $XDG_STATE_HOME/ksre, falling back to ~/.local/state/ksre. the log file is kept here too
+pub(crate) fn state_dir() -> Option<PathBuf> {
+    let base = std::env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| {
+            std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state"))
+        })?;
+    Some(base.join("ksre"))
+}
+
+fn state_file() -> Option<PathBuf> {
+    Some(state_dir()?.join(STATE_FILE))
+}
+
+fn read_state() -> StateFile {
+    let Some(data) = state_file().and_then(|path| std::fs::read(path).ok()) else {
+        return StateFile::default();
+    };
+    serde_json::from_slice(&data)
+        // files of older versions are only the map of sessions
+        .or_else(|_| {
+            serde_json::from_slice(&data).map(|sessions| StateFile {
+                last_context: None,
+                sessions,
+            })
+        })
+        .unwrap_or_default()
+}
+
+// a missing or broken state file starts a fresh session
+pub fn load(context: &str) -> Option<Session> {
+    read_state().sessions.remove(context)
+}
+
+// sessions of other contexts are kept as they are
+pub fn save(context: &str, session: Session) -> Result<()> {
+    let path = state_file().ok_or(eyre!("no home directory to keep the session in"))?;
+    let mut state = read_state();
+    state.sessions.insert(context.to_string(), session);
+    state.last_context = Some(context.to_string());
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_vec_pretty(&state)?)?;
+    Ok(())
+}
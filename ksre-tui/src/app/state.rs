@@ -1,13 +1,58 @@
-use std::{char, collections::HashMap, rc::Rc, sync::Arc};
-
-use k8s_openapi::api::core::v1::{PodSpec, PodStatus};
+use std::{
+    char,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+    sync::Arc,
+};
+
+use k8s_openapi::api::{
+    apps::v1::{
+        DaemonSetSpec, DaemonSetStatus, DeploymentSpec, DeploymentStatus, ReplicaSetSpec,
+        ReplicaSetStatus, StatefulSetSpec, StatefulSetStatus,
+    },
+    autoscaling::v2::{HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus},
+    batch::v1::{CronJobSpec, CronJobStatus, JobSpec, JobStatus},
+    core::v1::{
+        NodeSpec, NodeStatus, PersistentVolumeClaimSpec, PersistentVolumeClaimStatus,
+        PersistentVolumeSpec, PersistentVolumeStatus, PodSpec, PodStatus, ServiceSpec,
+        ServiceStatus,
+    },
+};
 use kube::Client as KubeClient;
-use nucleo_matcher::{Config, Matcher};
-use tokio::task::JoinHandle;
+use nucleo_matcher::{
+    pattern::{Atom, AtomKind, CaseMatching, Normalization},
+    Config, Matcher,
+};
+use ratatui::layout::Rect;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{sync::broadcast, task::JoinHandle};
 use tokio_util::sync::CancellationToken;
 use tui_textarea::TextArea;
 
-use crate::kubernetes::{api::pod::PodDescribe, indexer::StoreIndex};
+use super::{
+    alert::Notifier,
+    favorite::Favorite,
+    job::{node_drain::DrainProgress, pod_cp::RemoteDir, JobId, JobManager},
+    search::Search,
+    session,
+};
+use crate::kubernetes::{
+    api::{
+        access::{review_rules, Permission, Permissions},
+        autoscale::HpaSample,
+        cluster::ClusterInfo,
+        config::ConfigData,
+        dynamic::ResourceKind,
+        object::RtObject,
+        pod::PodDescribe,
+        storage::VolumeStats,
+    },
+    indexer::StoreIndex,
+    metrics::pod::PodMetrics,
+    owner::OwnerTree,
+    reflector::dynamic::{DynamicEvent, DynamicReflector},
+};
 
 pub struct AppState {
     kube_client: KubeClient,
@@ -16,41 +61,187 @@ pub struct AppState {
     route: Route,
 
     pub fuzz_matcher: Matcher,
+    pub pod_filter: PodFilter,
+    // namespace and name of the pod marked for the diff view
+    pub diff_mark: Option<(String, String)>,
+    pub diff_view: Option<DiffView>,
     pub user_input: UserInput,
     pub pod_storage: StoreIndex<PodSpec, PodStatus>,
     pub pod_describes: KubeDescribeIndices<PodDescribe>,
+    // the version a pod had before its last watch event, for the diff view
+    pub pod_previous: KubeDescribeIndices<Rc<RtObject<PodSpec, PodStatus>>>,
+    pub node_storage: StoreIndex<NodeSpec, NodeStatus>,
+    pub service_storage: StoreIndex<ServiceSpec, ServiceStatus>,
+    pub configmap_storage: StoreIndex<ConfigData, ()>,
+    pub secret_storage: StoreIndex<ConfigData, ()>,
+    pub deployment_storage: StoreIndex<DeploymentSpec, DeploymentStatus>,
+    pub replicaset_storage: StoreIndex<ReplicaSetSpec, ReplicaSetStatus>,
+    pub statefulset_storage: StoreIndex<StatefulSetSpec, StatefulSetStatus>,
+    pub daemonset_storage: StoreIndex<DaemonSetSpec, DaemonSetStatus>,
+    pub job_storage: StoreIndex<JobSpec, JobStatus>,
+    pub cronjob_storage: StoreIndex<CronJobSpec, CronJobStatus>,
+    pub pvc_storage: StoreIndex<PersistentVolumeClaimSpec, PersistentVolumeClaimStatus>,
+    pub pv_storage: StoreIndex<PersistentVolumeSpec, PersistentVolumeStatus>,
+    pub hpa_storage: StoreIndex<HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus>,
+    // samples of every autoscaler by namespace:name, one per watch event that changed something
+    pub hpa_history: HashMap<String, CycledCache<HpaSample>>,
     pub cache_items: StatefulList,
     pub namespace_cache: StatefulList,
     pub nodes_cache: StatefulList,
-    pub pod_metrics_cache: HashMap<String, HashMap<String, CycledCache<(i64, f64, f64)>>>,
+    // items are namespace/name
+    pub services_cache: StatefulList,
+    // items are ConfigMap/name or Secret/name of the selected namespace
+    pub configs_cache: StatefulList,
+    pub config_keys_cache: StatefulList,
+    // secret keys of the selected object shown in clear text
+    pub config_revealed: HashSet<String>,
+    // items are CronJob/name or Job/name of the selected namespace
+    pub batch_cache: StatefulList,
+    // claim names of the selected namespace and names of every volume
+    pub claims_cache: StatefulList,
+    pub volumes_cache: StatefulList,
+    // kubelet usage of mounted claims by namespace/name, polled once the storage page is shown
+    pub volume_stats: Option<Arc<tokio::sync::RwLock<HashMap<String, VolumeStats>>>>,
+    pub volume_stats_job: Option<JobId>,
+    // autoscaler names of the selected namespace
+    pub autoscalers_cache: StatefulList,
+    // owner tree of the selected namespace, items of workload_rows are uids of visible nodes
+    pub workload_tree: OwnerTree,
+    pub workload_rows: StatefulList,
+    pub workload_collapsed: HashSet<String>,
+    // nodes left by jumping to an owner, popped when going back
+    pub workload_trail: Vec<String>,
+    // node to select at the next resync, e.g. a pod opened from pod tabpage
+    pub workload_focus: Option<String>,
+    // revision numbers of the selected deployment, newest first
+    pub rollout_history: StatefulList,
+    // pinned objects, numbered by their position, items of favorites_cache are their labels
+    pub favorites: Vec<Favorite>,
+    pub favorites_cache: StatefulList,
+    // item to select in the list of the tabpage at the next resync, e.g. the pod of the
+    // last run of a job or a search hit
+    pub focus: Option<String>,
+    // latest metrics-server sample by namespace and pod name, replaced on every poll
+    pub pod_usage: HashMap<String, HashMap<String, PodMetrics>>,
     pub stdout_buffer: Arc<tokio::sync::RwLock<TextArea<'static>>>,
-    pub metrics_buffer: TextArea<'static>,
     pub executor: Option<Executor>,
+    // containers of the selected pod a debug container can target
+    pub debug_targets: StatefulList,
+    // stdin of the running debug session, lines typed in the terminal pane
+    pub term_input: Option<tokio::sync::mpsc::Sender<String>>,
+    // directory of the file browser, listed in background by exec of ls
+    pub remote_dir: Option<Arc<tokio::sync::RwLock<RemoteDir>>>,
+    // every long-running background task, listed in the jobs pane
+    pub jobs: JobManager,
+    pub ui_layout: UiLayout,
+    pub overlay: Option<Overlay>,
+    pub overlay_scroll: u16,
+    pub search: Option<Search>,
+    pub notifier: Notifier,
+    // api server version, readiness and warning events, refreshed in background
+    pub cluster_info: Arc<tokio::sync::RwLock<ClusterInfo>>,
+    // one line message shown in the header, written by background tasks
+    pub status_message: Arc<tokio::sync::RwLock<String>>,
+    // kubeconfig context the client was built from, the session is saved under it
+    pub context: String,
+    // rbac rules of the user by namespace, reviewed in background when a namespace is first shown
+    pub permissions: Arc<tokio::sync::RwLock<HashMap<String, Permissions>>>,
+    // drain is kept apart from executor, so it keeps running when tabpage is switched
+    pub node_drain: Option<Executor>,
+    pub drain_progress: Option<Arc<tokio::sync::RwLock<DrainProgress>>>,
+    // every kind found by api discovery, filled in background
+    pub resource_kinds: Arc<tokio::sync::RwLock<Vec<ResourceKind>>>,
+    pub resource_kinds_cache: StatefulList,
+    // objects of the watched kind, items of resources_cache are namespace/name
+    pub resource_storage: StoreIndex<Value, Value>,
+    pub resources_cache: StatefulList,
+    pub resource_watch: Option<DynamicReflector>,
+    pub resource_tx: broadcast::Sender<DynamicEvent>,
+    pub describe_scroll: u16,
+    // None means the log pane follows the tail, Some(n) keeps it frozen at line n
+    pub log_scroll: Option<u16>,
 
-    // format is timestamp, cpu, memory
     quit: bool,
 }
 
 impl AppState {
     pub fn new(kube_client: KubeClient) -> Self {
-        let mut metrics_buffer = TextArea::default();
-        metrics_buffer.set_max_histories(32);
+        let (resource_tx, _) = broadcast::channel(1024);
         Self {
             kube_client,
             cur_mode: Mode::Normal,
-            route: Route::PodIndex,
+            route: Route::ClusterIndex,
             cache_items: StatefulList::default(),
             namespace_cache: StatefulList::default(),
             nodes_cache: StatefulList::default(),
+            services_cache: StatefulList::default(),
+            configs_cache: StatefulList::default(),
+            config_keys_cache: StatefulList::default(),
+            config_revealed: HashSet::new(),
+            batch_cache: StatefulList::default(),
+            claims_cache: StatefulList::default(),
+            volumes_cache: StatefulList::default(),
+            volume_stats: None,
+            volume_stats_job: None,
+            autoscalers_cache: StatefulList::default(),
+            workload_tree: OwnerTree::default(),
+            workload_rows: StatefulList::default(),
+            workload_collapsed: HashSet::new(),
+            workload_trail: Vec::new(),
+            workload_focus: None,
+            rollout_history: StatefulList::default(),
+            favorites: Vec::new(),
+            favorites_cache: StatefulList::default(),
+            focus: None,
             user_input: UserInput::default(),
             fuzz_matcher: Matcher::new(Config::DEFAULT),
-            tabpage: TabPage::Pod,
+            pod_filter: PodFilter::default(),
+            tabpage: TabPage::Cluster,
             pod_storage: StoreIndex::new(),
             pod_describes: KubeDescribeIndices::new(),
+            pod_previous: KubeDescribeIndices::new(),
+            diff_mark: None,
+            diff_view: None,
+            node_storage: StoreIndex::new(),
+            service_storage: StoreIndex::new(),
+            configmap_storage: StoreIndex::new(),
+            secret_storage: StoreIndex::new(),
+            deployment_storage: StoreIndex::new(),
+            replicaset_storage: StoreIndex::new(),
+            statefulset_storage: StoreIndex::new(),
+            daemonset_storage: StoreIndex::new(),
+            job_storage: StoreIndex::new(),
+            cronjob_storage: StoreIndex::new(),
+            pvc_storage: StoreIndex::new(),
+            pv_storage: StoreIndex::new(),
+            hpa_storage: StoreIndex::new(),
+            hpa_history: HashMap::new(),
             stdout_buffer: Arc::new(tokio::sync::RwLock::new(TextArea::default())),
-            metrics_buffer,
             executor: None,
-            pod_metrics_cache: HashMap::new(),
+            debug_targets: StatefulList::default(),
+            term_input: None,
+            remote_dir: None,
+            jobs: JobManager::default(),
+            ui_layout: UiLayout::default(),
+            overlay: None,
+            overlay_scroll: 0,
+            search: None,
+            notifier: Notifier::from_env(),
+            cluster_info: Arc::new(tokio::sync::RwLock::new(ClusterInfo::default())),
+            status_message: Arc::new(tokio::sync::RwLock::new(String::new())),
+            context: session::IN_CLUSTER_CONTEXT.to_string(),
+            permissions: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            node_drain: None,
+            drain_progress: None,
+            resource_kinds: Arc::new(tokio::sync::RwLock::new(Vec::new())),
+            resource_kinds_cache: StatefulList::default(),
+            resource_storage: StoreIndex::new(),
+            resources_cache: StatefulList::default(),
+            resource_watch: None,
+            resource_tx,
+            describe_scroll: 0,
+            log_scroll: None,
+            pod_usage: HashMap::new(),
             quit: false,
         }
     }
@@ -61,21 +252,108 @@ impl AppState {
     pub fn kube_client(&self) -> KubeClient {
         self.kube_client.clone()
     }
+
+    // actions stay available until the review of the namespace is back
+    pub fn review_permissions(&self, namespace: &str) {
+        let Ok(mut permissions) = self.permissions.try_write() else {
+            return;
+        };
+        if permissions.contains_key(namespace) {
+            return;
+        }
+        permissions.insert(namespace.to_string(), Permissions::default());
+        let permissions = self.permissions.clone();
+        let kube_client = self.kube_client();
+        let namespace = namespace.to_string();
+        tokio::spawn(async move {
+            match review_rules(kube_client, &namespace).await {
+                Ok(reviewed) => {
+                    permissions.write().await.insert(namespace, reviewed);
+                }
+                Err(err) => tracing::warn!("rules review of {} failed: {}", namespace, err),
+            }
+        });
+    }
+
+    // whether the user may do it in the selected namespace
+    pub fn is_permitted(&self, permission: &Permission) -> bool {
+        match self.namespace_cache.get() {
+            Some(namespace) => self.is_permitted_in(namespace.as_ref(), permission),
+            None => true,
+        }
+    }
+
+    // a namespace not reviewed yet allows everything, see review_permissions
+    pub fn is_permitted_in(&self, namespace: &str, permission: &Permission) -> bool {
+        let Ok(permissions) = self.permissions.try_read() else {
+            return true;
+        };
+        match permissions.get(namespace) {
+            Some(reviewed) => reviewed.allows(permission),
+            None => true,
+        }
+    }
 }
 
 // AppState[#TODO] (should add some comments)
 impl AppState {
     pub fn next_route(&mut self) {
+        self.switch_tabpage(self.tabpage.next());
+    }
+
+    pub fn switch_tabpage(&mut self, tabpage: TabPage) {
         // switch route will stop all executors
         self.executor.take();
         // clean all relative buffer
         self.user_input.clear();
         self.cache_items.reset();
-        // clean all metrics buffer
-        self.metrics_buffer.select_all();
-        self.metrics_buffer.cut();
+        self.pod_filter = PodFilter::default();
+        self.describe_scroll = 0;
+        self.log_scroll = None;
+
+        // the next visit polls again
+        if tabpage != TabPage::Storage {
+            if let Some(id) = self.volume_stats_job.take() {
+                self.jobs.stop(id);
+                self.volume_stats = None;
+            }
+        }
+
+        self.tabpage = tabpage;
+        self.route = tabpage.index_route();
+    }
 
-        self.tabpage = self.tabpage.next();
+    pub fn select_namespace(&mut self, namespace: &str) {
+        let index = self
+            .namespace_cache
+            .list()
+            .iter()
+            .position(|x| x.as_ref() == namespace);
+        if let Some(index) = index {
+            self.namespace_cache.select(index);
+        }
+    }
+
+    // the workload tree is focused by uid through workload_focus instead
+    pub fn select_in_list(&mut self, item: &str) {
+        let list = match self.tabpage {
+            TabPage::Cluster => &mut self.favorites_cache,
+            TabPage::Pod => &mut self.cache_items,
+            TabPage::Deploy => return,
+            TabPage::Node => &mut self.nodes_cache,
+            TabPage::Service => &mut self.services_cache,
+            TabPage::Config => &mut self.configs_cache,
+            TabPage::Batch => &mut self.batch_cache,
+            TabPage::Storage => match self.route {
+                Route::StorageVolumes => &mut self.volumes_cache,
+                _ => &mut self.claims_cache,
+            },
+            TabPage::Autoscale => &mut self.autoscalers_cache,
+            TabPage::Resource => &mut self.resources_cache,
+        };
+        if let Some(index) = list.list().iter().position(|x| x.as_ref() == item) {
+            list.select(index);
+        }
     }
 
     #[inline]
@@ -147,7 +425,7 @@ impl Drop for Executor {
         }
         if let Some(async_task) = self.async_task.take() {
             for single_task in async_task.into_iter() {
-                if single_task.is_finished() {
+                if !single_task.is_finished() {
                     single_task.abort();
                 }
             }
@@ -163,14 +441,14 @@ pub struct CycledCache<T> {
 }
 
 impl<T: Clone> CycledCache<T> {
-    fn with_capacity(capacity: usize) -> Self {
+    pub fn with_capacity(capacity: usize) -> Self {
         CycledCache {
             items: Vec::<T>::new(),
             capacity,
             start_index: 0,
         }
     }
-    fn append(&mut self, obj: T) {
+    pub fn append(&mut self, obj: T) {
         if self.items.len() == self.capacity {
             *self.items.get_mut(self.start_index).unwrap() = obj;
             self.start_index = (self.start_index + 1) % self.capacity;
@@ -178,6 +456,12 @@ impl<T: Clone> CycledCache<T> {
             self.items.push(obj);
         }
     }
+    // the most recently appended item
+    pub fn last(&self) -> Option<&T> {
+        let end = (self.start_index + self.items.len()).checked_sub(1)?;
+        self.items.get(end % self.items.len())
+    }
+
     pub fn get_all(&self) -> Vec<T> {
         let mut result = Vec::new();
         result.extend_from_slice(&self.items[self.start_index..]);
@@ -236,6 +520,12 @@ impl StatefulList {
         self.index = 0;
     }
     #[inline]
+    pub fn select(&mut self, index: usize) {
+        if index < self.items.len() {
+            self.index = index;
+        }
+    }
+    #[inline]
     pub fn reset(&mut self) {
         self.confirmed = false;
         self.items.clear();
@@ -277,6 +567,68 @@ impl StatefulList {
     }
 }
 
+// what the pod diff view compares, pods are namespace and name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DiffView {
+    Pods((String, String), (String, String)),
+    // one pod before and after its last watch event
+    Previous(String, String),
+}
+
+// pods of a namespace matched by the filter input. the last result is kept, a keystroke
+// that only narrows the query fuzzes the previous matches instead of the whole namespace
+#[derive(Default)]
+pub struct PodFilter {
+    namespace: String,
+    query: String,
+    generation: u64,
+    matches: Vec<Rc<str>>,
+    valid: bool,
+}
+
+impl PodFilter {
+    // None when neither the pods nor the query changed since the last call
+    pub fn apply(
+        &mut self,
+        namespace: &str,
+        query: &str,
+        generation: u64,
+        names: impl FnOnce() -> Vec<Rc<str>>,
+        matcher: &mut Matcher,
+    ) -> Option<Vec<Rc<str>>> {
+        let same_pods = self.valid && self.namespace == namespace && self.generation == generation;
+        if same_pods && self.query == query {
+            return None;
+        }
+        let candidates = if same_pods && query.starts_with(self.query.as_str()) {
+            std::mem::take(&mut self.matches)
+        } else {
+            names()
+        };
+        let matches = if query.is_empty() {
+            candidates
+        } else {
+            Atom::new(
+                query,
+                CaseMatching::Ignore,
+                Normalization::Smart,
+                AtomKind::Fuzzy,
+                false,
+            )
+            .match_list(candidates, matcher)
+            .into_iter()
+            .map(|x| x.0)
+            .collect()
+        };
+        self.namespace = namespace.to_string();
+        self.query = query.to_string();
+        self.generation = generation;
+        self.matches = matches.clone();
+        self.valid = true;
+        Some(matches)
+    }
+}
+
 pub struct KubeDescribeIndices<T> {
     indices: HashMap<String, HashMap<String, T>>,
 }
@@ -292,6 +644,12 @@ impl<T> KubeDescribeIndices<T> {
         store.get(name)
     }
 
+    pub fn delete(&mut self, namespace: &str, name: &str) {
+        if let Some(store) = self.indices.get_mut(namespace) {
+            store.remove(name);
+        }
+    }
+
     pub fn add(&mut self, namespace: String, name: String, obj: T) {
         if self.indices.get(namespace.as_str()).is_none() {
             let cache = HashMap::from([(name.to_string(), obj)]);
@@ -357,40 +715,188 @@ impl UserInput {
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TabPage {
+    Cluster,
     Pod,
     Deploy,
     Node,
+    Service,
+    Config,
+    Batch,
+    Storage,
+    Autoscale,
+    Resource,
 }
 
 impl TabPage {
+    pub const ALL: [TabPage; 10] = [
+        TabPage::Cluster,
+        TabPage::Pod,
+        TabPage::Deploy,
+        TabPage::Node,
+        TabPage::Service,
+        TabPage::Config,
+        TabPage::Batch,
+        TabPage::Storage,
+        TabPage::Autoscale,
+        TabPage::Resource,
+    ];
+
     pub fn next(self) -> Self {
         match self {
+            TabPage::Cluster => TabPage::Pod,
             TabPage::Pod => TabPage::Deploy,
             TabPage::Deploy => TabPage::Node,
-            TabPage::Node => TabPage::Pod,
+            TabPage::Node => TabPage::Service,
+            TabPage::Service => TabPage::Config,
+            TabPage::Config => TabPage::Batch,
+            TabPage::Batch => TabPage::Storage,
+            TabPage::Storage => TabPage::Autoscale,
+            TabPage::Autoscale => TabPage::Resource,
+            TabPage::Resource => TabPage::Cluster,
         }
     }
     pub fn prev(self) -> Self {
         match self {
-            TabPage::Pod => TabPage::Node,
+            TabPage::Cluster => TabPage::Resource,
+            TabPage::Pod => TabPage::Cluster,
             TabPage::Deploy => TabPage::Pod,
-            TabPage::Node => TabPage::Pod,
+            TabPage::Node => TabPage::Deploy,
+            TabPage::Service => TabPage::Node,
+            TabPage::Config => TabPage::Service,
+            TabPage::Batch => TabPage::Config,
+            TabPage::Storage => TabPage::Batch,
+            TabPage::Autoscale => TabPage::Storage,
+            TabPage::Resource => TabPage::Autoscale,
+        }
+    }
+    // route shown when the tabpage is entered
+    pub fn index_route(self) -> Route {
+        match self {
+            TabPage::Cluster => Route::ClusterIndex,
+            TabPage::Pod => Route::PodIndex,
+            TabPage::Deploy => Route::DeployIndex,
+            TabPage::Node => Route::NodeIndex,
+            TabPage::Service => Route::ServiceIndex,
+            TabPage::Config => Route::ConfigIndex,
+            TabPage::Batch => Route::BatchIndex,
+            TabPage::Storage => Route::StorageClaims,
+            TabPage::Autoscale => Route::AutoscaleIndex,
+            TabPage::Resource => Route::ResourceKinds,
         }
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Route {
+    ClusterIndex,
+
     PodIndex,
     PodNamespace,
     PodList,
     PodState,
     PodLog,
     PodTerm,
+    PodCapacity,
+    PodDiff,
+    PodDebug,
+    PodFiles,
+    PodUpload,
 
     DeployIndex,
+    DeployHistory,
+    DeployScale,
+
+    NodeIndex,
+    NodeDrainConfirm,
+    NodeDrain,
+
+    ServiceIndex,
+
+    ConfigIndex,
+    ConfigKeys,
+
+    BatchIndex,
+
+    StorageClaims,
+    StorageVolumes,
+
+    AutoscaleIndex,
+
+    ResourceKinds,
+    ResourceIndex,
+    ResourceYaml,
+}
+
+// screen areas recorded by the last draw, mouse events are hit-tested against them
+pub struct UiLayout {
+    pub tabs: Vec<(Rect, TabPage)>,
+    pub namespace_list: Rect,
+    pub pod_list: Rect,
+    // first pod drawn, only the rows that fit are rendered
+    pub pod_list_offset: usize,
+    pub node_list: Rect,
+    pub node_list_offset: usize,
+    pub bottom_body: Rect,
+    pub page_body: Rect,
+    pub divider_row: u16,
+    pub divider_col: u16,
+    // percentage of the page taken by the top panes / the left pane
+    pub split_vertical: u16,
+    pub split_horizontal: u16,
+    pub dragging: Option<Divider>,
+}
+
+impl Default for UiLayout {
+    fn default() -> Self {
+        Self {
+            tabs: Vec::new(),
+            namespace_list: Rect::default(),
+            pod_list: Rect::default(),
+            pod_list_offset: 0,
+            node_list: Rect::default(),
+            node_list_offset: 0,
+            bottom_body: Rect::default(),
+            page_body: Rect::default(),
+            divider_row: 0,
+            divider_col: 0,
+            split_vertical: 50,
+            split_horizontal: 50,
+            dragging: None,
+        }
+    }
+}
+
+impl UiLayout {
+    // forget areas of the previous frame, split ratios survive
+    pub fn reset_areas(&mut self) {
+        self.tabs.clear();
+        self.namespace_list = Rect::default();
+        self.pod_list = Rect::default();
+        self.node_list = Rect::default();
+        self.bottom_body = Rect::default();
+        self.page_body = Rect::default();
+        self.divider_row = 0;
+        self.divider_col = 0;
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Divider {
+    TopBottom,
+    LeftRight,
+}
+
+// popups drawn above the current tabpage, they take all key events while shown
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overlay {
+    Help,
+    Alerts,
+    Jobs,
+    Debug,
+    Yank,
+    Search,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -405,10 +911,14 @@ mod tests {
     #[test]
     fn test_appendonly_cache() {
         let mut cache = CycledCache::<i32>::with_capacity(10);
-        for i in 0..12 {
+        assert_eq!(None, cache.last());
+        cache.append(0);
+        assert_eq!(Some(&0), cache.last());
+        for i in 1..12 {
             cache.append(i);
         }
         assert_eq!(vec![2, 3, 4, 5, 6, 7, 8, 9, 10, 11], cache.get_all());
+        assert_eq!(Some(&11), cache.last());
 
         assert_eq!(vec![2, 3], cache.get_all_limit(2));
 
@@ -417,4 +927,21 @@ mod tests {
             cache.get_all_limit(20)
         );
     }
+
+    #[test]
+    fn test_pod_filter() {
+        let mut matcher = Matcher::new(Config::DEFAULT);
+        let mut filter = PodFilter::default();
+        let pods = || -> Vec<Rc<str>> { vec![Rc::from("web-1"), Rc::from("api-1")] };
+        let matches = filter.apply("default", "w", 1, pods, &mut matcher);
+        assert_eq!(Some(vec![Rc::from("web-1")]), matches);
+        // nothing changed, nothing to redo
+        assert_eq!(None, filter.apply("default", "w", 1, pods, &mut matcher));
+        // a longer query only looks at the previous matches
+        let matches = filter.apply("default", "we", 1, || unreachable!(), &mut matcher);
+        assert_eq!(Some(vec![Rc::from("web-1")]), matches);
+        // a pod event lists the namespace again
+        let matches = filter.apply("default", "", 2, pods, &mut matcher);
+        assert_eq!(Some(pods()), matches);
+    }
 }
@@ -0,0 +1,88 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{
+    alert::{Alert, AlertKind},
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::centered_rect,
+    },
+};
+
+const TOAST_WIDTH: u16 = 64;
+const TOAST_HEIGHT: u16 = 4;
+
+fn kind_color(kind: AlertKind) -> Color {
+    match kind {
+        AlertKind::Restarted => theme::DefaultTheme::YELLOW_CARP,
+        AlertKind::OOMKilled => theme::DefaultTheme::RED_SAMURAI,
+        AlertKind::FailedScheduling => theme::DefaultTheme::VIOLET_ONI,
+        AlertKind::ImagePull => theme::DefaultTheme::RED_WAVE,
+    }
+}
+
+// toasts are stacked upward from the bottom right corner
+pub(super) fn draw_toasts(f: &mut Frame, state: &AppState, area: Rect) {
+    if area.width < TOAST_WIDTH || area.height < TOAST_HEIGHT {
+        return;
+    }
+    let x = area.x + area.width - TOAST_WIDTH;
+    let mut y = area.y + area.height;
+    for alert in state.notifier.toasts() {
+        if y < area.y + TOAST_HEIGHT {
+            break;
+        }
+        y -= TOAST_HEIGHT;
+        let toast_area = Rect::new(x, y, TOAST_WIDTH, TOAST_HEIGHT);
+        let toast = Paragraph::new(alert.message.as_str())
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(format!("{} {}/{}", alert.kind, alert.namespace, alert.name))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(kind_color(alert.kind))),
+            );
+        f.render_widget(Clear, toast_area);
+        f.render_widget(toast, toast_area);
+    }
+}
+
+pub(super) fn draw_alert_history(f: &mut Frame, state: &AppState, area: Rect) {
+    let area = centered_rect(80, 70, area);
+    let lines = state
+        .notifier
+        .history()
+        .map(alert_line)
+        .collect::<Vec<Line>>();
+    let history = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title("Alerts [j/k]:scroll [c]:clear [!]:close")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .scroll((state.overlay_scroll, 0));
+    f.render_widget(Clear, area);
+    f.render_widget(history, area);
+}
+
+fn alert_line(alert: &Alert) -> Line<'_> {
+    Line::from(vec![
+        Span::raw(format!("{} ", alert.time.format("%H:%M:%S"))),
+        Span::styled(
+            format!("{:<18}", alert.kind.to_string()),
+            Style::default().fg(kind_color(alert.kind)),
+        ),
+        Span::raw(format!(
+            "{}/{} {}",
+            alert.namespace, alert.name, alert.message
+        )),
+    ])
+}
@@ -0,0 +1,307 @@
+use chrono::Utc;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    symbols::Marker,
+    text::{Line, Span},
+    widgets::{
+        Axis, Block, BorderType, Borders, Chart, Dataset, GraphType, Paragraph, Row, Table,
+        TableState, Wrap,
+    },
+    Frame,
+};
+
+use crate::app::{
+    handler::{autoscale::selected_hpa, keybind::valid_bindings},
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::autoscale::{
+        hpa_metrics, hpa_replicas, scale_target, step_points, targets_summary, HpaObject, HpaSample,
+    },
+    helper::age,
+};
+
+// one color per metric line, repeated when there are more metrics
+const METRIC_COLORS: [Color; 4] = [
+    theme::DefaultTheme::BLUE_SPRING,
+    theme::DefaultTheme::YELLOW_CARP,
+    theme::DefaultTheme::PINK_SAKURA,
+    theme::DefaultTheme::AQUA_WAVE2,
+];
+
+// ------------------------------------
+// autoscalers of the namespace       |
+// ------------------------------------
+// metrics and     | replicas chart   |
+// conditions      | metrics chart    |
+// ------------------------------------
+// help
+pub(super) fn draw_page_autoscale(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Percentage(40),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+
+    draw_autoscalers(f, state, chunks[0]);
+    match selected_hpa(state) {
+        Some(hpa) => {
+            let body = uiutil::horizontal_chunks(
+                vec![Constraint::Percentage(40), Constraint::Percentage(60)],
+                chunks[1],
+            );
+            draw_detail(f, &hpa, body[0]);
+            draw_history(f, state, &hpa, body[1]);
+        }
+        None => f.render_widget(uiutil::debug_widget("Empty"), chunks[1]),
+    }
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[2],
+    );
+}
+
+fn draw_autoscalers(f: &mut Frame, state: &AppState, area: Rect) {
+    let namespace = state.namespace_cache.get().unwrap_or_default();
+    let rows = state
+        .autoscalers_cache
+        .list()
+        .iter()
+        .filter_map(|name| state.hpa_storage.get(namespace.as_ref(), name))
+        .map(|hpa| {
+            let (current, desired) = hpa_replicas(&hpa);
+            let row = Row::new(vec![
+                hpa.0.metadata.name.clone().unwrap_or_default(),
+                scale_target(&hpa),
+                targets_summary(&hpa),
+                hpa.0.spec.min_replicas.unwrap_or(1).to_string(),
+                hpa.0.spec.max_replicas.to_string(),
+                format!("{}/{}", current, desired),
+                age(hpa.0.metadata.creation_timestamp.as_ref()),
+            ]);
+            if current != desired {
+                row.style(Style::default().fg(theme::DefaultTheme::YELLOW_CARP))
+            } else {
+                row
+            }
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(18),
+        Constraint::Percentage(18),
+        Constraint::Percentage(40),
+        Constraint::Percentage(5),
+        Constraint::Percentage(5),
+        Constraint::Percentage(8),
+        Constraint::Percentage(6),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec![
+                "Name",
+                "Reference",
+                "Targets",
+                "Min",
+                "Max",
+                "Replicas",
+                "Age",
+            ])
+            .bold(),
+        )
+        .block(
+            Block::default()
+                .title(format!("Autoscalers of {} [n]:next namespace", namespace))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state =
+        TableState::default().with_selected(Some(state.autoscalers_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+// every metric against its target, then the conditions explaining why it does not scale
+fn draw_detail(f: &mut Frame, hpa: &HpaObject, area: Rect) {
+    let outer = uiutil::outer_block(f, &format!("Scales {} [t]:show", scale_target(hpa)), area);
+    let (current, desired) = hpa_replicas(hpa);
+    let mut lines = vec![Line::from(format!(
+        "replicas:      {} current, {} desired, {}..{}",
+        current,
+        desired,
+        hpa.0.spec.min_replicas.unwrap_or(1),
+        hpa.0.spec.max_replicas
+    ))];
+    for (idx, metric) in hpa_metrics(hpa).iter().enumerate() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<15}", metric.name),
+                Style::default().fg(METRIC_COLORS[idx % METRIC_COLORS.len()]),
+            ),
+            Span::raw(format!(
+                "{} of {}",
+                metric.current.as_deref().unwrap_or("<unknown>"),
+                metric.target
+            )),
+        ]));
+    }
+    let conditions = hpa
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.conditions.as_deref())
+        .unwrap_or_default();
+    for condition in conditions {
+        // ScalingLimited is the one that is bad when True
+        let healthy = (condition.status == "True") != (condition.type_ == "ScalingLimited");
+        let color = if healthy {
+            theme::DefaultTheme::GREEN_SPRING
+        } else {
+            theme::DefaultTheme::RED_PEACH
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<15}", condition.type_),
+                Style::default().fg(color),
+            ),
+            Span::raw(format!(
+                "{} {}: {}",
+                condition.status,
+                condition.reason.as_deref().unwrap_or_default(),
+                condition.message.as_deref().unwrap_or_default()
+            )),
+        ]));
+    }
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), outer);
+}
+
+// replicas above, metrics as percent of their target below, both over the kept samples.
+// a sample is a change, each value is drawn flat until the next one and up to now
+fn draw_history(f: &mut Frame, state: &AppState, hpa: &HpaObject, area: Rect) {
+    let samples = state
+        .hpa_history
+        .get(&hpa.resource_name())
+        .map(|x| x.get_all())
+        .unwrap_or_default();
+    if samples.is_empty() {
+        f.render_widget(uiutil::debug_widget("no samples yet"), area);
+        return;
+    }
+    let chunks = uiutil::vertical_chunks(
+        vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        area,
+    );
+    let now = Utc::now().timestamp();
+    // a single sample still gets a minute of axis
+    let since = (samples[0].time - now).min(-60) as f64;
+    let x_axis = || {
+        Axis::default()
+            .bounds([since, 0.0])
+            .labels(vec![
+                Span::raw(format!("-{}m", (-since / 60.0).round())),
+                Span::raw("now"),
+            ])
+            .style(Style::default().fg(theme::DefaultTheme::VIOLET_SPRING1))
+    };
+    let x = |sample: &HpaSample| (sample.time - now) as f64;
+
+    let current = samples
+        .iter()
+        .map(|s| (x(s), s.current as f64))
+        .collect::<Vec<(f64, f64)>>();
+    let current = step_points(&current, 0.0);
+    let desired = samples
+        .iter()
+        .map(|s| (x(s), s.desired as f64))
+        .collect::<Vec<(f64, f64)>>();
+    let desired = step_points(&desired, 0.0);
+    let top = samples
+        .iter()
+        .map(|s| s.current.max(s.desired))
+        .max()
+        .unwrap_or(0)
+        .max(hpa.0.spec.max_replicas) as f64;
+    let replicas = Chart::new(vec![
+        Dataset::default()
+            .name("current")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme::DefaultTheme::GREEN_SPRING))
+            .data(&current),
+        Dataset::default()
+            .name("desired")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(theme::DefaultTheme::YELLOW_CARP))
+            .data(&desired),
+    ])
+    .block(uiutil::titled_block("Replicas"))
+    .x_axis(x_axis())
+    .y_axis(
+        Axis::default()
+            .bounds([0.0, top + 1.0])
+            .labels(vec![Span::raw("0"), Span::raw(format!("{}", top + 1.0))])
+            .style(Style::default().fg(theme::DefaultTheme::VIOLET_SPRING1)),
+    );
+    f.render_widget(replicas, chunks[0]);
+
+    let metrics = hpa_metrics(hpa);
+    let series = (0..metrics.len())
+        .map(|idx| {
+            samples
+                .iter()
+                .filter_map(|s| Some((x(s), (*s.ratios.get(idx)?)?)))
+                .collect::<Vec<(f64, f64)>>()
+        })
+        .map(|points| step_points(&points, 0.0))
+        .collect::<Vec<Vec<(f64, f64)>>>();
+    let target = [(since, 100.0), (0.0, 100.0)];
+    let top = series
+        .iter()
+        .flatten()
+        .map(|(_, y)| *y)
+        .fold(200.0, f64::max);
+    let mut datasets = vec![Dataset::default()
+        .name("target")
+        .graph_type(GraphType::Line)
+        .style(Style::default().fg(theme::DefaultTheme::VIOLET_SPRING1))
+        .data(&target)];
+    for (idx, (metric, data)) in metrics.iter().zip(series.iter()).enumerate() {
+        datasets.push(
+            Dataset::default()
+                .name(metric.name.clone())
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(METRIC_COLORS[idx % METRIC_COLORS.len()]))
+                .data(data),
+        );
+    }
+    let chart = Chart::new(datasets)
+        .block(uiutil::titled_block("Metrics, % of target"))
+        .x_axis(x_axis())
+        .y_axis(
+            Axis::default()
+                .bounds([0.0, top])
+                // labels are spread evenly over the bounds
+                .labels(vec![
+                    Span::raw("0"),
+                    Span::raw(format!("{:.0}", top / 2.0)),
+                    Span::raw(format!("{:.0}", top)),
+                ])
+                .style(Style::default().fg(theme::DefaultTheme::VIOLET_SPRING1)),
+        );
+    f.render_widget(chart, chunks[1]);
+}
@@ -0,0 +1,207 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::{
+    handler::{
+        batch::{selected_batch, SelectedBatch},
+        keybind::valid_bindings,
+    },
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::batch::{
+        cronjob_active, cronjob_history, is_suspended, job_completions, job_duration, job_failures,
+        job_status, latest_job_pod, CronJobObject, JobObject,
+    },
+    helper::age,
+};
+
+// ------------------------------------
+// cronjobs and jobs                  |
+// ------------------------------------
+// run history / job pods             |
+// ------------------------------------
+// help
+pub(super) fn draw_page_batch(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Percentage(55),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+
+    draw_batch_objects(f, state, chunks[0]);
+    match selected_batch(state) {
+        Some(SelectedBatch::CronJob(cronjob)) => draw_history(f, state, &cronjob, chunks[1]),
+        Some(SelectedBatch::Job(job)) => draw_job_detail(f, state, &job, chunks[1]),
+        None => f.render_widget(uiutil::debug_widget("Empty"), chunks[1]),
+    }
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[2],
+    );
+}
+
+fn draw_batch_objects(f: &mut Frame, state: &AppState, area: Rect) {
+    let namespace = state.namespace_cache.get().unwrap_or_default();
+    let rows = state
+        .batch_cache
+        .list()
+        .iter()
+        .filter_map(|item| {
+            let (kind, name) = item.split_once('/')?;
+            let row = match kind {
+                "CronJob" => cronjob_row(&*state.cronjob_storage.get(namespace.as_ref(), name)?),
+                _ => job_row(&*state.job_storage.get(namespace.as_ref(), name)?),
+            };
+            Some(row)
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(8),
+        Constraint::Percentage(32),
+        Constraint::Percentage(16),
+        Constraint::Percentage(16),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+        Constraint::Percentage(8),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec![
+                "Kind",
+                "Name",
+                "Schedule/Status",
+                "Last schedule",
+                "Active/Done",
+                "Failed",
+                "Age",
+            ])
+            .bold(),
+        )
+        .block(
+            Block::default()
+                .title(format!("Batch of {} [n]:next namespace", namespace))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.batch_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn cronjob_row(cronjob: &CronJobObject) -> Row<'static> {
+    let mut schedule = cronjob.0.spec.schedule.clone();
+    if is_suspended(cronjob) {
+        schedule.push_str(" (suspended)");
+    }
+    let last_schedule = cronjob
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.last_schedule_time.as_ref());
+    Row::new(vec![
+        "CronJob".to_string(),
+        cronjob.0.metadata.name.clone().unwrap_or_default(),
+        schedule,
+        last_schedule.map_or("<none>".to_string(), |x| format!("{} ago", age(Some(x)))),
+        cronjob_active(cronjob).to_string(),
+        String::new(),
+        age(cronjob.0.metadata.creation_timestamp.as_ref()),
+    ])
+}
+
+fn job_row(job: &JobObject) -> Row<'static> {
+    let status = job_status(job);
+    Row::new(vec![
+        "Job".to_string(),
+        job.0.metadata.name.clone().unwrap_or_default(),
+        format!("{} {}", status, job_duration(job)),
+        String::new(),
+        job_completions(job),
+        job_failures(job).to_string(),
+        age(job.0.metadata.creation_timestamp.as_ref()),
+    ])
+    .style(Style::default().fg(status_color(status)))
+}
+
+#[inline]
+fn status_color(status: &str) -> Color {
+    match status {
+        "Complete" => theme::DefaultTheme::GREEN_SPRING,
+        "Failed" => theme::DefaultTheme::RED_PEACH,
+        "Suspended" => theme::DefaultTheme::VIOLET_SPRING1,
+        _ => theme::DefaultTheme::YELLOW_CARP,
+    }
+}
+
+// jobs created by the cronjob, the most recent run first
+fn draw_history(f: &mut Frame, state: &AppState, cronjob: &CronJobObject, area: Rect) {
+    let history = cronjob_history(cronjob, state.job_storage.iter());
+    let title = format!(
+        "Runs of {}",
+        cronjob.0.metadata.name.as_deref().unwrap_or_default()
+    );
+    let outer = uiutil::outer_block(f, title.as_str(), area);
+    if history.is_empty() {
+        f.render_widget(Paragraph::new("no runs kept in history"), outer);
+        return;
+    }
+    let lines = history
+        .iter()
+        .map(|job| {
+            let status = job_status(job);
+            Line::from(format!(
+                "{:<56}{:<12}{:<10}{:<10}{}",
+                job.0.metadata.name.as_deref().unwrap_or_default(),
+                status,
+                job_completions(job),
+                job_duration(job),
+                age(job.0.metadata.creation_timestamp.as_ref()),
+            ))
+            .style(Style::default().fg(status_color(status)))
+        })
+        .collect::<Vec<Line>>();
+    f.render_widget(Paragraph::new(lines), outer);
+}
+
+fn draw_job_detail(f: &mut Frame, state: &AppState, job: &JobObject, area: Rect) {
+    let title = format!("Job {}", job.0.metadata.name.as_deref().unwrap_or_default());
+    let outer = uiutil::outer_block(f, title.as_str(), area);
+    let owner = job
+        .0
+        .metadata
+        .owner_references
+        .as_deref()
+        .and_then(|x| x.first())
+        .map_or("<none>".to_string(), |x| format!("{}/{}", x.kind, x.name));
+    let latest_pod = latest_job_pod(job, state.pod_storage.iter())
+        .and_then(|pod| pod.0.metadata.name.clone())
+        .unwrap_or("<none>".to_string());
+    let lines = vec![
+        Line::from(format!("status:        {}", job_status(job))),
+        Line::from(format!("completions:   {}", job_completions(job))),
+        Line::from(format!("failed:        {}", job_failures(job))),
+        Line::from(format!("duration:      {}", job_duration(job))),
+        Line::from(format!("owner:         {}", owner)),
+        Line::from(format!("latest pod:    {} [l]:logs", latest_pod)),
+    ];
+    f.render_widget(Paragraph::new(lines), outer);
+}
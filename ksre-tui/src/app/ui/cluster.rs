@@ -1,17 +1,277 @@
-use ratatui::Frame;
+use std::collections::BTreeMap;
 
-use ratatui::layout::{Layout, Rect};
-use ratatui::prelude::Constraint;
-use ratatui::widgets::Tabs;
+use chrono::Local;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph, Row, Table, TableState},
+    Frame,
+};
 
-use crate::app::AppState;
+use crate::app::{
+    favorite::Favorite,
+    handler::keybind::valid_bindings,
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::{
+        capacity::{format_cpu, format_memory},
+        cluster::{event_time, namespace_summaries, node_conditions, pod_status, ClusterInfo},
+    },
+    helper::age,
+};
 
-use super::util::{self, vertical_chunks, titled_block};
+const TOP_NAMESPACES: usize = 10;
+const HEALTHY_STATUSES: [&str; 4] = ["Ready", "Running", "Succeeded", "Completed"];
 
-pub(super) fn draw_main(f: &mut Frame, size: Rect, state: &mut AppState) {
-    let chunks = util::vertical_chunks(vec![Constraint::Length(3), Constraint::Min(1)], f.size());
-    draw_header(f, chunks[0], state.cur_tab as usize);
+// ------------------------------------------------
+// api server version / readyz
+// ------------------------------------------------
+// nodes     | pods by phase | pods by status    |
+// ------------------------------------------------
+// pinned    | top namespaces | recent warnings  |
+// ------------------------------------------------
+// help
+pub(super) fn draw_page_cluster(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Length(3),
+            Constraint::Length(10),
+            Constraint::Min(5),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+    let counters = uiutil::horizontal_chunks(
+        vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ],
+        chunks[1],
+    );
+    let bottom = uiutil::horizontal_chunks(
+        vec![
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+            Constraint::Percentage(40),
+        ],
+        chunks[2],
+    );
+
+    // written by the probe every few seconds, skip the frame when it is busy
+    if let Ok(info) = state.cluster_info.try_read() {
+        draw_api_server(f, &info, chunks[0]);
+        draw_warnings(f, &info, bottom[2]);
+    }
+
+    let nodes = node_conditions(state.node_storage.iter().map(|x| x.as_ref()));
+    draw_counter(f, "Nodes", &nodes, counters[0]);
+    let mut phases = BTreeMap::new();
+    let mut statuses = BTreeMap::new();
+    for pod in state.pod_storage.iter() {
+        let phase = pod.0.status.as_ref().and_then(|x| x.phase.clone());
+        *phases
+            .entry(phase.unwrap_or("Unknown".to_string()))
+            .or_insert(0) += 1;
+        *statuses.entry(pod_status(pod)).or_insert(0) += 1;
+    }
+    draw_counter(f, "Pods by phase", &phases, counters[1]);
+    draw_counter(f, "Pods by status", &statuses, counters[2]);
+    draw_pinned(f, state, bottom[0]);
+    draw_namespaces(f, state, bottom[1]);
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[3],
+    );
+}
+
+fn draw_api_server(f: &mut Frame, info: &ClusterInfo, area: Rect) {
+    let outer = uiutil::outer_block(f, "API server", area);
+    let Some(checked) = info.checked else {
+        f.render_widget(Paragraph::new("probing api server ..."), outer);
+        return;
+    };
+    let (health, color) = match info.health.as_ref() {
+        Some(Ok(body)) => (body.clone(), theme::DefaultTheme::GREEN_SPRING),
+        Some(Err(err)) => (err.clone(), theme::DefaultTheme::RED_PEACH),
+        None => ("unknown".to_string(), theme::DefaultTheme::YELLOW_CARP),
+    };
+    let line = Line::from(vec![
+        Span::raw(format!(
+            "version {}   readyz ",
+            info.version.as_deref().unwrap_or("<unknown>")
+        )),
+        Span::styled(health, Style::default().fg(color)),
+        Span::raw(format!(
+            "   checked {}s ago",
+            (Local::now() - checked).num_seconds().max(0)
+        )),
+    ]);
+    f.render_widget(Paragraph::new(line), outer);
+}
+
+// one line per key, statuses that need attention are red
+fn draw_counter(f: &mut Frame, title: &str, counts: &BTreeMap<String, usize>, area: Rect) {
+    let total = counts.values().sum::<usize>();
+    let outer = uiutil::outer_block(f, title, area);
+    let lines = counts
+        .iter()
+        .map(|(key, count)| {
+            let color = if HEALTHY_STATUSES.contains(&key.as_str()) {
+                theme::DefaultTheme::GREEN_SPRING
+            } else {
+                theme::DefaultTheme::RED_PEACH
+            };
+            Line::from(format!("{:<24}{:>6}", key, count)).style(Style::default().fg(color))
+        })
+        .chain(std::iter::once(
+            Line::from(format!("{:<24}{:>6}", "total", total)).style(Style::default().bold()),
+        ))
+        .collect::<Vec<Line>>();
+    f.render_widget(Paragraph::new(lines), outer);
 }
-fn draw_header(f: &mut Frame, area: Rect, tab_nr: usize) {
-    f.render_widget(util::titled_block("hehe"), area);
+
+// favorites with what they resolve to now, numbered like the keys that open them
+fn draw_pinned(f: &mut Frame, state: &AppState, area: Rect) {
+    let block = Block::default()
+        .title("Pinned [1-9]:open [f]:pin on pods/workloads")
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded);
+    if state.favorites.is_empty() {
+        f.render_widget(Paragraph::new("nothing pinned").block(block), area);
+        return;
+    }
+    let rows = state.favorites.iter().enumerate().map(|(index, favorite)| {
+        let (target, healthy) = pinned_status(state, favorite);
+        let color = if healthy {
+            theme::DefaultTheme::GREEN_SPRING
+        } else {
+            theme::DefaultTheme::RED_PEACH
+        };
+        Row::new(vec![(index + 1).to_string(), favorite.to_string(), target])
+            .style(Style::default().fg(color))
+    });
+    let widths = [
+        Constraint::Length(2),
+        Constraint::Percentage(55),
+        Constraint::Percentage(45),
+    ];
+    let table = Table::new(rows, widths).block(block).highlight_style(
+        Style::default()
+            .fg(theme::DefaultTheme::BLUE_LIGHT)
+            .bg(theme::DefaultTheme::SUMLINK1),
+    );
+    let mut table_state = TableState::default().with_selected(Some(state.favorites_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn pinned_status(state: &AppState, favorite: &Favorite) -> (String, bool) {
+    match favorite {
+        Favorite::Namespace { name } => {
+            let pods = state
+                .pod_storage
+                .iter()
+                .filter(|x| x.0.metadata.namespace.as_ref() == Some(name))
+                .count();
+            (format!("{} pods", pods), pods > 0)
+        }
+        Favorite::Deployment { namespace, name } => {
+            match state.deployment_storage.get(namespace, name) {
+                Some(deploy) => {
+                    let ready = deploy
+                        .0
+                        .status
+                        .as_ref()
+                        .and_then(|x| x.ready_replicas)
+                        .unwrap_or(0);
+                    let desired = deploy.0.spec.replicas.unwrap_or(1);
+                    (format!("{}/{} ready", ready, desired), ready >= desired)
+                }
+                None => ("<gone>".to_string(), false),
+            }
+        }
+        Favorite::Pod { .. } => match favorite.resolve_pod(state.pod_storage.iter()) {
+            Some(pod) => {
+                let status = pod_status(&pod);
+                let healthy = HEALTHY_STATUSES.contains(&status.as_str());
+                (
+                    format!(
+                        "{} {}",
+                        pod.0.metadata.name.as_deref().unwrap_or_default(),
+                        status
+                    ),
+                    healthy,
+                )
+            }
+            None => ("<no pod>".to_string(), false),
+        },
+    }
+}
+
+fn draw_namespaces(f: &mut Frame, state: &AppState, area: Rect) {
+    let outer = uiutil::outer_block(f, "Top namespaces", area);
+    let rows = namespace_summaries(
+        state.pod_storage.iter().map(|x| x.as_ref()),
+        &state.pod_usage,
+    )
+    .into_iter()
+    .take(TOP_NAMESPACES)
+    .map(|x| {
+        Row::new(vec![
+            x.namespace,
+            x.pods.to_string(),
+            format_cpu(x.cpu),
+            format_memory(x.memory),
+        ])
+    });
+    let widths = [
+        Constraint::Percentage(46),
+        Constraint::Percentage(14),
+        Constraint::Percentage(20),
+        Constraint::Percentage(20),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Namespace", "Pods", "CPU", "Memory"]).bold());
+    f.render_widget(table, outer);
+}
+
+fn draw_warnings(f: &mut Frame, info: &ClusterInfo, area: Rect) {
+    let outer = uiutil::outer_block(f, "Recent warning events", area);
+    if info.warnings.is_empty() {
+        f.render_widget(Paragraph::new("no warning events"), outer);
+        return;
+    }
+    let rows = info.warnings.iter().map(|event| {
+        let time = event_time(event).map(Time);
+        let object = &event.involved_object;
+        Row::new(vec![
+            age(time.as_ref()),
+            format!(
+                "{}/{}",
+                object.kind.as_deref().unwrap_or_default(),
+                object.name.as_deref().unwrap_or_default()
+            ),
+            event.reason.clone().unwrap_or_default(),
+            event.message.clone().unwrap_or_default().trim().to_string(),
+        ])
+        .style(Style::default().fg(theme::DefaultTheme::YELLOW_CARP))
+    });
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Percentage(30),
+        Constraint::Percentage(16),
+        Constraint::Percentage(54),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Age", "Object", "Reason", "Message"]).bold());
+    f.render_widget(table, outer);
 }
@@ -0,0 +1,199 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{
+        Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+        Wrap,
+    },
+    Frame,
+};
+
+use crate::app::{
+    handler::{config::selected_config, keybind::valid_bindings},
+    state::{AppState, Route},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::config::{hexdump, referencing_pods, text_value, ConfigKind, ConfigObject},
+    helper::age,
+};
+
+const MASK: &str = "********";
+
+// ----------------------------------------------
+// objects           | keys      | value        |
+// ----------------------------------------------
+// referenced by pods                           |
+// ----------------------------------------------
+// help
+pub(super) fn draw_page_config(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Percentage(65),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+    let top = uiutil::horizontal_chunks(
+        vec![
+            Constraint::Percentage(40),
+            Constraint::Percentage(20),
+            Constraint::Percentage(40),
+        ],
+        chunks[0],
+    );
+
+    draw_objects(f, state, top[0]);
+    let selected = selected_config(state);
+    draw_keys(f, state, top[1]);
+    draw_value(f, state, selected.as_deref(), top[2]);
+    draw_references(f, state, selected.as_deref(), chunks[1]);
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[2],
+    );
+}
+
+fn draw_objects(f: &mut Frame, state: &AppState, area: Rect) {
+    let namespace = state.namespace_cache.get().unwrap_or_default();
+    let rows = state
+        .configs_cache
+        .list()
+        .iter()
+        .filter_map(|item| {
+            let (kind, name) = item.split_once('/')?;
+            let storage = match kind {
+                "ConfigMap" => &state.configmap_storage,
+                _ => &state.secret_storage,
+            };
+            storage.get(namespace.as_ref(), name)
+        })
+        .map(|obj| {
+            let kind = match obj.0.spec.kind {
+                ConfigKind::ConfigMap => "ConfigMap",
+                ConfigKind::Secret => "Secret",
+            };
+            Row::new(vec![
+                kind.to_string(),
+                obj.0.metadata.name.clone().unwrap_or_default(),
+                obj.0.spec.type_.clone().unwrap_or_default(),
+                obj.0.spec.entries.len().to_string(),
+                age(obj.0.metadata.creation_timestamp.as_ref()),
+            ])
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(14),
+        Constraint::Percentage(38),
+        Constraint::Percentage(30),
+        Constraint::Percentage(8),
+        Constraint::Percentage(10),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Kind", "Name", "Type", "Keys", "Age"]).bold())
+        .block(
+            Block::default()
+                .title(format!("Config of {} [n]:next namespace", namespace))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.configs_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_keys(f: &mut Frame, state: &AppState, area: Rect) {
+    let items = state
+        .config_keys_cache
+        .list()
+        .iter()
+        .map(|key| ListItem::new(key.as_ref()))
+        .collect::<Vec<ListItem>>();
+    let focused = matches!(state.get_route(), Route::ConfigKeys);
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title("Keys")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(if focused {
+                    theme::DefaultTheme::BLUE_SPRING
+                } else {
+                    theme::DefaultTheme::VIOLET_SPRING1
+                })
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut list_state = ListState::default().with_selected(Some(state.config_keys_cache.index()));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn draw_value(f: &mut Frame, state: &AppState, obj: Option<&ConfigObject>, area: Rect) {
+    let key = state.config_keys_cache.get();
+    let value = obj.zip(key.as_ref()).and_then(|(obj, key)| {
+        obj.0
+            .spec
+            .entries
+            .get(key.as_ref())
+            .map(|value| (obj, value))
+    });
+    let Some((obj, value)) = value else {
+        f.render_widget(uiutil::debug_widget(""), area);
+        return;
+    };
+    let key = key.unwrap_or_default();
+
+    let masked =
+        obj.0.spec.kind == ConfigKind::Secret && !state.config_revealed.contains(key.as_ref());
+    let (lines, format) = if masked {
+        (vec![Line::from(MASK)], "masked [v]:reveal")
+    } else {
+        match text_value(value) {
+            Some(text) => (text.lines().map(Line::from).collect(), "text"),
+            None => (
+                hexdump(value).into_iter().map(Line::from).collect(),
+                "binary",
+            ),
+        }
+    };
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(format!("{} ({} bytes, {})", key, value.len(), format))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+fn draw_references(f: &mut Frame, state: &AppState, obj: Option<&ConfigObject>, area: Rect) {
+    let Some(obj) = obj else {
+        f.render_widget(uiutil::debug_widget("Empty"), area);
+        return;
+    };
+    let references = referencing_pods(obj, state.pod_storage.iter());
+    let lines = if references.is_empty() {
+        vec![Line::from("not referenced by any pod")]
+    } else {
+        references
+            .iter()
+            .map(|(pod, usage)| Line::from(format!("{:<56}{}", pod, usage)))
+            .collect()
+    };
+    let outer = uiutil::outer_block(f, "Referenced by", area);
+    f.render_widget(Paragraph::new(lines), outer);
+}
@@ -0,0 +1,60 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+use tracing::Level;
+
+use crate::{
+    app::{
+        state::AppState,
+        ui::{
+            theme::{self, Kanagawa},
+            util::centered_rect,
+        },
+    },
+    logging::{self, Record},
+};
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::ERROR => theme::DefaultTheme::RED_PEACH,
+        Level::WARN => theme::DefaultTheme::YELLOW_CARP,
+        Level::INFO => theme::DefaultTheme::GREEN_SPRING,
+        _ => theme::DefaultTheme::VIOLET_SPRING1,
+    }
+}
+
+// the in-memory tail of the log file, newest first
+pub(super) fn draw_debug(f: &mut Frame, state: &AppState, area: Rect) {
+    let area = centered_rect(85, 70, area);
+    let records = logging::recent();
+    let lines = records.iter().map(record_line).collect::<Vec<Line>>();
+    let debug = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title("Debug log [j/k]:scroll [c]:clear [`]:close")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .scroll((state.overlay_scroll, 0));
+    f.render_widget(Clear, area);
+    f.render_widget(debug, area);
+}
+
+fn record_line(record: &Record) -> Line<'_> {
+    Line::from(vec![
+        Span::raw(format!("{} ", record.time.format("%H:%M:%S"))),
+        Span::styled(
+            format!("{:<6}", record.level.to_string()),
+            Style::default().fg(level_color(record.level)),
+        ),
+        Span::styled(
+            format!("{} ", record.target),
+            Style::default().fg(theme::DefaultTheme::BLUE_SPRING),
+        ),
+        Span::raw(record.message.as_str()),
+    ])
+}
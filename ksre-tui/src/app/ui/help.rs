@@ -0,0 +1,61 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{
+    handler::keybind::valid_bindings,
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::centered_rect,
+    },
+};
+
+pub(super) fn draw_help(f: &mut Frame, state: &AppState, area: Rect) {
+    let area = centered_rect(60, 60, area);
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+
+    let mut lines = vec![
+        Line::styled(
+            format!("{:<12}{}", "Key", "Action"),
+            Style::default().bold(),
+        ),
+        Line::from(""),
+    ];
+    for binding in bindings {
+        let key = format!("{:<12}", format!("[{}]", binding.key.to_lowercase()));
+        // the missing permission says what to ask the cluster admin for
+        let line = match binding.missing_permission(state) {
+            Some(permission) => Line::from(vec![
+                Span::raw(key),
+                Span::raw(format!("{} (forbidden: {})", binding.desc, permission)),
+            ])
+            .style(Style::default().fg(Color::DarkGray)),
+            None => Line::from(vec![
+                Span::styled(key, Style::default().fg(theme::DefaultTheme::ORANGE_SURIMI)),
+                Span::raw(binding.desc),
+            ]),
+        };
+        lines.push(line);
+    }
+
+    let title = format!(
+        "Help - {:?} / {:?} [j/k]:scroll [?]:close",
+        state.get_tabpage(),
+        state.get_route()
+    );
+    let help = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .scroll((state.overlay_scroll, 0));
+    f.render_widget(Clear, area);
+    f.render_widget(help, area);
+}
@@ -1,14 +1,30 @@
 use std::usize;
 
-use ratatui::layout::{Constraint, Rect};
+use ratatui::layout::{Alignment, Constraint, Rect};
 
+use ratatui::widgets::{block::Title, Paragraph};
 use ratatui::Frame;
 use tui_textarea::TextArea;
 
-use super::{pod, util as uiutil};
-use crate::app::state::{AppState, TabPage};
+use super::{
+    alert, autoscale, batch, cluster, config, debug, help, jobs, node, pod, resource, search,
+    service, storage, util as uiutil, workload, yank,
+};
+use crate::app::state::{AppState, Overlay, TabPage};
 
 const HEAD_TITLE: &'_ str = "ksre - ksre tools";
+const TAB_TITLES: [&str; 10] = [
+    "[ cluster ]",
+    "[ pods ]",
+    "[ workloads ]",
+    "[ nodes ]",
+    "[ services ]",
+    "[ config ]",
+    "[ batch ]",
+    "[ storage ]",
+    "[ hpa ]",
+    "[ resources ]",
+];
 
 pub enum View {
     Pod,
@@ -22,26 +38,69 @@ pub fn ui_main(
     state: &mut AppState,
     reader: tokio::sync::RwLockReadGuard<TextArea>,
 ) {
+    state.ui_layout.reset_areas();
     let chunks = uiutil::vertical_chunks(vec![Constraint::Length(3), Constraint::Min(1)], f.size());
     // header  pods  nodes
-    draw_header(f, chunks[0], state.get_tabpage() as usize);
+    draw_header(f, chunks[0], state);
 
-    // pod index is default home page
+    // cluster overview is the landing page
     match state.get_tabpage() {
+        TabPage::Cluster => cluster::draw_page_cluster(f, state, chunks[1]),
         TabPage::Pod => pod::draw_page_index(f, state, chunks[1], reader),
-        TabPage::Deploy => todo_fn(),
-        TabPage::Node => todo_fn(),
+        TabPage::Deploy => workload::draw_page_workload(f, state, chunks[1]),
+        TabPage::Node => node::draw_page_node(f, state, chunks[1]),
+        TabPage::Service => service::draw_page_service(f, state, chunks[1]),
+        TabPage::Config => config::draw_page_config(f, state, chunks[1]),
+        TabPage::Batch => batch::draw_page_batch(f, state, chunks[1]),
+        TabPage::Storage => storage::draw_page_storage(f, state, chunks[1]),
+        TabPage::Autoscale => autoscale::draw_page_autoscale(f, state, chunks[1]),
+        TabPage::Resource => resource::draw_page_resource(f, state, chunks[1]),
+    }
+
+    alert::draw_toasts(f, state, chunks[1]);
+
+    match state.overlay {
+        Some(Overlay::Help) => help::draw_help(f, state, f.size()),
+        Some(Overlay::Alerts) => alert::draw_alert_history(f, state, f.size()),
+        Some(Overlay::Jobs) => jobs::draw_jobs(f, state, f.size()),
+        Some(Overlay::Debug) => debug::draw_debug(f, state, f.size()),
+        Some(Overlay::Yank) => yank::draw_yank(f, state, f.size()),
+        Some(Overlay::Search) => search::draw_search(f, state, f.size()),
+        None => {}
     }
 }
-fn todo_fn() {}
 
-fn draw_header(f: &mut Frame, area: Rect, tab_nr: usize) {
-    f.render_widget(uiutil::titled_block(HEAD_TITLE), area);
+fn draw_header(f: &mut Frame, area: Rect, state: &mut AppState) {
+    // the context decides which cluster every key acts on, so it is always in sight
+    let context = Title::from(format!(" {} ", state.context)).alignment(Alignment::Right);
+    f.render_widget(uiutil::titled_block(HEAD_TITLE).title(context), area);
 
-    let tabs = uiutil::selected_tab(vec!["[ pods ]", "[ deployment ]", "[ nodes ]"], tab_nr);
+    let tabs = uiutil::selected_tab(TAB_TITLES.to_vec(), state.get_tabpage() as usize);
 
     f.render_widget(tabs, area);
 
+    // tabs are drawn inside the border, each title has one char left padding and one char divider
+    let mut x = area.x + 1;
+    for (title, tabpage) in TAB_TITLES.iter().zip(TabPage::ALL) {
+        let width = title.chars().count() as u16 + 1;
+        state
+            .ui_layout
+            .tabs
+            .push((Rect::new(x, area.y + 1, width, 1), tabpage));
+        x += width + 1;
+    }
+
+    // result of the last background action, e.g. cordon
+    if let Ok(message) = state.status_message.try_read() {
+        if !message.is_empty() {
+            let inner = Rect::new(area.x + 1, area.y + 1, area.width.saturating_sub(2), 1);
+            f.render_widget(
+                Paragraph::new(message.as_str()).alignment(Alignment::Right),
+                inner,
+            );
+        }
+    }
+
     /* let chunks =
         util::horizontal_margined_chunks(vec![Constraint::Length(75), Constraint::Min(0)], area, 1);
     let tabs = Tabs::new(vec!["Workload <tab|0>", "Cluster <tab|1>", "Node <tab|2>"])
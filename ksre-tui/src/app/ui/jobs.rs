@@ -0,0 +1,59 @@
+use chrono::Local;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    widgets::{Block, BorderType, Borders, Clear, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::{
+    job::JobState,
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::centered_rect,
+    },
+};
+
+fn state_color(state: &JobState) -> Color {
+    match state {
+        JobState::Starting | JobState::Running => theme::DefaultTheme::GREEN_SPRING,
+        JobState::Stopping => theme::DefaultTheme::YELLOW_CARP,
+        JobState::Terminated => theme::DefaultTheme::VIOLET_SPRING1,
+        JobState::Failed(_) => theme::DefaultTheme::RED_PEACH,
+    }
+}
+
+pub(super) fn draw_jobs(f: &mut Frame, state: &AppState, area: Rect) {
+    let area = centered_rect(80, 60, area);
+    let rows = state.jobs.list().iter().map(|job| {
+        let job_state = job.state();
+        Row::new(vec![
+            job.id.to_string(),
+            job.kind.to_string(),
+            job.target.clone(),
+            format!("{}s", (Local::now() - job.started).num_seconds().max(0)),
+            job_state.to_string(),
+        ])
+        .style(Style::default().fg(state_color(&job_state)))
+    });
+    let widths = [
+        Constraint::Length(4),
+        Constraint::Length(10),
+        Constraint::Percentage(35),
+        Constraint::Length(8),
+        Constraint::Percentage(50),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Id", "Kind", "Target", "Age", "State"]).bold())
+        .block(
+            Block::default()
+                .title("Jobs [j/k]:select [x]:kill [c]:clear finished [&]:close")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(Style::default().bg(theme::DefaultTheme::SUMLINK1).bold());
+    let mut table_state = TableState::default().with_selected(Some(state.jobs.selected()));
+    f.render_widget(Clear, area);
+    f.render_stateful_widget(table, area, &mut table_state);
+}
@@ -1,4 +1,19 @@
+mod alert;
+mod autoscale;
+mod batch;
+mod cluster;
+mod config;
+mod debug;
+mod help;
 pub(super) mod home;
+mod jobs;
+mod node;
 mod pod;
+mod resource;
+mod search;
+mod service;
+mod storage;
 mod theme;
 mod util;
+mod workload;
+mod yank;
@@ -1,11 +1,227 @@
-use ratatui::Frame;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Row, Table, TableState, Wrap},
+    Frame,
+};
 
-use ratatui::layout::{Layout, Rect};
+use crate::app::{
+    job::node_drain::{DrainProgress, DrainState, EvictionState},
+    state::{AppState, Route},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil, centered_rect, debug_widget},
+    },
+};
+use crate::kubernetes::{
+    api::node::{node_details, node_roles, node_status, node_version},
+    helper::age,
+};
 
-use crate::app::AppState;
+// -------------------------------------
+// nodes              | node detail     |
+// ------------------------------------|
+// pods on node / drain progress
+pub(super) fn draw_page_node(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        area,
+    );
+    let top_area = uiutil::horizontal_chunks(
+        vec![Constraint::Percentage(60), Constraint::Percentage(40)],
+        chunks[0],
+    );
 
-use super::util::{titled_block, self};
+    draw_node_list(f, state, top_area[0]);
+    draw_node_detail(f, state, top_area[1]);
 
-pub(super) fn draw_main(f: &mut Frame, area: Rect, state: &mut AppState) {
-    f.render_widget(util::titled_block("nihao"), area);
+    let drain_progress = state.drain_progress.clone();
+    match drain_progress.as_ref().map(|x| x.try_read()) {
+        Some(Ok(progress)) => draw_drain_progress(f, &progress, chunks[1]),
+        // progress is being written, skip this frame
+        Some(Err(_)) => {}
+        None => draw_node_pods(f, state, chunks[1]),
+    }
+
+    if let Route::NodeDrainConfirm = state.get_route() {
+        draw_drain_confirm(f, state, area);
+    }
+}
+
+fn draw_node_list(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let header = Row::new(vec!["Name", "Status", "Roles", "Version", "Pods", "Age"]).bold();
+    let rows = state
+        .nodes_cache
+        .list()
+        .iter()
+        .filter_map(|name| state.node_storage.get("", name))
+        .map(|node| {
+            let name = node.0.metadata.name.as_deref().unwrap_or_default();
+            let pods = state
+                .pod_storage
+                .iter()
+                .filter(|pod| pod.0.spec.node_name.as_deref() == Some(name))
+                .count();
+            Row::new(vec![
+                name.to_string(),
+                node_status(&node),
+                node_roles(&node),
+                node_version(&node).to_string(),
+                pods.to_string(),
+                age(node.0.metadata.creation_timestamp.as_ref()),
+            ])
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(30),
+        Constraint::Percentage(25),
+        Constraint::Percentage(15),
+        Constraint::Percentage(12),
+        Constraint::Percentage(8),
+        Constraint::Percentage(10),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title("Nodes")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.nodes_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+    state.ui_layout.node_list = area;
+    state.ui_layout.node_list_offset = table_state.offset();
+}
+
+fn draw_node_detail(f: &mut Frame, state: &AppState, area: Rect) {
+    let outer = uiutil::outer_block(f, "Node Detail", area);
+    let node = state
+        .nodes_cache
+        .get()
+        .and_then(|name| state.node_storage.get("", name.as_ref()));
+    match node {
+        Some(node) => f.render_widget(Paragraph::new(node_details(&node).join("\n")), outer),
+        None => f.render_widget(Paragraph::new("Empty"), outer),
+    }
+}
+
+fn draw_node_pods(f: &mut Frame, state: &AppState, area: Rect) {
+    let node_name = state.nodes_cache.get();
+    let rows = state
+        .pod_storage
+        .iter()
+        .filter(|pod| pod.0.spec.node_name.as_deref() == node_name.as_deref())
+        .map(|pod| {
+            Row::new(vec![
+                pod.0.metadata.namespace.clone().unwrap_or_default(),
+                pod.0.metadata.name.clone().unwrap_or_default(),
+                pod.0
+                    .status
+                    .as_ref()
+                    .and_then(|status| status.phase.clone())
+                    .unwrap_or_default(),
+                age(pod.0.metadata.creation_timestamp.as_ref()),
+            ])
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(25),
+        Constraint::Percentage(45),
+        Constraint::Percentage(15),
+        Constraint::Percentage(15),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Namespace", "Pod", "Status", "Age"]).bold())
+        .block(
+            Block::default()
+                .title(format!("Pods on {}", node_name.as_deref().unwrap_or("-")))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        );
+    f.render_widget(table, area);
+}
+
+fn draw_drain_progress(f: &mut Frame, progress: &DrainProgress, area: Rect) {
+    let (evicted, total) = progress.counts();
+    let state = match &progress.state {
+        DrainState::Cordoning => "Cordoning".to_string(),
+        DrainState::Evicting => "Evicting".to_string(),
+        DrainState::Done => "Done".to_string(),
+        DrainState::Cancelled => "Cancelled".to_string(),
+        DrainState::TimedOut => "TimedOut".to_string(),
+        DrainState::Failed(err) => format!("Failed: {}", err),
+    };
+    let title = format!(
+        "Drain {} - {} {}/{} evicted, {}s [x]:cancel [esc]:close",
+        progress.node,
+        state,
+        evicted,
+        total,
+        progress.started.elapsed().as_secs()
+    );
+
+    let rows = progress
+        .evictions
+        .iter()
+        .map(|eviction| {
+            let (state, message, color) = match &eviction.state {
+                EvictionState::Pending => ("Pending", "", theme::DefaultTheme::VIOLET_SPRING),
+                EvictionState::Evicting => ("Evicting", "", theme::DefaultTheme::BLUE_SPRING),
+                EvictionState::Evicted => ("Evicted", "", theme::DefaultTheme::GREEN_SPRING),
+                EvictionState::Blocked(msg) => {
+                    ("Blocked", msg.as_str(), theme::DefaultTheme::YELLOW_CARP)
+                }
+                EvictionState::Skipped(reason) => {
+                    ("Skipped", *reason, theme::DefaultTheme::VIOLET_SPRING1)
+                }
+                EvictionState::Failed(msg) => {
+                    ("Failed", msg.as_str(), theme::DefaultTheme::RED_PEACH)
+                }
+            };
+            Row::new(vec![
+                eviction.namespace.clone(),
+                eviction.name.clone(),
+                state.to_string(),
+                eviction.attempts.to_string(),
+                message.to_string(),
+            ])
+            .style(Style::default().fg(color))
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(15),
+        Constraint::Percentage(30),
+        Constraint::Percentage(10),
+        Constraint::Percentage(8),
+        Constraint::Percentage(37),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Namespace", "Pod", "State", "Attempts", "Message"]).bold())
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        );
+    f.render_widget(table, area);
+}
+
+fn draw_drain_confirm(f: &mut Frame, state: &AppState, area: Rect) {
+    let area = centered_rect(50, 30, area);
+    let node_name = state.nodes_cache.get().unwrap_or_default();
+    let message = format!(
+        "Drain node {}?\n\nThe node is cordoned, then every pod is evicted respecting PodDisruptionBudgets. DaemonSet and mirror pods are skipped.\n\n[y]:confirm [esc]:cancel",
+        node_name
+    );
+    f.render_widget(Clear, area);
+    f.render_widget(
+        debug_widget(message.as_str()).wrap(Wrap { trim: false }),
+        area,
+    );
 }
@@ -0,0 +1,92 @@
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::{
+    state::{AppState, DiffView},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil, debug_widget},
+    },
+};
+use crate::kubernetes::api::diff::{diff_objects, Change};
+
+pub fn draw_pod_diff(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(view) = state.diff_view.as_ref() else {
+        f.render_widget(debug_widget("nothing to compare"), area);
+        return;
+    };
+    let (title, old, new) = match view {
+        DiffView::Pods((old_ns, old_name), (new_ns, new_name)) => (
+            format!("{}/{} -> {}/{}", old_ns, old_name, new_ns, new_name),
+            state.pod_storage.get(old_ns, old_name),
+            state.pod_storage.get(new_ns, new_name),
+        ),
+        DiffView::Previous(namespace, name) => (
+            format!("{}/{} before -> after its last update", namespace, name),
+            state.pod_previous.get(namespace, name).cloned(),
+            state.pod_storage.get(namespace, name),
+        ),
+    };
+    let area = uiutil::outer_block(
+        f,
+        format!("{} [j/k]:scroll [esc]:back", title).as_str(),
+        area,
+    );
+    let (Some(old), Some(new)) = (old, new) else {
+        let message = match view {
+            DiffView::Previous(..) => "no update seen since ksre started",
+            DiffView::Pods(..) => "pod is gone",
+        };
+        f.render_widget(debug_widget(message), area);
+        return;
+    };
+    let changes = diff_objects(&old.0, &new.0);
+    if changes.is_empty() {
+        f.render_widget(debug_widget("no difference"), area);
+        return;
+    }
+    let lines = changes.iter().map(change_line).collect::<Vec<Line>>();
+    f.render_widget(
+        Paragraph::new(lines).scroll((state.describe_scroll, 0)),
+        area,
+    );
+}
+
+fn change_line(change: &Change) -> Line<'_> {
+    match change {
+        Change::Added { path, value } => Line::from(vec![
+            Span::styled("+ ", Style::default().fg(theme::DefaultTheme::GREEN_SPRING)),
+            Span::raw(format!("{}: ", path)),
+            Span::styled(
+                value.as_str(),
+                Style::default().fg(theme::DefaultTheme::GREEN_SPRING),
+            ),
+        ]),
+        Change::Removed { path, value } => Line::from(vec![
+            Span::styled("- ", Style::default().fg(theme::DefaultTheme::RED_PEACH)),
+            Span::raw(format!("{}: ", path)),
+            Span::styled(
+                value.as_str(),
+                Style::default().fg(theme::DefaultTheme::RED_PEACH),
+            ),
+        ]),
+        Change::Modified { path, old, new } => Line::from(vec![
+            Span::styled("~ ", Style::default().fg(theme::DefaultTheme::YELLOW_CARP)),
+            Span::raw(format!("{}: ", path)),
+            Span::styled(
+                old.as_str(),
+                Style::default().fg(theme::DefaultTheme::RED_PEACH),
+            ),
+            Span::raw(" -> "),
+            Span::styled(
+                new.as_str(),
+                Style::default().fg(theme::DefaultTheme::GREEN_SPRING),
+            ),
+        ]),
+    }
+}
@@ -0,0 +1,71 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Style,
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::app::{
+    job::pod_cp::RemoteDir,
+    state::{AppState, Route},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil, vertical_chunks},
+    },
+};
+
+// entries of the remote directory, the upload prompt takes a local path below them
+pub fn draw_pod_files(f: &mut Frame, state: &AppState, area: Rect) {
+    let remote_dir = state.remote_dir.clone();
+    let Some(Ok(dir)) = remote_dir.as_ref().map(|x| x.try_read()) else {
+        // being listed, skip this frame
+        return;
+    };
+    let title = format!(
+        "{}:{} [enter]:open [g]:download [u]:upload [esc]:back",
+        dir.target.container, dir.path
+    );
+    let outer = uiutil::outer_block(f, &title, area);
+    if let Route::PodUpload = state.get_route() {
+        let chunks = vertical_chunks(vec![Constraint::Min(1), Constraint::Length(3)], outer);
+        draw_entries(f, &dir, chunks[0]);
+        f.render_widget(
+            uiutil::user_input(state.user_input.as_str(), state.get_mode()).block(
+                Block::default()
+                    .title(format!(
+                        "upload local file to {}, [enter]:start [esc]:cancel",
+                        dir.path
+                    ))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded),
+            ),
+            chunks[1],
+        );
+        return;
+    }
+    draw_entries(f, &dir, outer);
+}
+
+fn draw_entries(f: &mut Frame, dir: &RemoteDir, area: Rect) {
+    let message = match (dir.loading, dir.error.as_ref()) {
+        (true, _) => Some("listing...".to_string()),
+        (false, Some(err)) => Some(format!("can not list {}: {}", dir.path, err)),
+        _ => None,
+    };
+    if let Some(message) = message {
+        f.render_widget(Paragraph::new(message).wrap(Wrap { trim: true }), area);
+        return;
+    }
+    let items = dir
+        .entries
+        .iter()
+        .map(|x| ListItem::new(x.as_str()))
+        .collect::<Vec<ListItem>>();
+    let list = List::new(items).highlight_style(
+        Style::default()
+            .fg(theme::DefaultTheme::BLUE_SPRING)
+            .bg(theme::DefaultTheme::SUMLINK1),
+    );
+    let mut list_state = ListState::default().with_selected(Some(dir.selected));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
@@ -7,6 +7,7 @@ use ratatui::{
 };
 use tui_textarea::TextArea;
 
+use crate::app::handler::keybind::valid_bindings;
 use crate::app::state::Route;
 use crate::app::{
     state::AppState,
@@ -17,8 +18,13 @@ use crate::app::{
 };
 
 use super::{
-    list::draw_page_pod_list, logs::draw_pod_logs, resource::draw_pod_resource,
+    diff::draw_pod_diff,
+    files::draw_pod_files,
+    list::draw_page_pod_list,
+    logs::draw_pod_logs,
+    resource::{draw_namespace_capacity, draw_pod_resource},
     status::draw_page_pod_status,
+    tty::{draw_debug_targets, draw_page_pod_tty},
 };
 
 // -------------------------------------
@@ -35,16 +41,27 @@ pub fn draw_page_index(
     area: Rect,
     reader: tokio::sync::RwLockReadGuard<TextArea>,
 ) {
+    let split_vertical = state.ui_layout.split_vertical;
+    let split_horizontal = state.ui_layout.split_horizontal;
     let chunks = uiutil::vertical_chunks(
-        vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        vec![
+            Constraint::Percentage(split_vertical),
+            Constraint::Percentage(100 - split_vertical),
+        ],
         area,
     );
 
     // pod_chunks[0] for pod list, pod_chunks【1】 is used to render pod status
     let main_area = uiutil::horizontal_chunks(
-        vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        vec![
+            Constraint::Percentage(split_horizontal),
+            Constraint::Percentage(100 - split_horizontal),
+        ],
         chunks[0],
     );
+    state.ui_layout.page_body = area;
+    state.ui_layout.divider_row = chunks[1].y;
+    state.ui_layout.divider_col = main_area[1].x;
 
     let pod_list_area = uiutil::outer_block(f, "Pods", main_area[0]);
     let pod_res_area = uiutil::outer_block(f, "Pod Resource", main_area[1]);
@@ -52,6 +69,7 @@ pub fn draw_page_index(
     let bottom_area = vertical_chunks(vec![Constraint::Length(3), Constraint::Min(2)], chunks[1]);
     let bottom_head = bottom_area[0];
     let bottom_body = bottom_area[1];
+    state.ui_layout.bottom_body = bottom_body;
 
     // pod_chunks[0] 展示pod list, ,pod_chunks[1] 展示 pod status
     //
@@ -68,7 +86,11 @@ pub fn draw_page_index(
 
         match state.get_route() {
             Route::PodLog => draw_pod_logs(f, state, pod_describe, bottom_body, reader),
-            Route::PodTerm => {}
+            Route::PodTerm => draw_page_pod_tty(f, bottom_body, state, reader),
+            Route::PodDebug => draw_debug_targets(f, bottom_body, state),
+            Route::PodCapacity => draw_namespace_capacity(f, state, bottom_body),
+            Route::PodDiff => draw_pod_diff(f, state, bottom_body),
+            Route::PodFiles | Route::PodUpload => draw_pod_files(f, state, bottom_body),
             _ => draw_page_pod_status(f, state, pod_describe, bottom_body),
         }
         return;
@@ -76,7 +98,11 @@ pub fn draw_page_index(
     draw_pod_resource(f, state, None, pod_res_area);
     match state.get_route() {
         Route::PodLog => draw_pod_logs(f, state, None, bottom_body, reader),
-        Route::PodTerm => {}
+        Route::PodTerm => draw_page_pod_tty(f, bottom_body, state, reader),
+        Route::PodDebug => draw_debug_targets(f, bottom_body, state),
+        Route::PodCapacity => draw_namespace_capacity(f, state, bottom_body),
+        Route::PodDiff => draw_pod_diff(f, state, bottom_body),
+        Route::PodFiles | Route::PodUpload => draw_pod_files(f, state, bottom_body),
         _ => draw_page_pod_status(f, state, None, bottom_body),
     }
 }
@@ -89,10 +115,13 @@ fn draw_bottom_head(f: &mut Frame, state: &AppState, area: Rect) {
     );
     let id_selected = match state.get_route() {
         Route::PodLog => 1,
-        Route::PodTerm => 2,
+        Route::PodTerm | Route::PodDebug => 2,
+        Route::PodCapacity => 3,
+        Route::PodDiff => 4,
+        Route::PodFiles | Route::PodUpload => 5,
         _ => 0,
     };
-    let colored_items = vec!["Describe", "Log", "Terminal"]
+    let colored_items = ["Describe", "Log", "Terminal", "Capacity", "Diff", "Files"]
         .iter()
         .map(|&x| x.to_string().bg(theme::DefaultTheme::SUMLINK1).into())
         .collect::<Vec<Line>>();
@@ -110,7 +139,6 @@ fn draw_bottom_head(f: &mut Frame, state: &AppState, area: Rect) {
 
     f.render_widget(tabs, area[0]);
 
-    let help_message =
-        r#"help: [l]:show pods log, [t]:attach pod, [esc] exit then reback to descibe"#;
-    f.render_widget(Paragraph::new(help_message), area[1]);
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(Paragraph::new(uiutil::hint_line(state, &bindings)), area[1]);
 }
@@ -5,6 +5,7 @@ use ratatui::{
     Frame,
 };
 
+use crate::app::handler::keybind::POD_KEYMAPS;
 use crate::app::state::Route;
 use crate::app::{
     state::{AppState, Mode},
@@ -34,6 +35,7 @@ pub fn draw_page_pod_list(f: &mut Frame, area: Rect, state: &mut AppState) {
     draw_namespaces(f, ns_select_area, state);
 
     draw_pods(f, pod_list_area, state);
+    state.ui_layout.pod_list = pod_list_area;
     /* let input = state.input_char.to_string(); */
 
     /* let listitems = uiutil::selectable_list(&state.cache_items); */
@@ -48,34 +50,55 @@ fn draw_user_input(f: &mut Frame, area: Rect, state: &AppState) {
     f.render_widget(input_widget, area);
 }
 
-fn draw_namespaces(f: &mut Frame, area: Rect, state: &AppState) {
+fn draw_namespaces(f: &mut Frame, area: Rect, state: &mut AppState) {
     let area = horizontal_chunks(
         vec![Constraint::Percentage(70), Constraint::Percentage(30)],
         area,
     );
+    state.ui_layout.namespace_list = area[0];
 
     let list = uiutil::selectable_list_1(&state.namespace_cache);
 
-    let help_message = r#"[n]     trigger
-[k]     up 
-[j]     down
-[enter] comfirm
-[esc]   quit
-"#;
+    let help_message = POD_KEYMAPS
+        .valid_bindings(Route::PodNamespace)
+        .map(|binding| {
+            format!(
+                "{:<8}{}",
+                format!("[{}]", binding.key.to_lowercase()),
+                binding.desc
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
 
     f.render_widget(list, area[0]);
-    f.render_widget(debug_widget(help_message), area[1]);
+    f.render_widget(debug_widget(help_message.as_str()), area[1]);
 }
 
-fn draw_pods(f: &mut Frame, area: Rect, state: &AppState) {
-    let list = pod_select_items(state);
+fn draw_pods(f: &mut Frame, area: Rect, state: &mut AppState) {
+    // borders and the header line
+    let height = area.height.saturating_sub(3) as usize;
+    let offset = uiutil::scroll_offset(
+        state.cache_items.index(),
+        state.ui_layout.pod_list_offset,
+        height,
+    );
+    state.ui_layout.pod_list_offset = offset;
+    let list = pod_select_items(state, offset, height);
     f.render_widget(list, area);
 }
 
-fn pod_select_items(app_state: &AppState) -> List<'_> {
+// a namespace may hold thousands of pods, only the visible ones are formatted
+fn pod_select_items(app_state: &AppState, offset: usize, height: usize) -> List<'_> {
     let mut list_items = Vec::new();
     let namespace = app_state.namespace_cache.get().unwrap();
-    let items = app_state.cache_items.list().iter();
+    let items = app_state
+        .cache_items
+        .list()
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(height);
     let title = format!(
         "{:<48}{:<16}{:<16}",
         "Pod".to_string(),
@@ -83,18 +106,16 @@ fn pod_select_items(app_state: &AppState) -> List<'_> {
         "Ready".to_string()
     );
     list_items.push(ListItem::new(title).style(Style::default()));
-    for (idx, val) in items.enumerate() {
+    for (idx, val) in items {
         let pod_desc = app_state.pod_describes.get(namespace.as_ref(), val);
         let item_txt: String;
         if let Some(describe) = pod_desc {
-            unsafe {
-                item_txt = format!(
-                    "{:<48}{:<16}{:<16}",
-                    val,
-                    &(*describe.status),
-                    format!("{}/{}", describe.ready_number, describe.containers.len())
-                );
-            }
+            item_txt = format!(
+                "{:<48}{:<16}{:<16}",
+                val,
+                describe.status,
+                format!("{}/{}", describe.ready_number, describe.containers.len())
+            );
         } else {
             item_txt = format!(
                 "{:<48}{:<16}{:<16}",
@@ -1,6 +1,7 @@
 use ratatui::Frame;
 
 use ratatui::layout::Rect;
+use ratatui::widgets::Paragraph;
 use tui_textarea::TextArea;
 
 use crate::app::{
@@ -17,15 +18,21 @@ pub fn draw_pod_logs(
     reader: tokio::sync::RwLockReadGuard<TextArea>,
 ) {
     let namespace = state.namespace_cache.get().unwrap();
-    if let Some(pod_name) = state.cache_items.get() {
-        let outer = uituil::outer_block(
+    let outer = if let Some(pod_name) = state.cache_items.get() {
+        uituil::outer_block(
             f,
             format!("show {}:{} log [esc]:quit", namespace, pod_name).as_str(),
             area,
-        );
-        f.render_widget(reader.widget(), outer);
+        )
     } else {
-        let outer = uituil::outer_block(f, "Log [esc to quit]", area);
-        f.render_widget(reader.widget(), outer);
+        uituil::outer_block(f, "Log [esc to quit]", area)
+    };
+    match state.log_scroll {
+        // scrolled back by mouse wheel, render a frozen window of the buffer
+        Some(offset) => {
+            let lines = reader.lines().join("\n");
+            f.render_widget(Paragraph::new(lines).scroll((offset, 0)), outer);
+        }
+        None => f.render_widget(reader.widget(), outer),
     }
 }
@@ -1,3 +1,5 @@
+pub(super) mod diff;
+pub(super) mod files;
 pub(super) mod index;
 pub(super) mod list;
 pub(super) mod logs;
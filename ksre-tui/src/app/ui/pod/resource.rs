@@ -1,7 +1,22 @@
-use ratatui::{layout::Rect, Frame};
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Paragraph, Row, Table},
+    Frame,
+};
 
-use crate::app::state::AppState;
-use crate::kubernetes::api::pod::PodDescribe;
+use crate::app::{
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::debug_widget,
+    },
+};
+use crate::kubernetes::api::{
+    capacity::{container_usage, format_cpu, format_memory, rightsizing, Sizing, Utilization},
+    pod::PodDescribe,
+};
 
 pub fn draw_pod_resource(
     f: &mut Frame,
@@ -13,15 +28,133 @@ pub fn draw_pod_resource(
         return;
     }
     let pod_describe = pod_describe.unwrap();
-    let namespace: &str;
-    let pod_name: &str;
-    unsafe {
-        namespace = &(*pod_describe.namespace);
-        pod_name = &(*pod_describe.name);
-    }
+    let namespace = pod_describe.namespace.as_str();
+    let pod_name = pod_describe.name.as_str();
+
+    let pod = state.pod_storage.get(namespace, pod_name);
+    let metrics = state.pod_usage.get(namespace).and_then(|x| x.get(pod_name));
+    let (Some(pod), Some(metrics)) = (pod, metrics) else {
+        f.render_widget(Paragraph::new("no metrics from metrics-server yet"), area);
+        return;
+    };
+
+    // cpu and memory of each container, used / request / limit (% of limit)
+    let rows = container_usage(&pod, metrics).into_iter().map(|usage| {
+        let mut flags = Vec::new();
+        if usage.memory.near_limit() {
+            flags.push("near mem limit");
+        }
+        if usage.cpu.near_limit() {
+            // a guess from usage, metrics-server has no throttling counters
+            flags.push("cpu at limit, likely throttled");
+        }
+        let style = match flags.is_empty() {
+            true => Style::default(),
+            false => Style::default().fg(theme::DefaultTheme::RED_PEACH),
+        };
+        Row::new(vec![
+            usage.name,
+            utilization_cell(usage.cpu, format_cpu),
+            utilization_cell(usage.memory, format_memory),
+            flags.join(", "),
+        ])
+        .style(style)
+    });
+    let widths = [
+        Constraint::Percentage(22),
+        Constraint::Percentage(30),
+        Constraint::Percentage(30),
+        Constraint::Percentage(18),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Container", "CPU use/req/lim", "Mem use/req/lim", ""]).bold());
+    f.render_widget(table, area);
+}
 
-    f.render_widget(state.metrics_buffer.widget(), area);
-    if let Some(_namespaced_metrics) = state.pod_metrics_cache.get(namespace) {
-        if let Some(_pod_metrics) = _namespaced_metrics.get(pod_name) {}
+// 120m/250m/500m 24%
+fn utilization_cell(utilization: Utilization, format: fn(f64) -> String) -> String {
+    let value = |x: Option<f64>| x.map_or("-".to_string(), format);
+    let percent = utilization
+        .limit_percent()
+        .or(utilization.request_percent())
+        .map(|x| format!(" {:.0}%", x))
+        .unwrap_or_default();
+    format!(
+        "{}/{}/{}{}",
+        format(utilization.used),
+        value(utilization.request),
+        value(utilization.limit),
+        percent
+    )
+}
+
+// right-sizing opportunities of the selected namespace, the furthest off first
+pub fn draw_namespace_capacity(f: &mut Frame, state: &AppState, area: Rect) {
+    let namespace = state.namespace_cache.get().unwrap_or_default();
+    let Some(usage) = state.pod_usage.get(namespace.as_ref()) else {
+        f.render_widget(debug_widget("no metrics from metrics-server yet"), area);
+        return;
+    };
+    let pods = state
+        .pod_storage
+        .iter()
+        .filter(|pod| pod.0.metadata.namespace.as_deref() == Some(namespace.as_ref()))
+        .filter_map(|pod| {
+            let name = pod.0.metadata.name.as_deref().unwrap_or_default();
+            usage.get(name).map(|metrics| (pod.as_ref(), metrics))
+        });
+    let opportunities = rightsizing(pods);
+    if opportunities.is_empty() {
+        f.render_widget(
+            Paragraph::new(Line::from("requests of all containers match their usage")),
+            area,
+        );
+        return;
     }
+
+    let rows = opportunities.iter().map(|x| {
+        let format = match x.resource {
+            "cpu" => format_cpu,
+            _ => format_memory,
+        };
+        let (verdict, color) = match x.sizing {
+            Sizing::Under => ("under-provisioned", theme::DefaultTheme::RED_PEACH),
+            _ => ("over-provisioned", theme::DefaultTheme::YELLOW_CARP),
+        };
+        Row::new(vec![
+            x.pod.clone(),
+            x.container.clone(),
+            x.resource.to_string(),
+            format(x.utilization.used),
+            x.utilization.request.map_or("-".to_string(), format),
+            x.utilization
+                .request_percent()
+                .map(|x| format!("{:.0}%", x))
+                .unwrap_or_default(),
+            verdict.to_string(),
+        ])
+        .style(Style::default().fg(color))
+    });
+    let widths = [
+        Constraint::Percentage(30),
+        Constraint::Percentage(16),
+        Constraint::Percentage(8),
+        Constraint::Percentage(10),
+        Constraint::Percentage(10),
+        Constraint::Percentage(8),
+        Constraint::Percentage(18),
+    ];
+    let table = Table::new(rows, widths).header(
+        Row::new(vec![
+            "Pod",
+            "Container",
+            "Resource",
+            "Used",
+            "Request",
+            "Of req",
+            "",
+        ])
+        .bold(),
+    );
+    f.render_widget(table, area);
 }
@@ -1,23 +1,37 @@
-use ratatui::layout::Rect;
+use ratatui::layout::{Constraint, Rect};
 use ratatui::Frame;
+use tui_textarea::TextArea;
 
 use crate::app::state::AppState;
 
-use crate::app::ui::util::{self as uiutil, debug_widget};
+use crate::app::ui::util::{self as uiutil, selectable_list, vertical_chunks};
 
-#[allow(dead_code)]
+// output of the debug session above the line typed into its stdin
 pub fn draw_page_pod_tty(
     f: &mut Frame,
     area: Rect,
-    _state: &mut AppState,
-    _reader: tokio::sync::RwLockReadGuard<Vec<String>>,
+    state: &AppState,
+    reader: tokio::sync::RwLockReadGuard<TextArea>,
 ) {
-    let _outer = uiutil::outer_block(f, "Terminal", area);
+    let pod_name = state.cache_items.get().unwrap_or_default();
+    let outer = uiutil::outer_block(
+        f,
+        format!("debug {} [enter]:send [esc]:end session", pod_name).as_str(),
+        area,
+    );
+    let area = vertical_chunks(vec![Constraint::Min(1), Constraint::Length(3)], outer);
+    f.render_widget(reader.widget(), area[0]);
+    f.render_widget(
+        uiutil::user_input(state.user_input.as_str(), state.get_mode()),
+        area[1],
+    );
 }
 
-#[allow(dead_code)]
-fn show_user_commnad(f: &mut Frame, _x: u16, y: u16, cmd: &str) {
-    let area = Rect::new(1, y - 4, 32, 3);
-    let txt = debug_widget(cmd);
-    f.render_widget(txt, area);
+pub fn draw_debug_targets(f: &mut Frame, area: Rect, state: &AppState) {
+    let outer = uiutil::outer_block(
+        f,
+        "share processes with container [j/k]:select [enter]:start [esc]:back",
+        area,
+    );
+    f.render_widget(selectable_list(&state.debug_targets), outer);
 }
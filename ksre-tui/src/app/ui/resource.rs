@@ -0,0 +1,175 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    widgets::{
+        Block, BorderType, Borders, List, ListItem, ListState, Paragraph, Row, Table, TableState,
+    },
+    Frame,
+};
+
+use crate::app::{
+    handler::keybind::valid_bindings,
+    state::{AppState, Route},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::dynamic::{column_value, object_value, object_yaml, DynamicRtObject},
+    helper::age,
+};
+
+// ------------------------------------
+// kinds      | objects of the kind  |
+//            | or yaml of an object |
+// ------------------------------------
+// help
+pub(super) fn draw_page_resource(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(vec![Constraint::Min(3), Constraint::Length(1)], area);
+    let body = uiutil::horizontal_chunks(
+        vec![Constraint::Percentage(25), Constraint::Percentage(75)],
+        chunks[0],
+    );
+
+    draw_kinds(f, state, body[0]);
+    match state.get_route() {
+        Route::ResourceYaml => draw_yaml(f, state, body[1]),
+        _ => draw_objects(f, state, body[1]),
+    }
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[1],
+    );
+}
+
+fn draw_kinds(f: &mut Frame, state: &AppState, area: Rect) {
+    let title = if state.resource_kinds_cache.list().is_empty() {
+        "Kinds (discovering...)".to_string()
+    } else {
+        format!("Kinds ({})", state.resource_kinds_cache.list().len())
+    };
+    let items = state
+        .resource_kinds_cache
+        .list()
+        .iter()
+        .map(|id| ListItem::new(id.as_ref()))
+        .collect::<Vec<ListItem>>();
+    let focused = matches!(state.get_route(), Route::ResourceKinds);
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(if focused {
+                    theme::DefaultTheme::BLUE_SPRING
+                } else {
+                    theme::DefaultTheme::VIOLET_SPRING1
+                })
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut list_state =
+        ListState::default().with_selected(Some(state.resource_kinds_cache.index()));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn draw_objects(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(watch) = state.resource_watch.as_ref() else {
+        f.render_widget(
+            uiutil::debug_widget("select a kind and press [enter] to watch it"),
+            area,
+        );
+        return;
+    };
+    let kind = &watch.kind;
+
+    let mut header = Vec::new();
+    if kind.namespaced {
+        header.push("Namespace".to_string());
+    }
+    header.push("Name".to_string());
+    header.extend(kind.columns.iter().map(|column| column.name.clone()));
+    header.push("Age".to_string());
+
+    let rows = state
+        .resources_cache
+        .list()
+        .iter()
+        .filter_map(|key| selected_object(state, key))
+        .map(|obj| {
+            let value = object_value(&obj);
+            let mut row = Vec::new();
+            if kind.namespaced {
+                row.push(obj.0.metadata.namespace.clone().unwrap_or_default());
+            }
+            row.push(obj.0.metadata.name.clone().unwrap_or_default());
+            row.extend(
+                kind.columns
+                    .iter()
+                    .map(|column| column_value(&value, &column.json_path)),
+            );
+            row.push(age(obj.0.metadata.creation_timestamp.as_ref()));
+            Row::new(row)
+        })
+        .collect::<Vec<Row>>();
+    let widths = header
+        .iter()
+        .map(|_| Constraint::Ratio(1, header.len() as u32))
+        .collect::<Vec<Constraint>>();
+
+    let table = Table::new(rows, widths)
+        .header(Row::new(header).bold())
+        .block(
+            Block::default()
+                .title(format!(
+                    "{} ({})",
+                    kind.resource.kind,
+                    state.resources_cache.list().len()
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.resources_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_yaml(f: &mut Frame, state: &AppState, area: Rect) {
+    let obj = state
+        .resources_cache
+        .get()
+        .and_then(|key| selected_object(state, key.as_ref()));
+    let Some(obj) = obj else {
+        f.render_widget(uiutil::debug_widget("object is gone"), area);
+        return;
+    };
+    let paragraph = Paragraph::new(object_yaml(&obj))
+        .block(
+            Block::default()
+                .title(format!(
+                    "{} [j/k]:scroll [esc]:back",
+                    obj.0.metadata.name.as_deref().unwrap_or_default()
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .scroll((state.describe_scroll, 0));
+    f.render_widget(paragraph, area);
+}
+
+// keys of resources_cache are namespace/name
+#[inline]
+fn selected_object(state: &AppState, key: &str) -> Option<std::rc::Rc<DynamicRtObject>> {
+    let (namespace, name) = key.split_once('/')?;
+    state.resource_storage.get(namespace, name)
+}
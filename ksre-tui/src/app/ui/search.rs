@@ -0,0 +1,84 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::app::{
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil, centered_rect, vertical_chunks},
+    },
+};
+
+// the query above the hits, a heading line starts the hits of every kind
+pub(super) fn draw_search(f: &mut Frame, state: &AppState, area: Rect) {
+    let Some(search) = state.search.as_ref() else {
+        return;
+    };
+    let area = centered_rect(70, 70, area);
+    f.render_widget(Clear, area);
+    let chunks = vertical_chunks(vec![Constraint::Length(3), Constraint::Min(1)], area);
+    f.render_widget(
+        uiutil::user_input(search.input.as_str(), state.get_mode()).block(
+            Block::default()
+                .title("Search name, label, annotation, image or ip")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        ),
+        chunks[0],
+    );
+
+    let mut items = Vec::new();
+    let mut selected = None;
+    let mut kind = "";
+    for (idx, hit) in search.hits.iter().enumerate() {
+        let entry = &search.entries[hit.entry];
+        if entry.kind != kind {
+            kind = entry.kind.as_str();
+            let count = search.hits[idx..]
+                .iter()
+                .take_while(|x| search.entries[x.entry].kind == kind)
+                .count();
+            items.push(ListItem::new(Line::styled(
+                format!("{} ({})", kind, count),
+                Style::default().bold(),
+            )));
+        }
+        if idx == search.selected {
+            selected = Some(items.len());
+        }
+        let matched = match hit.field {
+            "name" => Span::raw(""),
+            field => Span::styled(
+                format!("  {}: {}", field, hit.value),
+                Style::default().fg(theme::DefaultTheme::VIOLET_SPRING1),
+            ),
+        };
+        items.push(ListItem::new(Line::from(vec![
+            Span::raw(format!("  {}", entry.key())),
+            matched,
+        ])));
+    }
+    let title = format!(
+        "{} hits [j/k]:select [enter]:open [/]:edit query [esc]:close",
+        search.hits.len()
+    );
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_SPRING)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut list_state = ListState::default().with_selected(selected);
+    f.render_stateful_widget(list, chunks[1], &mut list_state);
+}
@@ -0,0 +1,153 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::{
+    handler::keybind::valid_bindings,
+    state::AppState,
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::{
+        pod::is_pod_ready,
+        service::{
+            backing_pods, cluster_ip, endpoints_diagnosis, service_ports, service_selector,
+            service_type, ServiceObject,
+        },
+    },
+    helper::age,
+};
+
+// ------------------------------------
+// services                           |
+// ------------------------------------
+// backing pods                       |
+// ------------------------------------
+// help
+pub(super) fn draw_page_service(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Percentage(55),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+
+    draw_services(f, state, chunks[0]);
+    draw_backing_pods(f, state, chunks[1]);
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[2],
+    );
+}
+
+fn draw_services(f: &mut Frame, state: &AppState, area: Rect) {
+    let header = Row::new(vec![
+        "Namespace",
+        "Name",
+        "Type",
+        "Cluster-IP",
+        "Ports",
+        "Selector",
+        "Age",
+    ])
+    .bold();
+    let rows = state
+        .services_cache
+        .list()
+        .iter()
+        .filter_map(|key| selected_service(state, key))
+        .map(|svc| {
+            Row::new(vec![
+                svc.0.metadata.namespace.clone().unwrap_or_default(),
+                svc.0.metadata.name.clone().unwrap_or_default(),
+                service_type(&svc).to_string(),
+                cluster_ip(&svc).to_string(),
+                service_ports(&svc),
+                service_selector(&svc),
+                age(svc.0.metadata.creation_timestamp.as_ref()),
+            ])
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(12),
+        Constraint::Percentage(20),
+        Constraint::Percentage(10),
+        Constraint::Percentage(12),
+        Constraint::Percentage(18),
+        Constraint::Percentage(22),
+        Constraint::Percentage(6),
+    ];
+    let table = Table::new(rows, widths)
+        .header(header)
+        .block(
+            Block::default()
+                .title(format!("Services ({})", state.services_cache.list().len()))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.services_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_backing_pods(f: &mut Frame, state: &AppState, area: Rect) {
+    let svc = state
+        .services_cache
+        .get()
+        .and_then(|key| selected_service(state, key.as_ref()));
+    let Some(svc) = svc else {
+        f.render_widget(uiutil::debug_widget("Empty"), area);
+        return;
+    };
+
+    let pods = backing_pods(&svc, &state.pod_storage);
+    let mut lines = vec![
+        Line::styled(endpoints_diagnosis(&svc, &pods), Style::default().bold()),
+        Line::from(""),
+    ];
+    lines.extend(pods.iter().map(|pod| {
+        let name = pod.0.metadata.name.as_deref().unwrap_or_default();
+        let ip = pod
+            .0
+            .status
+            .as_ref()
+            .and_then(|status| status.pod_ip.as_deref())
+            .unwrap_or("<none>");
+        if is_pod_ready(pod) {
+            Line::from(format!("  ready      {:<48}{}", name, ip))
+                .style(Style::default().fg(theme::DefaultTheme::GREEN_SPRING))
+        } else {
+            Line::from(format!("  not-ready  {:<48}{}", name, ip))
+                .style(Style::default().fg(theme::DefaultTheme::RED_PEACH))
+        }
+    }));
+
+    let title = format!(
+        "Backing pods of {}",
+        svc.0.metadata.name.as_deref().unwrap_or_default()
+    );
+    let outer = uiutil::outer_block(f, title.as_str(), area);
+    f.render_widget(Paragraph::new(lines), outer);
+}
+
+// keys of services_cache are namespace/name
+#[inline]
+fn selected_service(state: &AppState, key: &str) -> Option<std::rc::Rc<ServiceObject>> {
+    let (namespace, name) = key.split_once('/')?;
+    state.service_storage.get(namespace, name)
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::{
+    handler::{
+        keybind::valid_bindings,
+        storage::{selected_claim, selected_volume},
+    },
+    state::{AppState, Route},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::{
+        capacity::format_memory,
+        cluster::pod_status,
+        storage::{
+            access_modes, claim_capacity, claim_phase, claim_pods, volume_capacity, volume_claim,
+            volume_phase, volume_source, ClaimObject, VolumeObject, VolumeStats,
+        },
+    },
+    helper::age,
+};
+
+// usage above these shares of the capacity is flagged
+const USAGE_WARN: f64 = 75.0;
+const USAGE_CRITICAL: f64 = 90.0;
+
+// ------------------------------------
+// claims of the namespace or volumes |
+// ------------------------------------
+// binding, usage and consuming pods  |
+// ------------------------------------
+// help
+pub(super) fn draw_page_storage(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Percentage(55),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+
+    let stats = state
+        .volume_stats
+        .as_ref()
+        .and_then(|x| x.try_read().ok())
+        .map(|x| x.clone())
+        .unwrap_or_default();
+    match state.get_route() {
+        Route::StorageVolumes => {
+            draw_volumes(f, state, &stats, chunks[0]);
+            match selected_volume(state) {
+                Some(volume) => draw_volume_detail(f, state, &volume, &stats, chunks[1]),
+                None => f.render_widget(uiutil::debug_widget("Empty"), chunks[1]),
+            }
+        }
+        _ => {
+            draw_claims(f, state, &stats, chunks[0]);
+            match selected_claim(state) {
+                Some(claim) => draw_claim_detail(f, state, &claim, &stats, chunks[1]),
+                None => f.render_widget(uiutil::debug_widget("Empty"), chunks[1]),
+            }
+        }
+    }
+
+    let bindings = valid_bindings(state.get_tabpage(), state.get_route(), state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[2],
+    );
+}
+
+fn draw_claims(f: &mut Frame, state: &AppState, stats: &HashMap<String, VolumeStats>, area: Rect) {
+    let namespace = state.namespace_cache.get().unwrap_or_default();
+    let rows = state
+        .claims_cache
+        .list()
+        .iter()
+        .filter_map(|name| state.pvc_storage.get(namespace.as_ref(), name))
+        .map(|claim| {
+            let usage = stats.get(&claim_key(&claim));
+            let phase = claim_phase(&claim);
+            let row = Row::new(vec![
+                claim.0.metadata.name.clone().unwrap_or_default(),
+                phase.to_string(),
+                claim.0.spec.volume_name.clone().unwrap_or_default(),
+                claim_capacity(&claim),
+                access_modes(claim.0.spec.access_modes.as_ref()),
+                claim.0.spec.storage_class_name.clone().unwrap_or_default(),
+                usage.map_or("-".to_string(), |x| x.summary()),
+                age(claim.0.metadata.creation_timestamp.as_ref()),
+            ]);
+            match (phase, usage) {
+                ("Bound", Some(usage)) => row.style(Style::default().fg(usage_color(usage))),
+                ("Bound", None) => row,
+                _ => row.style(Style::default().fg(theme::DefaultTheme::YELLOW_CARP)),
+            }
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(22),
+        Constraint::Percentage(8),
+        Constraint::Percentage(22),
+        Constraint::Percentage(8),
+        Constraint::Percentage(7),
+        Constraint::Percentage(10),
+        Constraint::Percentage(17),
+        Constraint::Percentage(6),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec![
+                "Name", "Status", "Volume", "Capacity", "Access", "Class", "Used", "Age",
+            ])
+            .bold(),
+        )
+        .block(
+            Block::default()
+                .title(format!("Claims of {} [n]:next namespace", namespace))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.claims_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_volumes(f: &mut Frame, state: &AppState, stats: &HashMap<String, VolumeStats>, area: Rect) {
+    let rows = state
+        .volumes_cache
+        .list()
+        .iter()
+        .filter_map(|name| state.pv_storage.get("", name))
+        .map(|volume| {
+            let claim = volume_claim(&volume);
+            let usage = claim.as_ref().and_then(|x| stats.get(x));
+            let phase = volume_phase(&volume);
+            let row = Row::new(vec![
+                volume.0.metadata.name.clone().unwrap_or_default(),
+                volume_capacity(&volume),
+                access_modes(volume.0.spec.access_modes.as_ref()),
+                volume
+                    .0
+                    .spec
+                    .persistent_volume_reclaim_policy
+                    .clone()
+                    .unwrap_or_default(),
+                phase.to_string(),
+                claim.unwrap_or_default(),
+                volume.0.spec.storage_class_name.clone().unwrap_or_default(),
+                usage.map_or("-".to_string(), |x| x.summary()),
+                age(volume.0.metadata.creation_timestamp.as_ref()),
+            ]);
+            match (phase, usage) {
+                ("Bound", Some(usage)) => row.style(Style::default().fg(usage_color(usage))),
+                ("Bound", None) => row,
+                ("Failed", _) => row.style(Style::default().fg(theme::DefaultTheme::RED_PEACH)),
+                _ => row.style(Style::default().fg(theme::DefaultTheme::YELLOW_CARP)),
+            }
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(20),
+        Constraint::Percentage(8),
+        Constraint::Percentage(6),
+        Constraint::Percentage(8),
+        Constraint::Percentage(8),
+        Constraint::Percentage(20),
+        Constraint::Percentage(10),
+        Constraint::Percentage(14),
+        Constraint::Percentage(6),
+    ];
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec![
+                "Name", "Capacity", "Access", "Reclaim", "Status", "Claim", "Class", "Used", "Age",
+            ])
+            .bold(),
+        )
+        .block(
+            Block::default()
+                .title("Persistent volumes")
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(
+            Style::default()
+                .fg(theme::DefaultTheme::BLUE_LIGHT)
+                .bg(theme::DefaultTheme::SUMLINK1),
+        );
+    let mut table_state = TableState::default().with_selected(Some(state.volumes_cache.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_claim_detail(
+    f: &mut Frame,
+    state: &AppState,
+    claim: &ClaimObject,
+    stats: &HashMap<String, VolumeStats>,
+    area: Rect,
+) {
+    let title = format!(
+        "Claim {}",
+        claim.0.metadata.name.as_deref().unwrap_or_default()
+    );
+    let outer = uiutil::outer_block(f, title.as_str(), area);
+    let volume = claim.0.spec.volume_name.clone();
+    let source = volume
+        .as_deref()
+        .and_then(|x| state.pv_storage.get("", x))
+        .map_or("<none>".to_string(), |x| volume_source(&x));
+    let mut lines = vec![
+        Line::from(format!("status:        {}", claim_phase(claim))),
+        Line::from(format!(
+            "volume:        {} [v]:show",
+            volume.as_deref().unwrap_or("<none>")
+        )),
+        Line::from(format!("source:        {}", source)),
+        Line::from(format!(
+            "mode:          {} {}",
+            access_modes(claim.0.spec.access_modes.as_ref()),
+            claim.0.spec.volume_mode.as_deref().unwrap_or("Filesystem")
+        )),
+        usage_line(stats.get(&claim_key(claim))),
+    ];
+    lines.extend(pod_lines(state, claim));
+    f.render_widget(Paragraph::new(lines), outer);
+}
+
+fn draw_volume_detail(
+    f: &mut Frame,
+    state: &AppState,
+    volume: &VolumeObject,
+    stats: &HashMap<String, VolumeStats>,
+    area: Rect,
+) {
+    let title = format!(
+        "Volume {}",
+        volume.0.metadata.name.as_deref().unwrap_or_default()
+    );
+    let outer = uiutil::outer_block(f, title.as_str(), area);
+    let claim_name = volume_claim(volume);
+    let claim = claim_name
+        .as_deref()
+        .and_then(|x| x.split_once('/'))
+        .and_then(|(namespace, name)| state.pvc_storage.get(namespace, name));
+    let reason = volume
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.message.clone().or(x.reason.clone()));
+    let mut lines = vec![
+        Line::from(format!(
+            "status:        {} {}",
+            volume_phase(volume),
+            reason.unwrap_or_default()
+        )),
+        Line::from(format!(
+            "claim:         {} [v]:show",
+            claim_name.as_deref().unwrap_or("<none>")
+        )),
+        Line::from(format!("source:        {}", volume_source(volume))),
+        usage_line(claim_name.as_ref().and_then(|x| stats.get(x))),
+    ];
+    if let Some(claim) = claim {
+        lines.extend(pod_lines(state, &claim));
+    }
+    f.render_widget(Paragraph::new(lines), outer);
+}
+
+fn usage_line(usage: Option<&VolumeStats>) -> Line<'static> {
+    let Some(usage) = usage else {
+        return Line::from("used:          - (not mounted or kubelet stats unavailable)");
+    };
+    Line::from(format!(
+        "used:          {}, {} available",
+        usage.summary(),
+        format_memory(usage.available as f64)
+    ))
+    .style(Style::default().fg(usage_color(usage)))
+}
+
+fn pod_lines(state: &AppState, claim: &ClaimObject) -> Vec<Line<'static>> {
+    let pods = claim_pods(claim, state.pod_storage.iter());
+    if pods.is_empty() {
+        return vec![Line::from("pods:          <none>")];
+    }
+    pods.iter()
+        .enumerate()
+        .map(|(idx, pod)| {
+            Line::from(format!(
+                "{:<15}{:<48}{:<20}{}",
+                if idx == 0 { "pods:" } else { "" },
+                pod.0.metadata.name.as_deref().unwrap_or_default(),
+                pod_status(pod),
+                pod.0.spec.node_name.as_deref().unwrap_or_default(),
+            ))
+        })
+        .collect()
+}
+
+fn claim_key(claim: &ClaimObject) -> String {
+    format!(
+        "{}/{}",
+        claim.0.metadata.namespace.as_deref().unwrap_or_default(),
+        claim.0.metadata.name.as_deref().unwrap_or_default()
+    )
+}
+
+#[inline]
+fn usage_color(usage: &VolumeStats) -> Color {
+    match usage.percent_used() {
+        x if x >= USAGE_CRITICAL => theme::DefaultTheme::RED_PEACH,
+        x if x >= USAGE_WARN => theme::DefaultTheme::YELLOW_CARP,
+        _ => theme::DefaultTheme::GREEN_SPRING,
+    }
+}
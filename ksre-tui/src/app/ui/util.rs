@@ -3,18 +3,32 @@ use std::rc::Rc;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Style, Stylize},
-    text::Line,
+    text::{Line, Span},
     widgets::{Block, BorderType, Borders, List, ListItem, Paragraph, Tabs},
     Frame,
 };
 
 use crate::app::{
-    state::{Mode, StatefulList},
+    handler::keybind::KeyBinding,
+    state::{AppState, Mode, StatefulList},
     ui::theme,
 };
 
 use super::theme::Kanagawa;
 
+// one line hint like "help: [e]:filter pods [q]:quit", actions rbac forbids are greyed out
+pub(super) fn hint_line(state: &AppState, bindings: &[&KeyBinding]) -> Line<'static> {
+    let mut spans = vec![Span::raw("help:")];
+    for binding in bindings {
+        let hint = format!(" [{}]:{}", binding.key.to_lowercase(), binding.desc);
+        match binding.missing_permission(state) {
+            Some(_) => spans.push(Span::styled(hint, Style::default().fg(Color::DarkGray))),
+            None => spans.push(Span::raw(hint)),
+        }
+    }
+    Line::from(spans)
+}
+
 pub(super) fn titled_block(title: &'static str) -> Block {
     Block::default()
         .title(title)
@@ -71,7 +85,6 @@ pub(super) fn selected_tab(values: Vec<&str>, id_selected: usize) -> Tabs<'_> {
         .padding(" ", "")
 }
 
-#[allow(dead_code)]
 pub(super) fn selectable_list(stateful_list: &StatefulList) -> List {
     let mut list_items = Vec::new();
     for (idx, val) in stateful_list.list().iter().enumerate() {
@@ -94,6 +107,17 @@ pub(super) fn selectable_list(stateful_list: &StatefulList) -> List {
     )
 }
 
+// first row to draw so that the selected one stays within `height` rows, moving as little as possible
+pub(super) fn scroll_offset(selected: usize, offset: usize, height: usize) -> usize {
+    if height == 0 || selected < offset {
+        selected
+    } else if selected >= offset + height {
+        selected + 1 - height
+    } else {
+        offset
+    }
+}
+
 pub(super) fn selectable_list_1(stateful_list: &StatefulList) -> List {
     let mut list_items = Vec::new();
     let items = stateful_list.list();
@@ -143,3 +167,23 @@ pub(super) fn outer_block(f: &mut Frame, title: &str, area: Rect) -> Rect {
     f.render_widget(outer, area);
     Rect::new(area.x + 1, area.y + 1, area.width - 1, area.height - 1)
 }
+
+// rect of percent_x * percent_y centered in area, used by popups
+pub(super) fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = vertical_chunks(
+        vec![
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ],
+        area,
+    );
+    horizontal_chunks(
+        vec![
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ],
+        vertical[1],
+    )[1]
+}
@@ -0,0 +1,248 @@
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, BorderType, Borders, Gauge, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use crate::app::{
+    handler::{keybind::valid_bindings, workload::selected_deployment},
+    state::{AppState, Route},
+    ui::{
+        theme::{self, Kanagawa},
+        util::{self as uiutil},
+    },
+};
+use crate::kubernetes::{
+    api::deployment::{
+        current_revision, rollout_history, rollout_progress, DeploymentObject, RolloutProgress,
+    },
+    helper::age,
+    owner::{Health, OwnerNode},
+};
+
+// ------------------------------------
+// workload tree                      |
+// ------------------------------------
+// owner chain / health, rollout      |
+// history or the replica count input |
+// ------------------------------------
+// help
+pub(super) fn draw_page_workload(f: &mut Frame, state: &mut AppState, area: Rect) {
+    let route = state.get_route();
+    let chunks = uiutil::vertical_chunks(
+        vec![
+            Constraint::Percentage(if route == Route::DeployIndex { 70 } else { 50 }),
+            Constraint::Min(3),
+            Constraint::Length(1),
+        ],
+        area,
+    );
+
+    draw_tree(f, state, chunks[0]);
+    match (route, selected_deployment(state)) {
+        (Route::DeployHistory, Some(deploy)) => draw_history(f, state, &deploy, chunks[1]),
+        (Route::DeployScale, Some(deploy)) => draw_scale_input(f, state, &deploy, chunks[1]),
+        _ => draw_detail(f, state, chunks[1]),
+    }
+
+    let bindings = valid_bindings(state.get_tabpage(), route, state.get_mode());
+    f.render_widget(
+        Paragraph::new(uiutil::hint_line(state, &bindings)),
+        chunks[2],
+    );
+}
+
+fn draw_tree(f: &mut Frame, state: &AppState, area: Rect) {
+    let namespace = state.namespace_cache.get().unwrap_or_default();
+    let rows = state
+        .workload_rows
+        .list()
+        .iter()
+        .filter_map(|uid| state.workload_tree.get(uid.as_ref()))
+        .map(|node| {
+            let marker = match node.children.is_empty() {
+                true => " ",
+                false if state.workload_collapsed.contains(&node.uid) => "▸",
+                false => "▾",
+            };
+            Row::new(vec![
+                format!(
+                    "{}{} {}/{}",
+                    "  ".repeat(node.depth),
+                    marker,
+                    node.kind,
+                    node.name
+                ),
+                format!("{}/{}", node.health.ready, node.health.total),
+                node.health.restarts.to_string(),
+                node.revision
+                    .as_ref()
+                    .map(|x| format!("rev {}", x))
+                    .unwrap_or_default(),
+            ])
+            .style(Style::default().fg(health_color(node.health)))
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(64),
+        Constraint::Percentage(12),
+        Constraint::Percentage(12),
+        Constraint::Percentage(12),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Workload", "Ready", "Restarts", "Revision"]).bold())
+        .block(
+            Block::default()
+                .title(format!("Workloads of {} [n]:next namespace", namespace))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(Style::default().bg(theme::DefaultTheme::SUMLINK1).bold());
+    let mut table_state = TableState::default().with_selected(Some(state.workload_rows.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_detail(f: &mut Frame, state: &AppState, area: Rect) {
+    let node = state
+        .workload_rows
+        .get()
+        .and_then(|uid| state.workload_tree.get(uid.as_ref()));
+    let Some(node) = node else {
+        f.render_widget(uiutil::debug_widget("Empty"), area);
+        return;
+    };
+
+    let chain = std::iter::once(node)
+        .chain(
+            state
+                .workload_tree
+                .ancestors(&node.uid)
+                .iter()
+                .filter_map(|uid| state.workload_tree.get(uid)),
+        )
+        .map(display_name)
+        .collect::<Vec<String>>()
+        .join(" <- ");
+    let lines = vec![
+        Line::from(format!("owner chain: {}", chain)),
+        Line::from(format!(
+            "pods ready: {}/{}, restarts: {}",
+            node.health.ready, node.health.total, node.health.restarts
+        ))
+        .style(Style::default().fg(health_color(node.health))),
+    ];
+    let outer = uiutil::outer_block(f, display_name(node).as_str(), area);
+    // a deployment in the middle of a rollout gets the progress panel beside its health
+    let progress = selected_deployment(state).and_then(|deploy| rollout_progress(&deploy));
+    let Some(progress) = progress else {
+        f.render_widget(Paragraph::new(lines), outer);
+        return;
+    };
+    let halves = uiutil::horizontal_chunks(
+        vec![Constraint::Percentage(50), Constraint::Percentage(50)],
+        outer,
+    );
+    f.render_widget(Paragraph::new(lines), halves[0]);
+    draw_progress(f, &progress, halves[1]);
+}
+
+fn draw_progress(f: &mut Frame, progress: &RolloutProgress, area: Rect) {
+    let chunks = uiutil::vertical_chunks(vec![Constraint::Length(1), Constraint::Min(1)], area);
+    let color = match progress.stalled {
+        Some(_) => theme::DefaultTheme::RED_PEACH,
+        None => theme::DefaultTheme::YELLOW_CARP,
+    };
+    let ratio = match progress.desired {
+        0 => 1.0,
+        desired => (progress.updated.min(desired) as f64 / desired as f64).clamp(0.0, 1.0),
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(color))
+        .ratio(ratio)
+        .label(format!(
+            "rolling out {}/{} updated",
+            progress.updated, progress.desired
+        ));
+    f.render_widget(gauge, chunks[0]);
+    let mut lines = vec![Line::from(format!(
+        "ready {}, available {}, old replicas {}",
+        progress.ready, progress.available, progress.old
+    ))];
+    if let Some(stalled) = progress.stalled.as_ref() {
+        lines.push(Line::from(stalled.as_str()).style(Style::default().fg(color)));
+    }
+    f.render_widget(Paragraph::new(lines), chunks[1]);
+}
+
+fn draw_history(f: &mut Frame, state: &AppState, deploy: &DeploymentObject, area: Rect) {
+    let current = current_revision(deploy);
+    let rows = rollout_history(deploy, state.replicaset_storage.iter())
+        .into_iter()
+        .map(|revision| {
+            Row::new(vec![
+                if Some(revision.number) == current {
+                    format!("{} (current)", revision.number)
+                } else {
+                    revision.number.to_string()
+                },
+                revision.change_cause.unwrap_or("<none>".to_string()),
+                revision.image_diff.join(", "),
+                age(revision.replicaset.0.metadata.creation_timestamp.as_ref()),
+            ])
+        })
+        .collect::<Vec<Row>>();
+    let widths = [
+        Constraint::Percentage(12),
+        Constraint::Percentage(30),
+        Constraint::Percentage(48),
+        Constraint::Percentage(10),
+    ];
+    let table = Table::new(rows, widths)
+        .header(Row::new(vec!["Revision", "Change cause", "Images", "Age"]).bold())
+        .block(
+            Block::default()
+                .title(format!(
+                    "Rollout history of {} [enter]:undo to revision [esc]:back",
+                    deploy.0.metadata.name.as_deref().unwrap_or_default()
+                ))
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded),
+        )
+        .highlight_style(Style::default().bg(theme::DefaultTheme::SUMLINK1).bold());
+    let mut table_state = TableState::default().with_selected(Some(state.rollout_history.index()));
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn draw_scale_input(f: &mut Frame, state: &AppState, deploy: &DeploymentObject, area: Rect) {
+    let input = uiutil::user_input(state.user_input.as_str(), state.get_mode()).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(
+                "Scale {} from {} replicas, [enter]:apply [esc]:cancel",
+                deploy.0.metadata.name.as_deref().unwrap_or_default(),
+                deploy.0.spec.replicas.unwrap_or(1)
+            ))
+            .border_type(BorderType::Rounded),
+    );
+    f.render_widget(input, area);
+}
+
+#[inline]
+fn display_name(node: &OwnerNode) -> String {
+    format!("{}/{}", node.kind, node.name)
+}
+
+// red when some pod is not ready, yellow when all are ready but restarted
+fn health_color(health: Health) -> Color {
+    if health.ready < health.total {
+        theme::DefaultTheme::RED_PEACH
+    } else if health.restarts > 0 {
+        theme::DefaultTheme::YELLOW_CARP
+    } else if health.total > 0 {
+        theme::DefaultTheme::GREEN_SPRING
+    } else {
+        theme::DefaultTheme::VIOLET_SPRING1
+    }
+}
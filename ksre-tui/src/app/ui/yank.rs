@@ -0,0 +1,30 @@
+use ratatui::{
+    layout::Rect,
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::app::{handler::keybind::YANK_KEYMAPS, state::AppState, ui::util::centered_rect};
+
+pub(super) fn draw_yank(f: &mut Frame, state: &AppState, area: Rect) {
+    let area = centered_rect(30, 30, area);
+    let menu = YANK_KEYMAPS
+        .valid_bindings(state.get_route())
+        .map(|binding| {
+            format!(
+                "{:<8}{}",
+                format!("[{}]", binding.key.to_lowercase()),
+                binding.desc
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    let menu = Paragraph::new(menu).block(
+        Block::default()
+            .title("Copy")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded),
+    );
+    f.render_widget(Clear, area);
+    f.render_widget(menu, area);
+}
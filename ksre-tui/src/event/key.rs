@@ -30,11 +30,32 @@ pub enum CusKey {
     X,
     Y,
     Z,
+    Num0,
+    Num1,
+    Num2,
+    Num3,
+    Num4,
+    Num5,
+    Num6,
+    Num7,
+    Num8,
+    Num9,
     Space,
     Backspace,
     Tab,
     Enter,
     Esc,
+    Question,
+    Exclamation,
+    Ampersand,
+    Backtick,
+    Dot,
+    Minus,
+    Underscore,
+    Colon,
+    Slash,
+    Equal,
+    Tilde,
     None,
 }
 
@@ -69,7 +90,28 @@ impl From<KeyEvent> for CusKey {
                 'x' => CusKey::X,
                 'y' => CusKey::Y,
                 'z' => CusKey::Z,
+                '0' => CusKey::Num0,
+                '1' => CusKey::Num1,
+                '2' => CusKey::Num2,
+                '3' => CusKey::Num3,
+                '4' => CusKey::Num4,
+                '5' => CusKey::Num5,
+                '6' => CusKey::Num6,
+                '7' => CusKey::Num7,
+                '8' => CusKey::Num8,
+                '9' => CusKey::Num9,
                 ' ' => CusKey::Space,
+                '?' => CusKey::Question,
+                '!' => CusKey::Exclamation,
+                '&' => CusKey::Ampersand,
+                '`' => CusKey::Backtick,
+                '.' => CusKey::Dot,
+                '-' => CusKey::Minus,
+                '_' => CusKey::Underscore,
+                ':' => CusKey::Colon,
+                '/' => CusKey::Slash,
+                '=' => CusKey::Equal,
+                '~' => CusKey::Tilde,
                 _ => CusKey::None,
             },
             KeyCode::Tab => CusKey::Tab,
@@ -111,12 +153,33 @@ impl CusKey {
             CusKey::X => 'x',
             CusKey::Y => 'y',
             CusKey::Z => 'z',
+            CusKey::Num0 => '0',
+            CusKey::Num1 => '1',
+            CusKey::Num2 => '2',
+            CusKey::Num3 => '3',
+            CusKey::Num4 => '4',
+            CusKey::Num5 => '5',
+            CusKey::Num6 => '6',
+            CusKey::Num7 => '7',
+            CusKey::Num8 => '8',
+            CusKey::Num9 => '9',
             CusKey::Space => ' ',
             CusKey::Tab => '_',
             CusKey::Enter => ';',
             CusKey::None => '-',
             CusKey::Backspace => ' ',
             CusKey::Esc => '~',
+            CusKey::Question => '?',
+            CusKey::Exclamation => '!',
+            CusKey::Ampersand => '&',
+            CusKey::Backtick => '`',
+            CusKey::Dot => '.',
+            CusKey::Minus => '-',
+            CusKey::Underscore => '_',
+            CusKey::Colon => ':',
+            CusKey::Slash => '/',
+            CusKey::Equal => '=',
+            CusKey::Tilde => '~',
         }
     }
     pub fn as_ref(self) -> &'static str {
@@ -147,12 +210,33 @@ impl CusKey {
             CusKey::X => "x",
             CusKey::Y => "y",
             CusKey::Z => "z",
+            CusKey::Num0 => "0",
+            CusKey::Num1 => "1",
+            CusKey::Num2 => "2",
+            CusKey::Num3 => "3",
+            CusKey::Num4 => "4",
+            CusKey::Num5 => "5",
+            CusKey::Num6 => "6",
+            CusKey::Num7 => "7",
+            CusKey::Num8 => "8",
+            CusKey::Num9 => "9",
             CusKey::Space => "Space",
             CusKey::Tab => "Tab",
             CusKey::Enter => "Enter",
             CusKey::None => "-",
             CusKey::Backspace => "Backspace",
             CusKey::Esc => "Esc",
+            CusKey::Question => "?",
+            CusKey::Exclamation => "!",
+            CusKey::Ampersand => "&",
+            CusKey::Backtick => "`",
+            CusKey::Dot => ".",
+            CusKey::Minus => "-",
+            CusKey::Underscore => "_",
+            CusKey::Colon => ":",
+            CusKey::Slash => "/",
+            CusKey::Equal => "=",
+            CusKey::Tilde => "~",
         }
     }
 }
@@ -1,10 +1,13 @@
 pub(crate) mod key;
 pub(crate) use key::CusKey;
 
+use crossterm::event::MouseEvent;
+
 #[derive(Debug, Clone, Copy)]
 pub enum Event {
     Tick,
     Key(CusKey),
+    Mouse(MouseEvent),
     Error,
 }
 
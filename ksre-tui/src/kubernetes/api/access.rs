@@ -0,0 +1,174 @@
+use color_eyre::eyre::Result;
+use k8s_openapi::api::authorization::v1::{
+    ResourceAttributes, ResourceRule, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec,
+    SelfSubjectRulesReview, SelfSubjectRulesReviewSpec,
+};
+use kube::{api::PostParams, Api, Client};
+
+// one request an action makes, resource may carry a subresource like pods/log
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Permission {
+    pub verb: &'static str,
+    pub group: &'static str,
+    pub resource: &'static str,
+}
+
+impl Permission {
+    pub const fn new(verb: &'static str, group: &'static str, resource: &'static str) -> Self {
+        Self {
+            verb,
+            group,
+            resource,
+        }
+    }
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.group {
+            "" => write!(f, "{} {}", self.verb, self.resource),
+            group => write!(f, "{} {}.{}", self.verb, self.resource, group),
+        }
+    }
+}
+
+pub const READ_LOGS: Permission = Permission::new("get", "", "pods/log");
+pub const EXEC: Permission = Permission::new("create", "", "pods/exec");
+pub const DEBUG: Permission = Permission::new("patch", "", "pods/ephemeralcontainers");
+pub const EVICT: Permission = Permission::new("create", "", "pods/eviction");
+pub const PATCH_NODES: Permission = Permission::new("patch", "", "nodes");
+pub const PATCH_DEPLOYMENTS: Permission = Permission::new("patch", "apps", "deployments");
+pub const UPDATE_DEPLOYMENTS: Permission = Permission::new("update", "apps", "deployments");
+pub const CREATE_JOBS: Permission = Permission::new("create", "batch", "jobs");
+pub const PATCH_CRONJOBS: Permission = Permission::new("patch", "batch", "cronjobs");
+pub const PATCH_JOBS: Permission = Permission::new("patch", "batch", "jobs");
+
+// rules of the user in one namespace. an incomplete review, e.g. with a webhook authorizer,
+// allows everything, the api server still has the last word
+#[derive(Clone, Debug, Default)]
+pub struct Permissions {
+    rules: Vec<ResourceRule>,
+    complete: bool,
+}
+
+impl Permissions {
+    pub fn allows(&self, permission: &Permission) -> bool {
+        !self.complete || self.rules.iter().any(|rule| rule_allows(rule, permission))
+    }
+}
+
+// resource names are ignored, a rule for some objects keeps the action available
+fn rule_allows(rule: &ResourceRule, permission: &Permission) -> bool {
+    let matches = |values: Option<&Vec<String>>, wanted: &str| {
+        values.is_some_and(|values| {
+            values
+                .iter()
+                .any(|x| x == "*" || resource_matches(x, wanted))
+        })
+    };
+    matches(Some(&rule.verbs), permission.verb)
+        && matches(rule.api_groups.as_ref(), permission.group)
+        && matches(rule.resources.as_ref(), permission.resource)
+}
+
+// a rule of pods/* or */log covers pods/log
+fn resource_matches(rule: &str, wanted: &str) -> bool {
+    match (rule.split_once('/'), wanted.split_once('/')) {
+        (Some((resource, sub)), Some((wanted_resource, wanted_sub))) => {
+            (resource == "*" || resource == wanted_resource) && (sub == "*" || sub == wanted_sub)
+        }
+        _ => rule == wanted,
+    }
+}
+
+pub async fn review_rules(client: Client, namespace: &str) -> Result<Permissions> {
+    let review = SelfSubjectRulesReview {
+        spec: SelfSubjectRulesReviewSpec {
+            namespace: Some(namespace.to_string()),
+        },
+        ..Default::default()
+    };
+    let api: Api<SelfSubjectRulesReview> = Api::all(client);
+    let status = api.create(&PostParams::default(), &review).await?.status;
+    Ok(status
+        .map(|status| Permissions {
+            complete: !status.incomplete,
+            rules: status.resource_rules,
+        })
+        .unwrap_or_default())
+}
+
+// kubectl auth can-i, a namespace of None asks for every namespace or a cluster-scoped resource
+pub async fn can_i(
+    client: Client,
+    verb: &str,
+    group: &str,
+    resource: &str,
+    namespace: Option<&str>,
+) -> Result<bool> {
+    let review = SelfSubjectAccessReview {
+        spec: SelfSubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+                verb: Some(verb.to_string()),
+                group: Some(group.to_string()),
+                resource: Some(resource.to_string()),
+                namespace: namespace.map(String::from),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let api: Api<SelfSubjectAccessReview> = Api::all(client);
+    let status = api.create(&PostParams::default(), &review).await?.status;
+    Ok(status.is_some_and(|status| status.allowed))
+}
+
+// namespace to limit watches to, None when every namespace can be watched.
+// a user with namespaced rbac gets the namespace of the kubeconfig context, like kubectl
+pub async fn watch_namespace(client: Client) -> Option<String> {
+    let namespace = client.default_namespace().to_string();
+    match can_i(client, "list", "", "pods", None).await {
+        Ok(true) => None,
+        Ok(false) => {
+            tracing::info!(
+                "pods of all namespaces are forbidden, watching {}",
+                namespace
+            );
+            Some(namespace)
+        }
+        Err(err) => {
+            tracing::warn!("access review failed: {}", err);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_permissions_allows() {
+        let rule = |verbs: &[&str], groups: &[&str], resources: &[&str]| ResourceRule {
+            verbs: verbs.iter().map(|x| x.to_string()).collect(),
+            api_groups: Some(groups.iter().map(|x| x.to_string()).collect()),
+            resources: Some(resources.iter().map(|x| x.to_string()).collect()),
+            resource_names: None,
+        };
+        let permissions = Permissions {
+            rules: vec![
+                rule(&["get", "list", "watch"], &[""], &["pods", "pods/log"]),
+                rule(&["*"], &["apps"], &["deployments", "deployments/*"]),
+            ],
+            complete: true,
+        };
+        assert!(permissions.allows(&READ_LOGS));
+        assert!(permissions.allows(&PATCH_DEPLOYMENTS));
+        assert!(permissions.allows(&Permission::new("patch", "apps", "deployments/scale")));
+        assert!(!permissions.allows(&EXEC));
+        assert!(!permissions.allows(&CREATE_JOBS));
+        assert!(Permissions::default().allows(&EXEC));
+        assert_eq!("create jobs.batch", CREATE_JOBS.to_string());
+    }
+}
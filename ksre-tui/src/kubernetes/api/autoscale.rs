@@ -0,0 +1,320 @@
+use chrono::Utc;
+use k8s_openapi::api::autoscaling::v2::{
+    HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus, MetricSpec, MetricStatus,
+    MetricTarget, MetricValueStatus,
+};
+
+use crate::kubernetes::api::{capacity::quantity_value, object::RtObject};
+
+pub type HpaObject = RtObject<HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus>;
+
+// one metric of the autoscaler, current is None until the controller could read it
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricValue {
+    pub name: String,
+    pub current: Option<String>,
+    pub target: String,
+    // current as percent of the target, above 100 the autoscaler wants to scale up
+    pub ratio: Option<f64>,
+}
+
+// replicas and metric ratios at the time a watch event came in
+#[derive(Clone, Debug, PartialEq)]
+pub struct HpaSample {
+    pub time: i64,
+    pub current: i32,
+    pub desired: i32,
+    // in the order of the spec metrics
+    pub ratios: Vec<Option<f64>>,
+}
+
+impl HpaSample {
+    pub fn new(hpa: &HpaObject) -> Self {
+        let (current, desired) = hpa_replicas(hpa);
+        Self {
+            time: Utc::now().timestamp(),
+            current,
+            desired,
+            ratios: hpa_metrics(hpa).into_iter().map(|x| x.ratio).collect(),
+        }
+    }
+
+    // a relist sends every object again, unchanged ones add nothing to the history
+    pub fn same_values(&self, other: &HpaSample) -> bool {
+        (self.current, self.desired, &self.ratios) == (other.current, other.desired, &other.ratios)
+    }
+}
+
+// samples hold a value until the next change, so the chart steps instead of drawing slopes
+// between them. the last value is held until the end of the axis
+pub fn step_points(points: &[(f64, f64)], until: f64) -> Vec<(f64, f64)> {
+    let mut result = Vec::with_capacity(points.len() * 2 + 1);
+    for &(x, y) in points {
+        if let Some(&(_, previous)) = result.last() {
+            result.push((x, previous));
+        }
+        result.push((x, y));
+    }
+    if let Some(&(x, y)) = result.last() {
+        if x < until {
+            result.push((until, y));
+        }
+    }
+    result
+}
+
+// current and desired replicas
+pub fn hpa_replicas(hpa: &HpaObject) -> (i32, i32) {
+    hpa.0.status.as_ref().map_or((0, 0), |x| {
+        (x.current_replicas.unwrap_or(0), x.desired_replicas)
+    })
+}
+
+// e.g. Deployment/web
+pub fn scale_target(hpa: &HpaObject) -> String {
+    let target = &hpa.0.spec.scale_target_ref;
+    format!("{}/{}", target.kind, target.name)
+}
+
+// spec metrics joined with what the controller last observed for them
+pub fn hpa_metrics(hpa: &HpaObject) -> Vec<MetricValue> {
+    let observed = hpa
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.current_metrics.as_deref())
+        .unwrap_or_default();
+    hpa.0
+        .spec
+        .metrics
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|spec| {
+            let (name, target) = spec_metric(spec)?;
+            let current = observed
+                .iter()
+                .filter_map(status_metric)
+                .find(|(x, _)| *x == name)
+                .map(|(_, current)| current);
+            Some(metric_value(name, target, current))
+        })
+        .collect()
+}
+
+// the TARGETS column of kubectl get hpa, e.g. cpu: 45%/80%, memory: <unknown>/1Gi
+pub fn targets_summary(hpa: &HpaObject) -> String {
+    hpa_metrics(hpa)
+        .iter()
+        .map(|x| {
+            format!(
+                "{}: {}/{}",
+                x.name,
+                x.current.as_deref().unwrap_or("<unknown>"),
+                x.target
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn spec_metric(spec: &MetricSpec) -> Option<(String, &MetricTarget)> {
+    if let Some(x) = spec.resource.as_ref() {
+        Some((x.name.clone(), &x.target))
+    } else if let Some(x) = spec.container_resource.as_ref() {
+        Some((format!("{}/{}", x.container, x.name), &x.target))
+    } else if let Some(x) = spec.pods.as_ref() {
+        Some((format!("pods {}", x.metric.name), &x.target))
+    } else if let Some(x) = spec.object.as_ref() {
+        let object = &x.described_object;
+        let name = format!("{} {}/{}", x.metric.name, object.kind, object.name);
+        Some((name, &x.target))
+    } else {
+        let x = spec.external.as_ref()?;
+        Some((format!("external {}", x.metric.name), &x.target))
+    }
+}
+
+// keyed like spec_metric so the two can be matched
+fn status_metric(status: &MetricStatus) -> Option<(String, &MetricValueStatus)> {
+    if let Some(x) = status.resource.as_ref() {
+        Some((x.name.clone(), &x.current))
+    } else if let Some(x) = status.container_resource.as_ref() {
+        Some((format!("{}/{}", x.container, x.name), &x.current))
+    } else if let Some(x) = status.pods.as_ref() {
+        Some((format!("pods {}", x.metric.name), &x.current))
+    } else if let Some(x) = status.object.as_ref() {
+        let object = &x.described_object;
+        let name = format!("{} {}/{}", x.metric.name, object.kind, object.name);
+        Some((name, &x.current))
+    } else {
+        let x = status.external.as_ref()?;
+        Some((format!("external {}", x.metric.name), &x.current))
+    }
+}
+
+// a target is a utilization in percent, an average value per pod or a total value
+fn metric_value(
+    name: String,
+    target: &MetricTarget,
+    current: Option<&MetricValueStatus>,
+) -> MetricValue {
+    if let Some(utilization) = target.average_utilization {
+        let current = current.and_then(|x| x.average_utilization);
+        return MetricValue {
+            name,
+            current: current.map(|x| format!("{}%", x)),
+            target: format!("{}%", utilization),
+            ratio: current
+                .filter(|_| utilization > 0)
+                .map(|x| x as f64 * 100.0 / utilization as f64),
+        };
+    }
+    let (target, current) = match target.average_value.as_ref() {
+        Some(target) => (Some(target), current.and_then(|x| x.average_value.as_ref())),
+        None => (
+            target.value.as_ref(),
+            current.and_then(|x| x.value.as_ref()),
+        ),
+    };
+    let ratio = match (
+        current.and_then(quantity_value),
+        target.and_then(quantity_value),
+    ) {
+        (Some(current), Some(target)) if target > 0.0 => Some(current * 100.0 / target),
+        _ => None,
+    };
+    MetricValue {
+        name,
+        current: current.map(|x| x.0.clone()),
+        target: target.map_or("<none>".to_string(), |x| x.0.clone()),
+        ratio,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::{
+        api::autoscaling::v2::{
+            CrossVersionObjectReference, MetricIdentifier, PodsMetricSource, PodsMetricStatus,
+            ResourceMetricSource, ResourceMetricStatus,
+        },
+        apimachinery::pkg::api::resource::Quantity,
+    };
+    use kube::core::Object;
+
+    use super::*;
+
+    #[test]
+    fn test_hpa_metrics() {
+        let requests = MetricIdentifier {
+            name: "requests".to_string(),
+            selector: None,
+        };
+        let hpa = RtObject(Object {
+            types: None,
+            metadata: Default::default(),
+            spec: HorizontalPodAutoscalerSpec {
+                scale_target_ref: CrossVersionObjectReference {
+                    kind: "Deployment".to_string(),
+                    name: "web".to_string(),
+                    api_version: Some("apps/v1".to_string()),
+                },
+                max_replicas: 10,
+                metrics: Some(vec![
+                    MetricSpec {
+                        type_: "Resource".to_string(),
+                        resource: Some(ResourceMetricSource {
+                            name: "cpu".to_string(),
+                            target: MetricTarget {
+                                type_: "Utilization".to_string(),
+                                average_utilization: Some(80),
+                                ..Default::default()
+                            },
+                        }),
+                        ..Default::default()
+                    },
+                    MetricSpec {
+                        type_: "Resource".to_string(),
+                        resource: Some(ResourceMetricSource {
+                            name: "memory".to_string(),
+                            target: MetricTarget {
+                                type_: "AverageValue".to_string(),
+                                average_value: Some(Quantity("1Gi".to_string())),
+                                ..Default::default()
+                            },
+                        }),
+                        ..Default::default()
+                    },
+                    MetricSpec {
+                        type_: "Pods".to_string(),
+                        pods: Some(PodsMetricSource {
+                            metric: requests.clone(),
+                            target: MetricTarget {
+                                type_: "AverageValue".to_string(),
+                                average_value: Some(Quantity("10".to_string())),
+                                ..Default::default()
+                            },
+                        }),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            },
+            status: Some(HorizontalPodAutoscalerStatus {
+                current_replicas: Some(3),
+                desired_replicas: 4,
+                current_metrics: Some(vec![
+                    MetricStatus {
+                        type_: "Pods".to_string(),
+                        pods: Some(PodsMetricStatus {
+                            metric: requests,
+                            current: MetricValueStatus {
+                                average_value: Some(Quantity("15".to_string())),
+                                ..Default::default()
+                            },
+                        }),
+                        ..Default::default()
+                    },
+                    MetricStatus {
+                        type_: "Resource".to_string(),
+                        resource: Some(ResourceMetricStatus {
+                            name: "cpu".to_string(),
+                            current: MetricValueStatus {
+                                average_utilization: Some(40),
+                                ..Default::default()
+                            },
+                        }),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+        });
+
+        let metrics = hpa_metrics(&hpa);
+        assert_eq!(
+            vec![Some(50.0), None, Some(150.0)],
+            metrics.iter().map(|x| x.ratio).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "cpu: 40%/80%, memory: <unknown>/1Gi, pods requests: 15/10",
+            targets_summary(&hpa)
+        );
+        assert_eq!("Deployment/web", scale_target(&hpa));
+
+        let sample = HpaSample::new(&hpa);
+        assert_eq!((3, 4), (sample.current, sample.desired));
+        let later = HpaSample {
+            time: sample.time + 15,
+            ..sample.clone()
+        };
+        assert!(sample.same_values(&later));
+
+        assert_eq!(
+            vec![(-60.0, 2.0), (-30.0, 2.0), (-30.0, 4.0), (0.0, 4.0)],
+            step_points(&[(-60.0, 2.0), (-30.0, 4.0)], 0.0)
+        );
+        assert!(step_points(&[], 0.0).is_empty());
+    }
+}
@@ -0,0 +1,246 @@
+use std::{fmt::Debug, rc::Rc};
+
+use chrono::Utc;
+use k8s_openapi::{
+    api::{
+        batch::v1::{CronJobSpec, CronJobStatus, Job, JobSpec, JobStatus},
+        core::v1::{PodSpec, PodStatus},
+    },
+    apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference},
+};
+use kube::{
+    api::{Patch, PatchParams, PostParams},
+    Api, Client as KubeClient,
+};
+use serde::de::DeserializeOwned;
+
+use crate::kubernetes::api::object::RtObject;
+
+pub type JobObject = RtObject<JobSpec, JobStatus>;
+pub type CronJobObject = RtObject<CronJobSpec, CronJobStatus>;
+type PodObject = RtObject<PodSpec, PodStatus>;
+
+// same annotation kubectl create job --from sets
+const MANUAL_ANNOTATION: &str = "cronjob.kubernetes.io/instantiate";
+// object names are dns labels
+const MAX_NAME_LENGTH: usize = 63;
+
+// Complete, Failed, Suspended or Running, from the job conditions
+pub fn job_status(job: &JobObject) -> &'static str {
+    let conditions = job
+        .0
+        .status
+        .as_ref()
+        .and_then(|status| status.conditions.as_deref())
+        .unwrap_or_default();
+    let holds = |type_: &str| {
+        conditions
+            .iter()
+            .any(|x| x.type_ == type_ && x.status == "True")
+    };
+    if holds("Complete") {
+        "Complete"
+    } else if holds("Failed") {
+        "Failed"
+    } else if holds("Suspended") {
+        "Suspended"
+    } else {
+        "Running"
+    }
+}
+
+// succeeded/completions like kubectl, completions defaults to 1
+pub fn job_completions(job: &JobObject) -> String {
+    let succeeded = job
+        .0
+        .status
+        .as_ref()
+        .and_then(|status| status.succeeded)
+        .unwrap_or(0);
+    format!("{}/{}", succeeded, job.0.spec.completions.unwrap_or(1))
+}
+
+#[inline]
+pub fn job_failures(job: &JobObject) -> i32 {
+    job.0
+        .status
+        .as_ref()
+        .and_then(|status| status.failed)
+        .unwrap_or(0)
+}
+
+// from start to completion, a running job counts until now
+pub fn job_duration(job: &JobObject) -> String {
+    let status = job.0.status.as_ref();
+    let Some(start) = status.and_then(|x| x.start_time.as_ref()) else {
+        return "-".to_string();
+    };
+    let end = status
+        .and_then(|x| x.completion_time.as_ref())
+        .map_or(Utc::now(), |x| x.0);
+    let seconds = (end - start.0).num_seconds().max(0);
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m{}s", seconds / 60, seconds % 60),
+        _ => format!("{}h{}m", seconds / 3600, seconds % 3600 / 60),
+    }
+}
+
+#[inline]
+pub fn is_suspended(cronjob: &CronJobObject) -> bool {
+    cronjob.0.spec.suspend.unwrap_or(false)
+}
+
+#[inline]
+pub fn cronjob_active(cronjob: &CronJobObject) -> usize {
+    cronjob
+        .0
+        .status
+        .as_ref()
+        .and_then(|status| status.active.as_ref())
+        .map_or(0, |active| active.len())
+}
+
+#[inline]
+fn is_owned_by(meta: &ObjectMeta, owner: &ObjectMeta) -> bool {
+    meta.owner_references
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .any(|x| Some(&x.uid) == owner.uid.as_ref())
+}
+
+// jobs created by the cronjob, the most recent run first
+pub fn cronjob_history<'a>(
+    cronjob: &CronJobObject,
+    jobs: impl Iterator<Item = &'a Rc<JobObject>>,
+) -> Vec<Rc<JobObject>> {
+    let mut history = jobs
+        .filter(|job| is_owned_by(&job.0.metadata, &cronjob.0.metadata))
+        .cloned()
+        .collect::<Vec<Rc<JobObject>>>();
+    history.sort_by_key(|job| std::cmp::Reverse(job.0.metadata.creation_timestamp.clone()));
+    history
+}
+
+// newest pod of the job, the one whose logs tell why the last run went wrong
+pub fn latest_job_pod<'a>(
+    job: &JobObject,
+    pods: impl Iterator<Item = &'a Rc<PodObject>>,
+) -> Option<Rc<PodObject>> {
+    pods.filter(|pod| is_owned_by(&pod.0.metadata, &job.0.metadata))
+        .max_by_key(|pod| pod.0.metadata.creation_timestamp.clone())
+        .cloned()
+}
+
+// create a job from the job template of the cronjob, like kubectl create job --from=cronjob/x
+pub async fn run_now(kube_client: KubeClient, cronjob: &CronJobObject) -> kube::Result<String> {
+    let namespace = cronjob.0.metadata.namespace.as_deref().unwrap_or_default();
+    let cronjob_name = cronjob.0.metadata.name.as_deref().unwrap_or_default();
+    let template = &cronjob.0.spec.job_template;
+    let template_meta = template.metadata.clone().unwrap_or_default();
+
+    let mut annotations = template_meta.annotations.unwrap_or_default();
+    annotations.insert(MANUAL_ANNOTATION.to_string(), "manual".to_string());
+    let owner = OwnerReference {
+        api_version: "batch/v1".to_string(),
+        kind: "CronJob".to_string(),
+        name: cronjob_name.to_string(),
+        uid: cronjob.0.metadata.uid.clone().unwrap_or_default(),
+        controller: Some(true),
+        block_owner_deletion: Some(true),
+    };
+    let job = Job {
+        metadata: ObjectMeta {
+            name: Some(manual_job_name(
+                cronjob_name,
+                &Utc::now().format("%m%d%H%M%S").to_string(),
+            )),
+            namespace: Some(namespace.to_string()),
+            labels: template_meta.labels,
+            annotations: Some(annotations),
+            owner_references: Some(vec![owner]),
+            ..Default::default()
+        },
+        spec: template.spec.clone(),
+        status: None,
+    };
+    let api: Api<Job> = Api::namespaced(kube_client, namespace);
+    let job = api.create(&PostParams::default(), &job).await?;
+    Ok(job.metadata.name.unwrap_or_default())
+}
+
+// <cronjob>-manual-<suffix>, a long cronjob name is cut so the job name stays a valid label
+fn manual_job_name(cronjob_name: &str, suffix: &str) -> String {
+    let suffix = format!("-manual-{}", suffix);
+    let keep = MAX_NAME_LENGTH.saturating_sub(suffix.len());
+    let prefix = cronjob_name.get(..keep).unwrap_or(cronjob_name);
+    format!("{}{}", prefix.trim_end_matches(['-', '.']), suffix)
+}
+
+// spec.suspend exists on cronjobs and jobs, a suspended job deletes its running pods
+pub async fn set_suspend<K>(api: Api<K>, name: &str, suspend: bool) -> kube::Result<()>
+where
+    K: Clone + DeserializeOwned + Debug,
+{
+    let patch = serde_json::json!({ "spec": { "suspend": suspend } });
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::api::batch::v1::JobCondition;
+
+    use super::*;
+
+    #[test]
+    fn test_job_status() {
+        let condition = |type_: &str, status: &str| JobCondition {
+            type_: type_.to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        };
+        let job = |conditions| {
+            JobObject::from(Job {
+                spec: Some(JobSpec {
+                    completions: Some(3),
+                    ..Default::default()
+                }),
+                status: Some(JobStatus {
+                    succeeded: Some(2),
+                    conditions: Some(conditions),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+        };
+        assert_eq!("Running", job_status(&job(vec![])));
+        assert_eq!("2/3", job_completions(&job(vec![])));
+        assert_eq!(
+            "Failed",
+            job_status(&job(vec![
+                condition("Complete", "False"),
+                condition("Failed", "True")
+            ]))
+        );
+        assert_eq!(
+            "Suspended",
+            job_status(&job(vec![condition("Suspended", "True")]))
+        );
+
+        assert_eq!(
+            "backup-manual-1019120000",
+            manual_job_name("backup", "1019120000")
+        );
+        let name = manual_job_name(&"a".repeat(52), "1019120000");
+        assert_eq!(MAX_NAME_LENGTH, name.len());
+        // the cut must not leave a dash before -manual-
+        let name = manual_job_name(
+            &format!("{}-{}", "a".repeat(44), "b".repeat(7)),
+            "1019120000",
+        );
+        assert_eq!(format!("{}-manual-1019120000", "a".repeat(44)), name);
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::{
+    api::core::v1::{PodSpec, PodStatus},
+    apimachinery::pkg::api::resource::Quantity,
+};
+
+use crate::kubernetes::{api::object::RtObject, metrics::pod::PodMetrics};
+
+type PodObject = RtObject<PodSpec, PodStatus>;
+
+// usage above this share of the limit is flagged
+const NEAR_LIMIT_PERCENT: f64 = 90.0;
+// usage below this share of the request is over-provisioned
+const OVER_PROVISIONED_PERCENT: f64 = 50.0;
+
+const SUFFIXES: [(&str, f64); 15] = [
+    ("Ki", 1024.0),
+    ("Mi", 1048576.0),
+    ("Gi", 1073741824.0),
+    ("Ti", 1099511627776.0),
+    ("Pi", 1125899906842624.0),
+    ("Ei", 1152921504606846976.0),
+    ("n", 1e-9),
+    ("u", 1e-6),
+    ("m", 1e-3),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+    ("T", 1e12),
+    ("P", 1e15),
+    ("E", 1e18),
+];
+
+// quantity as plain number, cores for cpu and bytes for memory
+pub fn quantity_value(quantity: &Quantity) -> Option<f64> {
+    let value = quantity.0.trim();
+    for (suffix, factor) in SUFFIXES {
+        if let Some(number) = value.strip_suffix(suffix) {
+            if let Ok(number) = number.parse::<f64>() {
+                return Some(number * factor);
+            }
+        }
+    }
+    // plain numbers and the exponent form, e.g. 1e3
+    value.parse::<f64>().ok()
+}
+
+pub fn format_cpu(cores: f64) -> String {
+    format!("{:.0}m", cores * 1000.0)
+}
+
+pub fn format_memory(bytes: f64) -> String {
+    match bytes {
+        x if x >= 1073741824.0 => format!("{:.1}Gi", x / 1073741824.0),
+        x if x >= 1048576.0 => format!("{:.0}Mi", x / 1048576.0),
+        x => format!("{:.0}Ki", x / 1024.0),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sizing {
+    Over,
+    Under,
+    Fit,
+    // no request, the scheduler cannot account for it
+    Unset,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Utilization {
+    pub used: f64,
+    pub request: Option<f64>,
+    pub limit: Option<f64>,
+}
+
+impl Utilization {
+    #[inline]
+    pub fn request_percent(&self) -> Option<f64> {
+        percent(self.used, self.request)
+    }
+
+    #[inline]
+    pub fn limit_percent(&self) -> Option<f64> {
+        percent(self.used, self.limit)
+    }
+
+    // for cpu this suggests throttling, metrics-server has no cfs counters but usage
+    // pinned at the limit is what the quota allows at most
+    pub fn near_limit(&self) -> bool {
+        self.limit_percent()
+            .is_some_and(|x| x >= NEAR_LIMIT_PERCENT)
+    }
+
+    pub fn sizing(&self) -> Sizing {
+        match self.request_percent() {
+            None => Sizing::Unset,
+            Some(x) if x < OVER_PROVISIONED_PERCENT => Sizing::Over,
+            Some(x) if x > 100.0 => Sizing::Under,
+            Some(_) => Sizing::Fit,
+        }
+    }
+
+    // how many times usage and request are apart, used to rank opportunities
+    fn deviation(&self) -> f64 {
+        let request = self.request.unwrap_or_default();
+        if self.used <= 0.0 || request <= 0.0 {
+            return f64::INFINITY;
+        }
+        (self.used / request).max(request / self.used)
+    }
+}
+
+#[inline]
+fn percent(used: f64, total: Option<f64>) -> Option<f64> {
+    total.filter(|x| *x > 0.0).map(|x| used / x * 100.0)
+}
+
+pub struct ContainerUsage {
+    pub name: String,
+    pub cpu: Utilization,
+    pub memory: Utilization,
+}
+
+// containers of the pod with a metrics sample, joined with their spec resources
+pub fn container_usage(pod: &PodObject, metrics: &PodMetrics) -> Vec<ContainerUsage> {
+    pod.0
+        .spec
+        .containers
+        .iter()
+        .filter_map(|container| {
+            let sample = metrics
+                .containers
+                .iter()
+                .find(|x| x.name == container.name)?;
+            let resources = container.resources.as_ref();
+            let requests = resources.and_then(|x| x.requests.as_ref());
+            let limits = resources.and_then(|x| x.limits.as_ref());
+            let utilization = |key: &str, used: &Quantity| Utilization {
+                used: quantity_value(used).unwrap_or_default(),
+                request: resource_value(requests, key),
+                limit: resource_value(limits, key),
+            };
+            Some(ContainerUsage {
+                name: container.name.clone(),
+                cpu: utilization("cpu", &sample.usage.cpu),
+                memory: utilization("memory", &sample.usage.memory),
+            })
+        })
+        .collect()
+}
+
+#[inline]
+fn resource_value(resources: Option<&BTreeMap<String, Quantity>>, key: &str) -> Option<f64> {
+    resources.and_then(|x| x.get(key)).and_then(quantity_value)
+}
+
+pub struct Opportunity {
+    pub pod: String,
+    pub container: String,
+    pub resource: &'static str,
+    pub utilization: Utilization,
+    pub sizing: Sizing,
+}
+
+// over- and under-provisioned containers of the given pods, the furthest off first
+pub fn rightsizing<'a>(
+    pods: impl Iterator<Item = (&'a PodObject, &'a PodMetrics)>,
+) -> Vec<Opportunity> {
+    let mut result = Vec::new();
+    for (pod, metrics) in pods {
+        let pod_name = pod.0.metadata.name.clone().unwrap_or_default();
+        for usage in container_usage(pod, metrics) {
+            for (resource, utilization) in [("cpu", usage.cpu), ("memory", usage.memory)] {
+                let sizing = utilization.sizing();
+                if matches!(sizing, Sizing::Over | Sizing::Under) {
+                    result.push(Opportunity {
+                        pod: pod_name.clone(),
+                        container: usage.name.clone(),
+                        resource,
+                        utilization,
+                        sizing,
+                    });
+                }
+            }
+        }
+    }
+    result.sort_by(|a, b| {
+        b.utilization
+            .deviation()
+            .total_cmp(&a.utilization.deviation())
+    });
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantity_and_sizing() {
+        let value = |x: &str| quantity_value(&Quantity(x.to_string())).unwrap();
+        assert_eq!(0.25, value("250m"));
+        assert_eq!(2.0, value("2"));
+        assert_eq!(134217728.0, value("128Mi"));
+        assert_eq!(1e3, value("1e3"));
+        assert_eq!(1e18, value("1E"));
+        assert!((value("1500000n") - 0.0015).abs() < 1e-12);
+
+        let utilization = |used, request, limit| Utilization {
+            used,
+            request,
+            limit,
+        };
+        assert_eq!(Sizing::Over, utilization(0.1, Some(1.0), None).sizing());
+        assert_eq!(Sizing::Under, utilization(1.5, Some(1.0), None).sizing());
+        assert_eq!(Sizing::Fit, utilization(0.8, Some(1.0), None).sizing());
+        assert_eq!(Sizing::Unset, utilization(0.8, None, None).sizing());
+        assert!(utilization(0.95, None, Some(1.0)).near_limit());
+        assert_eq!(10.0, utilization(0.1, Some(1.0), None).deviation());
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Local, Utc};
+use color_eyre::eyre::Result;
+use k8s_openapi::api::core::v1::{Event, NodeSpec, NodeStatus, PodSpec, PodStatus};
+use kube::{api::ListParams, Api, Client};
+
+use crate::kubernetes::{
+    api::{capacity::quantity_value, object::RtObject},
+    metrics::pod::PodMetrics,
+};
+
+type PodObject = RtObject<PodSpec, PodStatus>;
+type NodeObject = RtObject<NodeSpec, NodeStatus>;
+
+const MAX_WARNINGS: usize = 50;
+// conditions that are only worth counting when True
+const PRESSURE_CONDITIONS: [&str; 4] = [
+    "MemoryPressure",
+    "DiskPressure",
+    "PIDPressure",
+    "NetworkUnavailable",
+];
+
+// ClusterInfo is what the overview polls from the api server, the rest comes from reflectors
+#[derive(Default)]
+pub struct ClusterInfo {
+    pub version: Option<String>,
+    // body of /readyz or the error of the request
+    pub health: Option<std::result::Result<String, String>>,
+    pub warnings: Vec<Event>,
+    pub checked: Option<DateTime<Local>>,
+}
+
+pub async fn server_version(client: &Client) -> Result<String> {
+    Ok(client.apiserver_version().await?.git_version)
+}
+
+pub async fn server_health(client: &Client) -> Result<String> {
+    let request = http::Request::get("/readyz").body(Vec::new())?;
+    Ok(client.request_text(request).await?.trim().to_string())
+}
+
+// Warning events of all namespaces, newest first
+pub async fn warning_events(client: &Client) -> Result<Vec<Event>> {
+    let api = Api::<Event>::all(client.clone());
+    let mut events = api
+        .list(&ListParams::default().fields("type=Warning"))
+        .await?
+        .items;
+    events.sort_by_key(|event| std::cmp::Reverse(event_time(event)));
+    events.truncate(MAX_WARNINGS);
+    Ok(events)
+}
+
+// events carry one of three timestamps depending on the reporter
+pub fn event_time(event: &Event) -> Option<DateTime<Utc>> {
+    event
+        .last_timestamp
+        .as_ref()
+        .map(|x| x.0)
+        .or(event.event_time.as_ref().map(|x| x.0))
+        .or(event.metadata.creation_timestamp.as_ref().map(|x| x.0))
+}
+
+// Ready / NotReady plus SchedulingDisabled and every pressure condition that is True
+pub fn node_conditions<'a>(nodes: impl Iterator<Item = &'a NodeObject>) -> BTreeMap<String, usize> {
+    let mut result = BTreeMap::new();
+    for node in nodes {
+        let conditions = node
+            .0
+            .status
+            .as_ref()
+            .and_then(|x| x.conditions.as_deref())
+            .unwrap_or_default();
+        let ready = conditions
+            .iter()
+            .any(|x| x.type_ == "Ready" && x.status == "True");
+        let mut keys = vec![if ready { "Ready" } else { "NotReady" }];
+        if node.0.spec.unschedulable.unwrap_or(false) {
+            keys.push("SchedulingDisabled");
+        }
+        keys.extend(
+            conditions
+                .iter()
+                .filter(|x| x.status == "True")
+                .filter_map(|x| PRESSURE_CONDITIONS.iter().find(|y| **y == x.type_)),
+        );
+        for key in keys {
+            *result.entry(key.to_string()).or_insert(0) += 1;
+        }
+    }
+    result
+}
+
+// status like the STATUS column of kubectl get pods, e.g. CrashLoopBackOff or Terminating
+pub fn pod_status(pod: &PodObject) -> String {
+    if pod.0.metadata.deletion_timestamp.is_some() {
+        return "Terminating".to_string();
+    }
+    let Some(status) = pod.0.status.as_ref() else {
+        return "Unknown".to_string();
+    };
+    let reason = status
+        .container_statuses
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|x| x.state.as_ref())
+        .find_map(|state| {
+            state
+                .waiting
+                .as_ref()
+                .and_then(|x| x.reason.clone())
+                .or(state.terminated.as_ref().and_then(|x| x.reason.clone()))
+        });
+    reason
+        .or(status.reason.clone())
+        .or(status.phase.clone())
+        .unwrap_or("Unknown".to_string())
+}
+
+pub struct NamespaceSummary {
+    pub namespace: String,
+    pub pods: usize,
+    // cores and bytes summed over the latest metrics of its pods
+    pub cpu: f64,
+    pub memory: f64,
+}
+
+// namespaces ordered by pod count, then by name
+pub fn namespace_summaries<'a>(
+    pods: impl Iterator<Item = &'a PodObject>,
+    usage: &HashMap<String, HashMap<String, PodMetrics>>,
+) -> Vec<NamespaceSummary> {
+    let mut counts = BTreeMap::<&str, usize>::new();
+    for pod in pods {
+        let namespace = pod.0.metadata.namespace.as_deref().unwrap_or_default();
+        *counts.entry(namespace).or_insert(0) += 1;
+    }
+    let mut result = counts
+        .into_iter()
+        .map(|(namespace, pods)| {
+            let containers = usage
+                .get(namespace)
+                .into_iter()
+                .flat_map(|x| x.values())
+                .flat_map(|x| x.containers.iter());
+            let (mut cpu, mut memory) = (0.0, 0.0);
+            for container in containers {
+                cpu += quantity_value(&container.usage.cpu).unwrap_or_default();
+                memory += quantity_value(&container.usage.memory).unwrap_or_default();
+            }
+            NamespaceSummary {
+                namespace: namespace.to_string(),
+                pods,
+                cpu,
+                memory,
+            }
+        })
+        .collect::<Vec<NamespaceSummary>>();
+    result.sort_by_key(|x| std::cmp::Reverse(x.pods));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::{
+        api::core::v1::{ContainerState, ContainerStateWaiting, ContainerStatus, Pod},
+        apimachinery::pkg::apis::meta::v1::Time,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_pod_status() {
+        let mut pod = Pod {
+            spec: Some(PodSpec::default()),
+            status: Some(PodStatus {
+                phase: Some("Running".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!("Running", pod_status(&PodObject::from(pod.clone())));
+
+        pod.status.as_mut().unwrap().container_statuses = Some(vec![ContainerStatus {
+            state: Some(ContainerState {
+                waiting: Some(ContainerStateWaiting {
+                    reason: Some("CrashLoopBackOff".to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }]);
+        assert_eq!(
+            "CrashLoopBackOff",
+            pod_status(&PodObject::from(pod.clone()))
+        );
+
+        pod.metadata.deletion_timestamp = Some(Time(Utc::now()));
+        assert_eq!("Terminating", pod_status(&PodObject::from(pod)));
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use k8s_openapi::api::core::v1::{ConfigMap, Container, PodSpec, PodStatus, Secret};
+use kube::core::{Object, TypeMeta};
+
+use crate::kubernetes::api::object::RtObject;
+
+const HEXDUMP_WIDTH: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfigKind {
+    ConfigMap,
+    Secret,
+}
+
+// ConfigData is the shared spec of ConfigMap and Secret, secret values are already
+// base64 decoded by ByteString deserialization
+#[derive(Clone, Debug)]
+pub struct ConfigData {
+    pub kind: ConfigKind,
+    // secret type, e.g. kubernetes.io/tls
+    pub type_: Option<String>,
+    pub entries: BTreeMap<String, Vec<u8>>,
+}
+
+pub type ConfigObject = RtObject<ConfigData, ()>;
+type PodObject = RtObject<PodSpec, PodStatus>;
+
+impl From<ConfigMap> for ConfigObject {
+    fn from(value: ConfigMap) -> Self {
+        let mut entries = value
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| (key, value.into_bytes()))
+            .collect::<BTreeMap<String, Vec<u8>>>();
+        entries.extend(
+            value
+                .binary_data
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| (key, value.0)),
+        );
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: "v1".to_string(),
+                kind: "ConfigMap".to_string(),
+            }),
+            metadata: value.metadata,
+            spec: ConfigData {
+                kind: ConfigKind::ConfigMap,
+                type_: None,
+                entries,
+            },
+            status: None,
+        })
+    }
+}
+
+impl From<Secret> for ConfigObject {
+    fn from(value: Secret) -> Self {
+        let entries = value
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| (key, value.0))
+            .collect();
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: "v1".to_string(),
+                kind: "Secret".to_string(),
+            }),
+            metadata: value.metadata,
+            spec: ConfigData {
+                kind: ConfigKind::Secret,
+                type_: value.type_,
+                entries,
+            },
+            status: None,
+        })
+    }
+}
+
+// value as text, None when it is not utf8 and has to be shown as hexdump
+#[inline]
+pub fn text_value(value: &[u8]) -> Option<&str> {
+    std::str::from_utf8(value).ok()
+}
+
+// 00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|
+pub fn hexdump(value: &[u8]) -> Vec<String> {
+    value
+        .chunks(HEXDUMP_WIDTH)
+        .enumerate()
+        .map(|(idx, chunk)| {
+            let hex = (0..HEXDUMP_WIDTH)
+                .map(|i| {
+                    let byte = chunk
+                        .get(i)
+                        .map_or("  ".to_string(), |b| format!("{:02x}", b));
+                    if i == HEXDUMP_WIDTH / 2 {
+                        format!(" {}", byte)
+                    } else {
+                        byte
+                    }
+                })
+                .collect::<Vec<String>>()
+                .join(" ");
+            let ascii = chunk
+                .iter()
+                .map(|b| {
+                    if b.is_ascii_graphic() || *b == b' ' {
+                        *b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            format!("{:08x}  {}  |{}|", idx * HEXDUMP_WIDTH, hex, ascii)
+        })
+        .collect()
+}
+
+// how every pod in the namespace of the object refers to it, (pod, "volume data", "env DB_HOST", ...)
+pub fn referencing_pods<'a>(
+    config: &ConfigObject,
+    pods: impl Iterator<Item = &'a std::rc::Rc<PodObject>>,
+) -> Vec<(String, String)> {
+    let namespace = config.0.metadata.namespace.as_deref();
+    let name = config.0.metadata.name.as_deref().unwrap_or_default();
+    let kind = config.0.spec.kind;
+
+    let mut result = Vec::new();
+    for pod in pods.filter(|pod| pod.0.metadata.namespace.as_deref() == namespace) {
+        let pod_name = pod.0.metadata.name.clone().unwrap_or_default();
+        for usage in pod_references(&pod.0.spec, kind, name) {
+            result.push((pod_name.clone(), usage));
+        }
+    }
+    result.sort();
+    result
+}
+
+fn pod_references(spec: &PodSpec, kind: ConfigKind, name: &str) -> Vec<String> {
+    let mut usages = Vec::new();
+    for volume in spec.volumes.as_deref().unwrap_or_default() {
+        let mounted = match kind {
+            ConfigKind::ConfigMap => volume
+                .config_map
+                .as_ref()
+                .is_some_and(|x| x.name.as_deref() == Some(name)),
+            ConfigKind::Secret => volume
+                .secret
+                .as_ref()
+                .is_some_and(|x| x.secret_name.as_deref() == Some(name)),
+        };
+        let projected = volume
+            .projected
+            .as_ref()
+            .and_then(|x| x.sources.as_ref())
+            .is_some_and(|sources| {
+                sources.iter().any(|source| match kind {
+                    ConfigKind::ConfigMap => source
+                        .config_map
+                        .as_ref()
+                        .is_some_and(|x| x.name.as_deref() == Some(name)),
+                    ConfigKind::Secret => source
+                        .secret
+                        .as_ref()
+                        .is_some_and(|x| x.name.as_deref() == Some(name)),
+                })
+            });
+        if mounted || projected {
+            usages.push(format!("volume {}", volume.name));
+        }
+    }
+
+    let containers = spec
+        .init_containers
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .chain(spec.containers.iter());
+    for container in containers {
+        usages.extend(container_references(container, kind, name));
+    }
+    if kind == ConfigKind::Secret {
+        let pull_secret = spec
+            .image_pull_secrets
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .any(|x| x.name.as_deref() == Some(name));
+        if pull_secret {
+            usages.push("imagePullSecrets".to_string());
+        }
+    }
+    usages
+}
+
+fn container_references(container: &Container, kind: ConfigKind, name: &str) -> Vec<String> {
+    let mut usages = Vec::new();
+    for env in container.env.as_deref().unwrap_or_default() {
+        let Some(source) = env.value_from.as_ref() else {
+            continue;
+        };
+        let referenced = match kind {
+            ConfigKind::ConfigMap => source
+                .config_map_key_ref
+                .as_ref()
+                .is_some_and(|x| x.name.as_deref() == Some(name)),
+            ConfigKind::Secret => source
+                .secret_key_ref
+                .as_ref()
+                .is_some_and(|x| x.name.as_deref() == Some(name)),
+        };
+        if referenced {
+            usages.push(format!("env {} ({})", env.name, container.name));
+        }
+    }
+    for env_from in container.env_from.as_deref().unwrap_or_default() {
+        let referenced = match kind {
+            ConfigKind::ConfigMap => env_from
+                .config_map_ref
+                .as_ref()
+                .is_some_and(|x| x.name.as_deref() == Some(name)),
+            ConfigKind::Secret => env_from
+                .secret_ref
+                .as_ref()
+                .is_some_and(|x| x.name.as_deref() == Some(name)),
+        };
+        if referenced {
+            usages.push(format!("envFrom ({})", container.name));
+        }
+    }
+    usages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexdump() {
+        let lines = hexdump(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR\x01");
+        assert_eq!(2, lines.len());
+        assert_eq!(
+            "00000000  89 50 4e 47 0d 0a 1a 0a  00 00 00 0d 49 48 44 52  |.PNG........IHDR|",
+            lines[0]
+        );
+        assert!(lines[1].starts_with("00000010  01    "));
+        assert!(lines[1].ends_with("|.|"));
+    }
+}
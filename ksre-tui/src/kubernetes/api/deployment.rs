@@ -0,0 +1,272 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use chrono::Utc;
+use k8s_openapi::api::{
+    apps::v1::{Deployment, DeploymentSpec, DeploymentStatus, ReplicaSetSpec, ReplicaSetStatus},
+    core::v1::PodTemplateSpec,
+};
+use kube::{
+    api::{Patch, PatchParams, PostParams},
+    Api, Client as KubeClient,
+};
+
+use crate::kubernetes::api::object::RtObject;
+
+pub type DeploymentObject = RtObject<DeploymentSpec, DeploymentStatus>;
+pub type ReplicaSetObject = RtObject<ReplicaSetSpec, ReplicaSetStatus>;
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+const CHANGE_CAUSE_ANNOTATION: &str = "kubernetes.io/change-cause";
+// same annotation kubectl rollout restart sets
+const RESTARTED_AT_ANNOTATION: &str = "kubectl.kubernetes.io/restartedAt";
+// added by the deployment controller, it must not be copied back into the deployment
+const POD_TEMPLATE_HASH_LABEL: &str = "pod-template-hash";
+
+// Revision is one replicaset of a deployment, as kubectl rollout history lists it
+pub struct Revision {
+    pub number: i64,
+    pub replicaset: Rc<ReplicaSetObject>,
+    pub change_cause: Option<String>,
+    // container=image changes against the previous revision
+    pub image_diff: Vec<String>,
+}
+
+// replicasets controlled by the deployment, the newest revision first
+pub fn rollout_history<'a>(
+    deployment: &DeploymentObject,
+    replicasets: impl Iterator<Item = &'a Rc<ReplicaSetObject>>,
+) -> Vec<Revision> {
+    let mut owned = replicasets
+        .filter(|rs| {
+            rs.0.metadata
+                .owner_references
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .any(|x| {
+                    x.controller.unwrap_or(false)
+                        && Some(&x.uid) == deployment.0.metadata.uid.as_ref()
+                })
+        })
+        .filter_map(|rs| Some((revision_number(&rs.0.metadata.annotations)?, rs)))
+        .collect::<Vec<(i64, &Rc<ReplicaSetObject>)>>();
+    owned.sort_by_key(|(number, _)| *number);
+
+    let mut history = Vec::with_capacity(owned.len());
+    let mut previous: Vec<(String, String)> = Vec::new();
+    for (number, rs) in owned {
+        let images = container_images(rs.0.spec.template.as_ref());
+        history.push(Revision {
+            number,
+            replicaset: rs.clone(),
+            change_cause: rs
+                .0
+                .metadata
+                .annotations
+                .as_ref()
+                .and_then(|x| x.get(CHANGE_CAUSE_ANNOTATION).cloned()),
+            image_diff: image_diff(&previous, &images),
+        });
+        previous = images;
+    }
+    history.reverse();
+    history
+}
+
+// revision the deployment runs now, the controller mirrors it from the newest replicaset
+#[inline]
+pub fn current_revision(deployment: &DeploymentObject) -> Option<i64> {
+    revision_number(&deployment.0.metadata.annotations)
+}
+
+#[inline]
+fn revision_number(annotations: &Option<BTreeMap<String, String>>) -> Option<i64> {
+    annotations.as_ref()?.get(REVISION_ANNOTATION)?.parse().ok()
+}
+
+fn container_images(template: Option<&PodTemplateSpec>) -> Vec<(String, String)> {
+    template
+        .and_then(|x| x.spec.as_ref())
+        .map(|spec| {
+            spec.containers
+                .iter()
+                .map(|x| (x.name.clone(), x.image.clone().unwrap_or_default()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// one entry per container whose image changed, the first revision lists every image
+pub fn image_diff(before: &[(String, String)], after: &[(String, String)]) -> Vec<String> {
+    after
+        .iter()
+        .filter_map(
+            |(name, image)| match before.iter().find(|(x, _)| x == name) {
+                Some((_, old)) if old == image => None,
+                Some((_, old)) => Some(format!("{}: {} -> {}", name, old, image)),
+                None => Some(format!("{}: {}", name, image)),
+            },
+        )
+        .collect()
+}
+
+// RolloutProgress is the replica counts of a deployment that has not finished rolling out
+pub struct RolloutProgress {
+    pub desired: i32,
+    pub updated: i32,
+    pub ready: i32,
+    pub available: i32,
+    // replicas of older revisions still running
+    pub old: i32,
+    // set when the controller gave up, e.g. ProgressDeadlineExceeded
+    pub stalled: Option<String>,
+}
+
+// None once the controller observed the latest spec and every replica is updated and available
+pub fn rollout_progress(deployment: &DeploymentObject) -> Option<RolloutProgress> {
+    let status = deployment.0.status.as_ref()?;
+    let desired = deployment.0.spec.replicas.unwrap_or(1);
+    let updated = status.updated_replicas.unwrap_or(0);
+    let available = status.available_replicas.unwrap_or(0);
+    let total = status.replicas.unwrap_or(0);
+    let observed = status.observed_generation >= deployment.0.metadata.generation;
+    if observed && updated >= desired && available >= desired && total <= updated {
+        return None;
+    }
+    let stalled = status
+        .conditions
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .find(|x| x.type_ == "Progressing" && x.status == "False")
+        .map(|x| {
+            x.message
+                .clone()
+                .or(x.reason.clone())
+                .unwrap_or("progress stalled".to_string())
+        });
+    Some(RolloutProgress {
+        desired,
+        updated,
+        ready: status.ready_replicas.unwrap_or(0),
+        available,
+        old: (total - updated).max(0),
+        stalled,
+    })
+}
+
+// kubectl rollout restart, a new pod template annotation makes a new revision
+pub async fn restart(kube_client: KubeClient, namespace: &str, name: &str) -> kube::Result<()> {
+    let patch = serde_json::json!({
+        "spec": { "template": { "metadata": { "annotations": {
+            RESTARTED_AT_ANNOTATION: Utc::now().to_rfc3339()
+        }}}}
+    });
+    let api: Api<Deployment> = Api::namespaced(kube_client, namespace);
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map(|_| ())
+}
+
+pub async fn scale(
+    kube_client: KubeClient,
+    namespace: &str,
+    name: &str,
+    replicas: i32,
+) -> kube::Result<()> {
+    let patch = serde_json::json!({ "spec": { "replicas": replicas } });
+    let api: Api<Deployment> = Api::namespaced(kube_client, namespace);
+    api.patch(name, &PatchParams::default(), &Patch::Merge(&patch))
+        .await
+        .map(|_| ())
+}
+
+// kubectl rollout undo, the template of the replicaset replaces the one of the deployment.
+// replace instead of a merge patch, so fields added by later revisions are dropped
+pub async fn rollback(
+    kube_client: KubeClient,
+    namespace: &str,
+    name: &str,
+    replicaset: &ReplicaSetObject,
+) -> kube::Result<()> {
+    let mut template = replicaset.0.spec.template.clone().unwrap_or_default();
+    if let Some(labels) = template.metadata.as_mut().and_then(|x| x.labels.as_mut()) {
+        labels.remove(POD_TEMPLATE_HASH_LABEL);
+    }
+    let api: Api<Deployment> = Api::namespaced(kube_client, namespace);
+    let mut deployment = api.get(name).await?;
+    if let Some(spec) = deployment.spec.as_mut() {
+        spec.template = template;
+    }
+    api.replace(name, &PostParams::default(), &deployment)
+        .await
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::{
+        api::{
+            apps::v1::ReplicaSet,
+            core::v1::{Container, PodSpec},
+        },
+        apimachinery::pkg::apis::meta::v1::{ObjectMeta, OwnerReference},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_rollout_history() {
+        let replicaset = |revision: &str, image: &str| {
+            Rc::new(ReplicaSetObject::from(ReplicaSet {
+                metadata: ObjectMeta {
+                    annotations: Some(
+                        [(REVISION_ANNOTATION.to_string(), revision.to_string())].into(),
+                    ),
+                    owner_references: Some(vec![OwnerReference {
+                        uid: "deploy".to_string(),
+                        controller: Some(true),
+                        ..Default::default()
+                    }]),
+                    ..Default::default()
+                },
+                spec: Some(ReplicaSetSpec {
+                    template: Some(PodTemplateSpec {
+                        spec: Some(PodSpec {
+                            containers: vec![Container {
+                                name: "app".to_string(),
+                                image: Some(image.to_string()),
+                                ..Default::default()
+                            }],
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }))
+        };
+        let deployment = DeploymentObject::from(Deployment {
+            metadata: ObjectMeta {
+                uid: Some("deploy".to_string()),
+                ..Default::default()
+            },
+            spec: Some(DeploymentSpec::default()),
+            ..Default::default()
+        });
+        let replicasets = [
+            replicaset("10", "nginx:1.25"),
+            replicaset("2", "nginx:1.24"),
+            replicaset("3", "nginx:1.24"),
+        ];
+        let history = rollout_history(&deployment, replicasets.iter());
+        assert_eq!(
+            vec![10, 3, 2],
+            history.iter().map(|x| x.number).collect::<Vec<i64>>()
+        );
+        assert_eq!(vec!["app: nginx:1.24 -> nginx:1.25"], history[0].image_diff);
+        assert!(history[1].image_diff.is_empty());
+        assert_eq!(vec!["app: nginx:1.24"], history[2].image_diff);
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeSet;
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+// status fields that change on every restart or probe, they would drown the real differences.
+// last*Time keys of status are noise too. resourceVersion, uid and the rest of the metadata
+// never reach the diff, see comparable
+const STATUS_NOISE: [&str; 3] = ["startedAt", "finishedAt", "containerID"];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Change {
+    Added {
+        path: String,
+        value: String,
+    },
+    Removed {
+        path: String,
+        value: String,
+    },
+    Modified {
+        path: String,
+        old: String,
+        new: String,
+    },
+}
+
+// diff_objects compares labels, annotations, spec and status of two objects, e.g. two replicas
+// or two versions of one object. lists of named items are matched by name, not by position
+pub fn diff_objects<T: Serialize>(old: &T, new: &T) -> Vec<Change> {
+    let old = comparable(serde_json::to_value(old).unwrap_or_default());
+    let new = comparable(serde_json::to_value(new).unwrap_or_default());
+    let mut changes = Vec::new();
+    diff_value("", &old, &new, &mut changes);
+    changes
+}
+
+fn comparable(value: Value) -> Value {
+    let mut result = Map::new();
+    let metadata = value.get("metadata");
+    for key in ["labels", "annotations"] {
+        if let Some(x) = metadata.and_then(|x| x.get(key)) {
+            result.insert(key.to_string(), x.clone());
+        }
+    }
+    for key in ["spec", "status"] {
+        if let Some(x) = value.get(key) {
+            result.insert(key.to_string(), x.clone());
+        }
+    }
+    Value::Object(result)
+}
+
+fn diff_value(path: &str, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys = old.keys().chain(new.keys()).collect::<BTreeSet<_>>();
+            for key in keys.into_iter().filter(|x| !is_noise(path, x)) {
+                let path = join(path, key);
+                diff_entry(path, old.get(key), new.get(key), changes);
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => match (item_keys(old), item_keys(new)) {
+            (Some(old_keys), Some(new_keys)) => {
+                let mut keys = old_keys.clone();
+                keys.extend(new_keys.iter().filter(|x| !old_keys.contains(x)).cloned());
+                for key in keys {
+                    let path = format!("{}[{}]", path, key);
+                    diff_entry(path, find_item(old, &key), find_item(new, &key), changes);
+                }
+            }
+            _ => {
+                for index in 0..old.len().max(new.len()) {
+                    let path = format!("{}[{}]", path, index);
+                    diff_entry(path, old.get(index), new.get(index), changes);
+                }
+            }
+        },
+        _ if old != new => changes.push(Change::Modified {
+            path: path.to_string(),
+            old: render(old),
+            new: render(new),
+        }),
+        _ => {}
+    }
+}
+
+fn diff_entry(path: String, old: Option<&Value>, new: Option<&Value>, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Some(old), Some(new)) => diff_value(&path, old, new, changes),
+        (Some(old), None) => changes.push(Change::Removed {
+            path,
+            value: render(old),
+        }),
+        (None, Some(new)) => changes.push(Change::Added {
+            path,
+            value: render(new),
+        }),
+        (None, None) => {}
+    }
+}
+
+// containers, env, ports are keyed by name, conditions by type
+fn item_key(item: &Value) -> Option<&String> {
+    match item.get("name").or_else(|| item.get("type"))? {
+        Value::String(key) => Some(key),
+        _ => None,
+    }
+}
+
+fn find_item<'a>(items: &'a [Value], key: &str) -> Option<&'a Value> {
+    items.iter().find(|x| item_key(x).is_some_and(|x| x == key))
+}
+
+fn item_keys(items: &[Value]) -> Option<Vec<String>> {
+    items.iter().map(|x| item_key(x).cloned()).collect()
+}
+
+// only under status, a label or an env var may have any of these names
+fn is_noise(path: &str, key: &str) -> bool {
+    let in_status = path.split(['.', '[']).next() == Some("status");
+    in_status && ((key.starts_with("last") && key.ends_with("Time")) || STATUS_NOISE.contains(&key))
+}
+
+#[inline]
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+fn render(value: &Value) -> String {
+    match value {
+        Value::String(x) => x.clone(),
+        _ => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_diff_objects() {
+        let healthy = json!({
+            "metadata": { "name": "web-1", "resourceVersion": "10", "labels": { "app": "web" } },
+            "spec": { "containers": [
+                { "name": "app", "image": "web:1.0", "env": [{ "name": "containerID", "value": "a" }] },
+                { "name": "proxy", "image": "envoy:1.28" }
+            ] },
+            "status": { "conditions": [
+                { "type": "Ready", "status": "True", "lastTransitionTime": "2024-01-01T00:00:00Z" }
+            ], "containerStatuses": [{ "name": "app", "containerID": "containerd://1" }] }
+        });
+        let broken = json!({
+            "metadata": { "name": "web-2", "resourceVersion": "42", "labels": { "app": "web", "canary": "true", "uid": "1" } },
+            "spec": { "containers": [
+                { "name": "proxy", "image": "envoy:1.28" },
+                { "name": "app", "image": "web:1.1", "env": [{ "name": "containerID", "value": "b" }] }
+            ] },
+            "status": { "conditions": [
+                { "type": "Ready", "status": "False", "lastTransitionTime": "2024-01-02T00:00:00Z" }
+            ], "containerStatuses": [{ "name": "app", "containerID": "containerd://2" }] }
+        });
+        assert_eq!(
+            vec![
+                Change::Added {
+                    path: "labels.canary".to_string(),
+                    value: "true".to_string()
+                },
+                Change::Added {
+                    path: "labels.uid".to_string(),
+                    value: "1".to_string()
+                },
+                Change::Modified {
+                    path: "spec.containers[app].env[containerID].value".to_string(),
+                    old: "a".to_string(),
+                    new: "b".to_string()
+                },
+                Change::Modified {
+                    path: "spec.containers[app].image".to_string(),
+                    old: "web:1.0".to_string(),
+                    new: "web:1.1".to_string()
+                },
+                Change::Modified {
+                    path: "status.conditions[Ready].status".to_string(),
+                    old: "True".to_string(),
+                    new: "False".to_string()
+                },
+            ],
+            diff_objects(&healthy, &broken)
+        );
+        assert!(diff_objects(&healthy, &healthy).is_empty());
+    }
+}
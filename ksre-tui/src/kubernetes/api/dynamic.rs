@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+
+use color_eyre::eyre::Result;
+use k8s_openapi::apiextensions_apiserver::pkg::apis::apiextensions::v1::CustomResourceDefinition;
+use kube::{
+    api::ListParams,
+    discovery::{verbs, ApiResource, Discovery, Scope},
+    Api, Client,
+};
+use serde_json::Value;
+
+use crate::kubernetes::api::object::RtObject;
+
+// spec holds every field but metadata and status, so kinds without spec (e.g. ConfigMap) keep their data
+pub type DynamicRtObject = RtObject<Value, Value>;
+
+#[derive(Clone, Debug)]
+pub struct PrinterColumn {
+    pub name: String,
+    pub json_path: String,
+}
+
+// ResourceKind is one served resource found by api discovery
+#[derive(Clone, Debug)]
+pub struct ResourceKind {
+    pub resource: ApiResource,
+    pub namespaced: bool,
+    // additionalPrinterColumns of the crd, empty for builtin kinds
+    pub columns: Vec<PrinterColumn>,
+}
+
+impl ResourceKind {
+    // plural.group like kubectl api-resources, core kinds have no group
+    pub fn id(&self) -> String {
+        if self.resource.group.is_empty() {
+            self.resource.plural.clone()
+        } else {
+            format!("{}.{}", self.resource.plural, self.resource.group)
+        }
+    }
+
+    #[inline]
+    pub fn matches(&self, obj: &DynamicRtObject) -> bool {
+        obj.0.types.as_ref().is_some_and(|types| {
+            types.kind == self.resource.kind && types.api_version == self.resource.api_version
+        })
+    }
+}
+
+// every listable and watchable resource at its preferred version, sorted by id
+pub async fn discover_resources(client: Client) -> Result<Vec<ResourceKind>> {
+    let discovery = Discovery::new(client.clone()).run().await?;
+    let mut columns = crd_printer_columns(client).await;
+
+    let mut kinds = discovery
+        .groups()
+        .flat_map(|group| group.recommended_resources())
+        .filter(|(_, caps)| caps.supports_operation(verbs::LIST))
+        .filter(|(_, caps)| caps.supports_operation(verbs::WATCH))
+        .map(|(resource, caps)| {
+            let key = (
+                resource.group.clone(),
+                resource.plural.clone(),
+                resource.version.clone(),
+            );
+            ResourceKind {
+                columns: columns.remove(&key).unwrap_or_default(),
+                namespaced: caps.scope == Scope::Namespaced,
+                resource,
+            }
+        })
+        .collect::<Vec<ResourceKind>>();
+    kinds.sort_by_key(|kind| kind.id());
+    Ok(kinds)
+}
+
+// (group, plural, version) -> printer columns, crds that can not be listed just have no columns
+async fn crd_printer_columns(
+    client: Client,
+) -> HashMap<(String, String, String), Vec<PrinterColumn>> {
+    let crds: Api<CustomResourceDefinition> = Api::all(client);
+    let crds = match crds.list(&ListParams::default()).await {
+        Ok(crds) => crds.items,
+        Err(err) => {
+            tracing::warn!("list crds failed: {}", err);
+            return HashMap::new();
+        }
+    };
+
+    let mut result = HashMap::new();
+    for crd in crds {
+        for version in crd.spec.versions {
+            let columns = version
+                .additional_printer_columns
+                .unwrap_or_default()
+                .into_iter()
+                // priority > 0 columns only show in -o wide, age is always drawn
+                .filter(|column| column.priority.unwrap_or(0) == 0)
+                .filter(|column| column.json_path != ".metadata.creationTimestamp")
+                .map(|column| PrinterColumn {
+                    name: column.name,
+                    json_path: column.json_path,
+                })
+                .collect();
+            let key = (
+                crd.spec.group.clone(),
+                crd.spec.names.plural.clone(),
+                version.name,
+            );
+            result.insert(key, columns);
+        }
+    }
+    result
+}
+
+// the object as it comes from the apiserver
+pub fn object_value(obj: &DynamicRtObject) -> Value {
+    let mut value = match &obj.0.spec {
+        Value::Object(fields) => fields.clone(),
+        _ => serde_json::Map::new(),
+    };
+    if let Some(types) = obj.0.types.as_ref() {
+        value.insert("apiVersion".to_string(), types.api_version.clone().into());
+        value.insert("kind".to_string(), types.kind.clone().into());
+    }
+    value.insert(
+        "metadata".to_string(),
+        serde_json::to_value(&obj.0.metadata).unwrap_or_default(),
+    );
+    if let Some(status) = obj.0.status.as_ref() {
+        value.insert("status".to_string(), status.clone());
+    }
+    Value::Object(value)
+}
+
+pub fn object_yaml(obj: &DynamicRtObject) -> String {
+    let mut value = object_value(obj);
+    // managedFields is noise for humans, kubectl hides it too
+    if let Some(metadata) = value.get_mut("metadata").and_then(|x| x.as_object_mut()) {
+        metadata.remove("managedFields");
+    }
+    serde_yaml::to_string(&value).unwrap_or_else(|err| format!("render yaml failed: {}", err))
+}
+
+// column_value evaluates the jsonpath subset crds use in printer columns:
+// .a.b fields, [0] indexes and [?(@.type=="Ready")] filters
+pub fn column_value(value: &Value, json_path: &str) -> String {
+    let mut current = vec![value];
+    for segment in path_segments(json_path) {
+        current = current
+            .into_iter()
+            .flat_map(|value| select(value, &segment))
+            .collect();
+    }
+    current
+        .into_iter()
+        .map(|value| match value {
+            Value::String(s) => s.clone(),
+            Value::Null => String::new(),
+            value => value.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Field(String),
+    Index(usize),
+    All,
+    Filter(String, String),
+}
+
+fn path_segments(json_path: &str) -> Vec<Segment> {
+    let path = json_path
+        .trim()
+        .trim_start_matches('{')
+        .trim_end_matches('}');
+    let path = path.trim_start_matches('$');
+    // a path without leading dot, e.g. spec.replicas
+    let path = if path.starts_with(['.', '[']) {
+        path.to_string()
+    } else {
+        format!(".{}", path)
+    };
+
+    let mut segments = Vec::new();
+    let mut rest = path.as_str();
+    while !rest.is_empty() {
+        if let Some(tail) = rest.strip_prefix('.') {
+            let end = tail.find(['.', '[']).unwrap_or(tail.len());
+            if end > 0 {
+                segments.push(Segment::Field(tail[..end].to_string()));
+            }
+            rest = &tail[end..];
+        } else if let Some(tail) = rest.strip_prefix('[') {
+            let Some(end) = tail.find(']') else {
+                break;
+            };
+            let inner = tail[..end].trim();
+            if inner == "*" {
+                segments.push(Segment::All);
+            } else if let Ok(idx) = inner.parse::<usize>() {
+                segments.push(Segment::Index(idx));
+            } else if let Some(filter) = parse_filter(inner) {
+                segments.push(filter);
+            }
+            rest = &tail[end + 1..];
+        } else {
+            break;
+        }
+    }
+    segments
+}
+
+// ?(@.type=="Ready")
+fn parse_filter(inner: &str) -> Option<Segment> {
+    let expr = inner.strip_prefix("?(")?.strip_suffix(')')?;
+    let (field, value) = expr.split_once("==")?;
+    let field = field.trim().strip_prefix("@.")?;
+    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+    Some(Segment::Filter(field.to_string(), value.to_string()))
+}
+
+fn select<'a>(value: &'a Value, segment: &Segment) -> Vec<&'a Value> {
+    match (segment, value) {
+        (Segment::Field(field), Value::Object(fields)) => fields.get(field).into_iter().collect(),
+        (Segment::Index(idx), Value::Array(items)) => items.get(*idx).into_iter().collect(),
+        (Segment::All, Value::Array(items)) => items.iter().collect(),
+        (Segment::Filter(field, expected), Value::Array(items)) => items
+            .iter()
+            .filter(|item| match item.get(field) {
+                Some(Value::String(s)) => s == expected,
+                Some(value) => expected
+                    .parse::<Value>()
+                    .is_ok_and(|expected| *value == expected),
+                None => false,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_column_value() {
+        let obj = json!({
+            "spec": { "secretName": "web-tls", "replicas": 3 },
+            "status": {
+                "conditions": [
+                    { "type": "Issuing", "status": "False" },
+                    { "type": "Ready", "status": "True", "message": "up to date" }
+                ]
+            }
+        });
+        assert_eq!("web-tls", column_value(&obj, ".spec.secretName"));
+        assert_eq!("3", column_value(&obj, ".spec.replicas"));
+        assert_eq!("Issuing", column_value(&obj, ".status.conditions[0].type"));
+        assert_eq!(
+            "True",
+            column_value(&obj, r#".status.conditions[?(@.type=="Ready")].status"#)
+        );
+        assert_eq!("", column_value(&obj, ".status.missing"));
+    }
+}
@@ -1,2 +1,14 @@
+pub mod access;
+pub mod autoscale;
+pub mod batch;
+pub mod capacity;
+pub mod cluster;
+pub mod config;
+pub mod deployment;
+pub mod diff;
+pub mod dynamic;
+pub mod node;
 pub mod object;
 pub mod pod;
+pub mod service;
+pub mod storage;
@@ -0,0 +1,123 @@
+use k8s_openapi::api::core::v1::{NodeSpec, NodeStatus};
+
+use crate::kubernetes::api::object::RtObject;
+
+const ROLE_LABEL_PREFIX: &str = "node-role.kubernetes.io/";
+
+pub type NodeObject = RtObject<NodeSpec, NodeStatus>;
+
+// status column of kubectl get nodes, e.g. Ready,SchedulingDisabled
+pub fn node_status(node: &NodeObject) -> String {
+    let ready = node
+        .0
+        .status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .and_then(|conditions| conditions.iter().find(|x| x.type_ == "Ready"))
+        .map(|condition| condition.status == "True")
+        .unwrap_or(false);
+    let mut status = if ready { "Ready" } else { "NotReady" }.to_string();
+    if is_cordoned(node) {
+        status.push_str(",SchedulingDisabled");
+    }
+    status
+}
+
+#[inline]
+pub fn is_cordoned(node: &NodeObject) -> bool {
+    node.0.spec.unschedulable.unwrap_or(false)
+}
+
+pub fn node_roles(node: &NodeObject) -> String {
+    let roles = node
+        .0
+        .metadata
+        .labels
+        .as_ref()
+        .map(|labels| {
+            labels
+                .keys()
+                .filter_map(|key| key.strip_prefix(ROLE_LABEL_PREFIX))
+                .filter(|role| !role.is_empty())
+                .collect::<Vec<&str>>()
+                .join(",")
+        })
+        .unwrap_or_default();
+    if roles.is_empty() {
+        "<none>".to_string()
+    } else {
+        roles
+    }
+}
+
+pub fn node_version(node: &NodeObject) -> &str {
+    node.0
+        .status
+        .as_ref()
+        .and_then(|status| status.node_info.as_ref())
+        .map(|info| info.kubelet_version.as_str())
+        .unwrap_or_default()
+}
+
+// lines of conditions, capacity/allocatable and taints shown in the node detail pane
+pub fn node_details(node: &NodeObject) -> Vec<String> {
+    let mut details = Vec::new();
+    let status = node.0.status.as_ref();
+    if let Some(info) = status.and_then(|status| status.node_info.as_ref()) {
+        details.push(format!("{:<20}{}", "OS Image:", info.os_image));
+        details.push(format!("{:<20}{}", "Kernel:", info.kernel_version));
+        details.push(format!(
+            "{:<20}{}",
+            "Runtime:", info.container_runtime_version
+        ));
+    }
+    if let Some(addresses) = status.and_then(|status| status.addresses.as_ref()) {
+        for address in addresses {
+            details.push(format!(
+                "{:<20}{}",
+                format!("{}:", address.type_),
+                address.address
+            ));
+        }
+    }
+    details.push("Conditions:".to_string());
+    for condition in status
+        .and_then(|status| status.conditions.as_deref())
+        .unwrap_or_default()
+    {
+        details.push(format!(
+            "  {:<24}{:<8}{}",
+            condition.type_,
+            condition.status,
+            condition.reason.as_deref().unwrap_or_default()
+        ));
+    }
+    details.push(format!(
+        "{:<20}{:<16}{}",
+        "Resource:", "Capacity", "Allocatable"
+    ));
+    if let Some(capacity) = status.and_then(|status| status.capacity.as_ref()) {
+        let allocatable = status.and_then(|status| status.allocatable.as_ref());
+        for resource in ["cpu", "memory", "pods"] {
+            details.push(format!(
+                "  {:<18}{:<16}{}",
+                resource,
+                capacity.get(resource).map(|x| x.0.as_str()).unwrap_or("-"),
+                allocatable
+                    .and_then(|x| x.get(resource))
+                    .map(|x| x.0.as_str())
+                    .unwrap_or("-")
+            ));
+        }
+    }
+    details.push("Taints:".to_string());
+    for taint in node.0.spec.taints.as_deref().unwrap_or_default() {
+        details.push(format!(
+            "  {}={}:{}",
+            taint.key,
+            taint.value.as_deref().unwrap_or_default(),
+            taint.effect
+        ));
+    }
+    details
+}
@@ -3,11 +3,28 @@ use std::fmt::Debug;
 use k8s_openapi::api::{
     apps::v1::{
         DaemonSet, DaemonSetSpec, DaemonSetStatus, Deployment, DeploymentSpec, DeploymentStatus,
-        StatefulSet, StatefulSetSpec, StatefulSetStatus,
+        ReplicaSet, ReplicaSetSpec, ReplicaSetStatus, StatefulSet, StatefulSetSpec,
+        StatefulSetStatus,
+    },
+    autoscaling::v2::{
+        HorizontalPodAutoscaler, HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus,
+    },
+    batch::v1::{CronJob, CronJobSpec, CronJobStatus, Job, JobSpec, JobStatus},
+    core::v1::{
+        ConfigMap, Node, NodeSpec, NodeStatus, PersistentVolume, PersistentVolumeClaim,
+        PersistentVolumeClaimSpec, PersistentVolumeClaimStatus, PersistentVolumeSpec,
+        PersistentVolumeStatus, Pod, PodSpec, PodStatus, Secret, Service, ServiceSpec,
+        ServiceStatus,
     },
-    core::v1::{Pod, PodSpec, PodStatus, Service, ServiceSpec, ServiceStatus},
 };
-use kube::core::{Object, TypeMeta};
+use kube::{
+    core::{DynamicObject, Object, TypeMeta},
+    Resource,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::kubernetes::api::config::ConfigData;
 
 // RtObject as Kbernetes runtime object
 pub struct RtObject<P: Clone, U: Clone>(pub Object<P, U>);
@@ -23,6 +40,17 @@ impl<P: Clone, U: Clone> RtObject<P, U> {
     }
 }
 
+impl<P: Clone + Serialize, U: Clone + Serialize> RtObject<P, U> {
+    // manifest as kubectl get -o yaml prints it, managedFields left out
+    pub fn to_yaml(&self) -> String {
+        let mut value = serde_json::to_value(&self.0).unwrap_or_default();
+        if let Some(metadata) = value.get_mut("metadata").and_then(|x| x.as_object_mut()) {
+            metadata.remove("managedFields");
+        }
+        serde_yaml::to_string(&value).unwrap_or_else(|err| format!("render yaml failed: {}", err))
+    }
+}
+
 // Debug[#TODO] (should add some comments)
 impl<P: Clone, U: Clone> Debug for RtObject<P, U> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -45,6 +73,46 @@ impl<P: Clone, U: Clone> Clone for RtObject<P, U> {
     }
 }
 
+// KubeObject is a kubernetes kind which can be watched and converted into RtObject
+pub trait KubeObject:
+    Resource<DynamicType = ()> + Clone + Debug + DeserializeOwned + Send + Sync + 'static
+{
+    type Spec: Clone + Debug + Send + 'static;
+    type Status: Clone + Debug + Send + 'static;
+
+    fn into_object(self) -> RtObject<Self::Spec, Self::Status>;
+}
+
+macro_rules! kube_object {
+    ($($kind:ty : $spec:ty, $status:ty);* $(;)?) => {
+        $(impl KubeObject for $kind {
+            type Spec = $spec;
+            type Status = $status;
+
+            fn into_object(self) -> RtObject<Self::Spec, Self::Status> {
+                self.into()
+            }
+        })*
+    };
+}
+
+kube_object! {
+    Pod: PodSpec, PodStatus;
+    Node: NodeSpec, NodeStatus;
+    Service: ServiceSpec, ServiceStatus;
+    ConfigMap: ConfigData, ();
+    Deployment: DeploymentSpec, DeploymentStatus;
+    ReplicaSet: ReplicaSetSpec, ReplicaSetStatus;
+    StatefulSet: StatefulSetSpec, StatefulSetStatus;
+    DaemonSet: DaemonSetSpec, DaemonSetStatus;
+    Secret: ConfigData, ();
+    Job: JobSpec, JobStatus;
+    CronJob: CronJobSpec, CronJobStatus;
+    PersistentVolumeClaim: PersistentVolumeClaimSpec, PersistentVolumeClaimStatus;
+    PersistentVolume: PersistentVolumeSpec, PersistentVolumeStatus;
+    HorizontalPodAutoscaler: HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus;
+}
+
 impl From<Pod> for RtObject<PodSpec, PodStatus> {
     fn from(value: Pod) -> Self {
         const API_VERSION: &'_ str = "v1";
@@ -78,6 +146,22 @@ impl From<Deployment> for RtObject<DeploymentSpec, DeploymentStatus> {
     }
 }
 
+impl From<Node> for RtObject<NodeSpec, NodeStatus> {
+    fn from(value: Node) -> Self {
+        const API_VERSION: &'_ str = "v1";
+        const KIND: &'_ str = "Node";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
 impl From<Service> for RtObject<ServiceSpec, ServiceStatus> {
     fn from(value: Service) -> Self {
         const API_VERSION: &'_ str = "v1";
@@ -109,6 +193,22 @@ impl From<DaemonSet> for RtObject<DaemonSetSpec, DaemonSetStatus> {
         })
     }
 }
+impl From<ReplicaSet> for RtObject<ReplicaSetSpec, ReplicaSetStatus> {
+    fn from(value: ReplicaSet) -> Self {
+        const API_VERSION: &'_ str = "apps/v1";
+        const KIND: &'_ str = "ReplicaSet";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
 impl From<StatefulSet> for RtObject<StatefulSetSpec, StatefulSetStatus> {
     fn from(value: StatefulSet) -> Self {
         const API_VERSION: &'_ str = "apps/v1";
@@ -124,3 +224,103 @@ impl From<StatefulSet> for RtObject<StatefulSetSpec, StatefulSetStatus> {
         })
     }
 }
+
+impl From<Job> for RtObject<JobSpec, JobStatus> {
+    fn from(value: Job) -> Self {
+        const API_VERSION: &'_ str = "batch/v1";
+        const KIND: &'_ str = "Job";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
+impl From<CronJob> for RtObject<CronJobSpec, CronJobStatus> {
+    fn from(value: CronJob) -> Self {
+        const API_VERSION: &'_ str = "batch/v1";
+        const KIND: &'_ str = "CronJob";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
+impl From<PersistentVolumeClaim>
+    for RtObject<PersistentVolumeClaimSpec, PersistentVolumeClaimStatus>
+{
+    fn from(value: PersistentVolumeClaim) -> Self {
+        const API_VERSION: &'_ str = "v1";
+        const KIND: &'_ str = "PersistentVolumeClaim";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
+impl From<PersistentVolume> for RtObject<PersistentVolumeSpec, PersistentVolumeStatus> {
+    fn from(value: PersistentVolume) -> Self {
+        const API_VERSION: &'_ str = "v1";
+        const KIND: &'_ str = "PersistentVolume";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
+impl From<HorizontalPodAutoscaler>
+    for RtObject<HorizontalPodAutoscalerSpec, HorizontalPodAutoscalerStatus>
+{
+    fn from(value: HorizontalPodAutoscaler) -> Self {
+        const API_VERSION: &'_ str = "autoscaling/v2";
+        const KIND: &'_ str = "HorizontalPodAutoscaler";
+        Self(Object {
+            types: Some(TypeMeta {
+                api_version: API_VERSION.to_string(),
+                kind: KIND.to_string(),
+            }),
+            metadata: value.metadata,
+            spec: value.spec.unwrap_or_default(),
+            status: value.status,
+        })
+    }
+}
+
+// dynamic objects have no typed spec, every field but metadata and status is kept as spec
+impl From<DynamicObject> for RtObject<Value, Value> {
+    fn from(value: DynamicObject) -> Self {
+        let mut data = value.data;
+        let status = data
+            .as_object_mut()
+            .and_then(|fields| fields.remove("status"));
+        Self(Object {
+            types: value.types,
+            metadata: value.metadata,
+            spec: data,
+            status,
+        })
+    }
+}
@@ -10,36 +10,34 @@ use crate::kubernetes::api::object::RtObject;
 
 const NIL_STR: &'_ str = "<none>";
 
-// unsafe
 pub struct PodDescribe {
-    pub name: *const str,
-    pub namespace: *const str,
+    pub name: String,
+    pub namespace: String,
     pub priority: i32,
-    pub service_account: *const str,
+    pub service_account: String,
     pub labels: String,
-    pub node: *const str,
+    pub node: String,
     pub start_time: String,
-    pub status: *const str,
-    pub ip: *const str,
-    pub ips: Vec<*const str>,
-    pub qos_class: *const str,
+    pub status: String,
+    pub ip: String,
+    pub ips: Vec<String>,
+    pub qos_class: String,
     pub node_selector: String,
     pub containers: Vec<PodDescContainer>,
-    pub conditions: HashMap<*const str, *const str>,
+    pub conditions: HashMap<String, String>,
     pub ready_number: i32,
 }
 
-// unsafe
 impl From<&RtObject<PodSpec, PodStatus>> for PodDescribe {
     fn from(object: &RtObject<PodSpec, PodStatus>) -> Self {
         let pod_metadata = object.0.meta();
         let pod_spec = object.0.spec();
         let pod_status = object.0.status();
-        let name: *const str = pod_metadata.name.as_deref().unwrap();
-        let namespace: *const str = pod_metadata.namespace.as_deref().unwrap();
-        let service_account: *const str = pod_spec.service_account.as_deref().unwrap_or_default();
+        let name = pod_metadata.name.clone().unwrap();
+        let namespace = pod_metadata.namespace.clone().unwrap();
+        let service_account = pod_spec.service_account.clone().unwrap_or_default();
         let priority = pod_spec.priority.unwrap_or(0);
-        let node: *const str = pod_spec.node_name.as_deref().unwrap_or_default();
+        let node = pod_spec.node_name.clone().unwrap_or_default();
 
         let labels = pod_metadata
             .labels
@@ -61,23 +59,19 @@ impl From<&RtObject<PodSpec, PodStatus>> for PodDescribe {
                 .as_ref()
                 .map(|x| format!("{:?}", x))
                 .unwrap_or_default();
-            let status = pod_status.phase.as_deref().unwrap_or_default();
-            let ip = pod_status.pod_ip.as_deref().unwrap_or_default();
+            let status = pod_status.phase.clone().unwrap_or_default();
+            let ip = pod_status.pod_ip.clone().unwrap_or_default();
             let ips = pod_status
                 .pod_ips
                 .as_deref()
                 .map(|_pod_ips| {
                     _pod_ips
                         .iter()
-                        .map(|x| {
-                            let raw_ptr: *const str =
-                                x.ip.as_deref().unwrap_or_default() as *const str;
-                            raw_ptr
-                        })
-                        .collect::<Vec<*const str>>()
+                        .map(|x| x.ip.clone().unwrap_or_default())
+                        .collect::<Vec<String>>()
                 })
                 .unwrap_or_default();
-            let qos_class = pod_status.qos_class.as_deref().unwrap_or_default();
+            let qos_class = pod_status.qos_class.clone().unwrap_or_default();
             let mut running_nr: i32 = 0;
             let containers = pod_status
                 .container_statuses
@@ -92,10 +86,10 @@ impl From<&RtObject<PodSpec, PodStatus>> for PodDescribe {
                         let (last_state, _) =
                             container_state_to_hashmap(container.last_state.as_ref().unwrap());
                         PodDescContainer {
-                            name: container.name.as_str(),
-                            container_id: container.container_id.as_deref().unwrap_or_default(),
-                            image: container.image.as_str(),
-                            image_id: container.image_id.as_str(),
+                            name: container.name.clone(),
+                            container_id: container.container_id.clone().unwrap_or_default(),
+                            image: container.image.clone(),
+                            image_id: container.image_id.clone(),
                             state,
                             last_state,
                             started: container.started.unwrap_or_default(),
@@ -111,10 +105,7 @@ impl From<&RtObject<PodSpec, PodStatus>> for PodDescribe {
                 .map(|_conditions| {
                     let mut ret = HashMap::new();
                     for condition in _conditions {
-                        ret.insert(
-                            condition.type_.as_str() as *const str,
-                            condition.status.as_str() as *const str,
-                        );
+                        ret.insert(condition.type_.clone(), condition.status.clone());
                     }
                     ret
                 })
@@ -146,10 +137,10 @@ impl From<&RtObject<PodSpec, PodStatus>> for PodDescribe {
                 labels,
                 node,
                 start_time: NIL_STR.to_string(),
-                status: NIL_STR,
-                ip: NIL_STR,
+                status: NIL_STR.to_string(),
+                ip: NIL_STR.to_string(),
                 ips: Vec::new(),
-                qos_class: NIL_STR,
+                qos_class: NIL_STR.to_string(),
                 node_selector,
                 containers: Vec::new(),
                 conditions: HashMap::new(),
@@ -161,18 +152,15 @@ impl From<&RtObject<PodSpec, PodStatus>> for PodDescribe {
 
 fn container_state_to_hashmap(
     container_state: &ContainerState,
-) -> (Vec<(*const str, String)>, bool) {
+) -> (Vec<(&'static str, String)>, bool) {
     if container_state.terminated.is_some() {
         let terminaled_status = container_state.terminated.as_ref().unwrap();
         let mut result = Vec::new();
         result.extend([
-            ("State" as *const str, "Terminated".to_string()),
+            ("State", "Terminated".to_string()),
+            ("ExitCode", format!("{}", terminaled_status.exit_code)),
             (
-                "ExitCode" as *const str,
-                format!("{}", terminaled_status.exit_code),
-            ),
-            (
-                "Finished_At" as *const str,
+                "Finished_At",
                 terminaled_status
                     .finished_at
                     .as_ref()
@@ -180,25 +168,25 @@ fn container_state_to_hashmap(
                     .unwrap_or_default(),
             ),
             (
-                "Message" as *const str,
+                "Message",
                 format!(
                     "{:?}",
                     terminaled_status.message.as_deref().unwrap_or_default()
                 ),
             ),
             (
-                "Reason" as *const str,
+                "Reason",
                 format!(
                     "{:?}",
                     terminaled_status.reason.as_deref().unwrap_or(NIL_STR)
                 ),
             ),
             (
-                "Signal" as *const str,
+                "Signal",
                 format!("{:?}", terminaled_status.signal.unwrap_or(0)),
             ),
             (
-                "Started_At" as *const str,
+                "Started_At",
                 terminaled_status
                     .started_at
                     .as_ref()
@@ -211,7 +199,7 @@ fn container_state_to_hashmap(
         let running_state = container_state.running.as_ref().unwrap();
         let mut result = Vec::new();
         result.extend([
-            ("State" as *const str, "Running".to_string()),
+            ("State", "Running".to_string()),
             (
                 "Start_At",
                 running_state
@@ -226,13 +214,13 @@ fn container_state_to_hashmap(
         let waiting_state = container_state.waiting.as_ref().unwrap();
         let mut result = Vec::new();
         result.extend([
-            ("State" as *const str, "Waiting".to_string()),
+            ("State", "Waiting".to_string()),
             (
-                "Reason" as *const str,
+                "Reason",
                 format!("{:?}", waiting_state.reason.as_deref().unwrap_or_default()),
             ),
             (
-                "Message" as *const str,
+                "Message",
                 format!("{:?}", waiting_state.message.as_deref().unwrap_or_default()),
             ),
         ]);
@@ -241,17 +229,70 @@ fn container_state_to_hashmap(
     (Vec::new(), false)
 }
 
+// lines of the describe pane, also what gets copied from it
+impl PodDescribe {
+    pub fn lines(&self) -> Vec<String> {
+        let mut describe = vec![
+            format!("Service Account:         {}", self.service_account),
+            format!("Node:                    {}", self.node),
+            format!("Start Time:              {}", self.start_time),
+            format!("Labels:                  {}", self.labels),
+            format!("Status:                  {}", self.status),
+            format!("IP:                      {}", self.ip),
+            "Containers".to_string(),
+        ];
+        for container in self.containers.iter() {
+            describe.push(format!(" {}", container.name));
+            let states = [
+                ("State:", &container.state),
+                ("Last State:", &container.last_state),
+            ];
+            for (title, state) in states {
+                for (k, v) in state.iter() {
+                    if *k == "State" {
+                        describe.push(format!("  {:<26}{:<16}", title, v));
+                    } else {
+                        describe.push(format!("    {:<24}{:<16}", k, v));
+                    }
+                }
+            }
+        }
+        describe
+    }
+}
+
 pub struct PodDescContainer {
-    pub name: *const str,
-    pub container_id: *const str,
-    pub image: *const str,
-    pub image_id: *const str,
-    pub state: Vec<(*const str, String)>,
-    pub last_state: Vec<(*const str, String)>,
+    pub name: String,
+    pub container_id: String,
+    pub image: String,
+    pub image_id: String,
+    pub state: Vec<(&'static str, String)>,
+    pub last_state: Vec<(&'static str, String)>,
     // 此次启动时间
     pub started: bool,
     pub rerestart_count: i32,
 }
 
+// same as the endpoints controller, a pod serves traffic when its Ready condition is True
+pub fn is_pod_ready(pod: &RtObject<PodSpec, PodStatus>) -> bool {
+    pod.0
+        .status
+        .as_ref()
+        .and_then(|status| status.conditions.as_ref())
+        .and_then(|conditions| conditions.iter().find(|x| x.type_ == "Ready"))
+        .map(|condition| condition.status == "True")
+        .unwrap_or(false)
+}
+
+// restarts of all containers, like the RESTARTS column of kubectl get pods
+pub fn pod_restarts(pod: &RtObject<PodSpec, PodStatus>) -> i32 {
+    pod.0
+        .status
+        .as_ref()
+        .and_then(|status| status.container_statuses.as_ref())
+        .map(|containers| containers.iter().map(|x| x.restart_count).sum())
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {}
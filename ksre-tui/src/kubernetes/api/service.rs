@@ -0,0 +1,134 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use k8s_openapi::api::core::v1::{PodSpec, PodStatus, ServiceSpec, ServiceStatus};
+
+use crate::kubernetes::{
+    api::{object::RtObject, pod::is_pod_ready},
+    indexer::StoreIndex,
+};
+
+pub type ServiceObject = RtObject<ServiceSpec, ServiceStatus>;
+type PodObject = RtObject<PodSpec, PodStatus>;
+
+// 80/TCP,443:30443/TCP like kubectl get svc
+pub fn service_ports(svc: &ServiceObject) -> String {
+    let ports = svc
+        .0
+        .spec
+        .ports
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|port| {
+            let protocol = port.protocol.as_deref().unwrap_or("TCP");
+            match port.node_port {
+                Some(node_port) => format!("{}:{}/{}", port.port, node_port, protocol),
+                None => format!("{}/{}", port.port, protocol),
+            }
+        })
+        .collect::<Vec<String>>();
+    if ports.is_empty() {
+        "<none>".to_string()
+    } else {
+        ports.join(",")
+    }
+}
+
+pub fn service_selector(svc: &ServiceObject) -> String {
+    match svc.0.spec.selector.as_ref() {
+        Some(selector) if !selector.is_empty() => selector
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .collect::<Vec<String>>()
+            .join(","),
+        _ => "<none>".to_string(),
+    }
+}
+
+#[inline]
+pub fn service_type(svc: &ServiceObject) -> &str {
+    svc.0.spec.type_.as_deref().unwrap_or("ClusterIP")
+}
+
+#[inline]
+pub fn cluster_ip(svc: &ServiceObject) -> &str {
+    svc.0.spec.cluster_ip.as_deref().unwrap_or("<none>")
+}
+
+// an empty selector matches nothing, endpoints of such services are managed by hand
+#[inline]
+pub fn selector_matches(
+    selector: &BTreeMap<String, String>,
+    labels: Option<&BTreeMap<String, String>>,
+) -> bool {
+    let Some(labels) = labels else {
+        return false;
+    };
+    !selector.is_empty()
+        && selector
+            .iter()
+            .all(|(key, value)| labels.get(key) == Some(value))
+}
+
+// pods in the namespace of the service selected by its selector, sorted by name
+pub fn backing_pods(
+    svc: &ServiceObject,
+    pod_storage: &StoreIndex<PodSpec, PodStatus>,
+) -> Vec<Rc<PodObject>> {
+    let Some(selector) = svc.0.spec.selector.as_ref() else {
+        return Vec::new();
+    };
+    let namespace = svc.0.metadata.namespace.as_deref().unwrap_or_default();
+    let mut pods = pod_storage
+        .iter()
+        .filter(|pod| pod.0.metadata.namespace.as_deref() == Some(namespace))
+        .filter(|pod| selector_matches(selector, pod.0.metadata.labels.as_ref()))
+        .cloned()
+        .collect::<Vec<Rc<PodObject>>>();
+    pods.sort_by(|a, b| a.0.metadata.name.cmp(&b.0.metadata.name));
+    pods
+}
+
+// one line answer to "why does this service have no endpoints"
+pub fn endpoints_diagnosis(svc: &ServiceObject, pods: &[Rc<PodObject>]) -> String {
+    if service_type(svc) == "ExternalName" {
+        return format!(
+            "ExternalName service, resolves to {}",
+            svc.0.spec.external_name.as_deref().unwrap_or("<none>")
+        );
+    }
+    let has_selector =
+        matches!(svc.0.spec.selector.as_ref(), Some(selector) if !selector.is_empty());
+    if !has_selector {
+        return "no selector, endpoints are not managed by kubernetes".to_string();
+    }
+    let ready = pods.iter().filter(|pod| is_pod_ready(pod)).count();
+    match (pods.len(), ready) {
+        (0, _) => "selector matches no pod in the namespace, check the labels".to_string(),
+        (total, 0) => format!(
+            "{} pods match but none is ready, check readiness probes",
+            total
+        ),
+        (total, ready) => format!("{}/{} backing pods ready", ready, total),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selector_matches() {
+        let selector = BTreeMap::from([("app".to_string(), "web".to_string())]);
+        let labels = BTreeMap::from([
+            ("app".to_string(), "web".to_string()),
+            ("tier".to_string(), "frontend".to_string()),
+        ]);
+        assert!(selector_matches(&selector, Some(&labels)));
+        assert!(!selector_matches(&selector, None));
+        assert!(!selector_matches(&BTreeMap::new(), Some(&labels)));
+
+        let selector = BTreeMap::from([("app".to_string(), "api".to_string())]);
+        assert!(!selector_matches(&selector, Some(&labels)));
+    }
+}
@@ -0,0 +1,267 @@
+use std::{collections::HashMap, rc::Rc};
+
+use color_eyre::eyre::Result;
+use k8s_openapi::api::core::v1::{
+    PersistentVolumeClaimSpec, PersistentVolumeClaimStatus, PersistentVolumeSpec,
+    PersistentVolumeStatus, PodSpec, PodStatus,
+};
+use kube::Client;
+use serde::Deserialize;
+
+use crate::kubernetes::api::{capacity::format_memory, object::RtObject};
+
+pub type ClaimObject = RtObject<PersistentVolumeClaimSpec, PersistentVolumeClaimStatus>;
+pub type VolumeObject = RtObject<PersistentVolumeSpec, PersistentVolumeStatus>;
+type PodObject = RtObject<PodSpec, PodStatus>;
+
+// filesystem usage of a mounted claim as the kubelet reports it
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VolumeStats {
+    pub used: u64,
+    pub available: u64,
+    pub capacity: u64,
+}
+
+impl VolumeStats {
+    pub fn percent_used(&self) -> f64 {
+        match self.capacity {
+            0 => 0.0,
+            capacity => self.used as f64 * 100.0 / capacity as f64,
+        }
+    }
+
+    // e.g. 7.2Gi/10.0Gi (72%)
+    pub fn summary(&self) -> String {
+        format!(
+            "{}/{} ({:.0}%)",
+            format_memory(self.used as f64),
+            format_memory(self.capacity as f64),
+            self.percent_used()
+        )
+    }
+}
+
+// the parts of the kubelet stats/summary we read, the rest is ignored
+#[derive(Deserialize)]
+struct Summary {
+    #[serde(default)]
+    pods: Vec<PodSummary>,
+}
+
+#[derive(Deserialize)]
+struct PodSummary {
+    #[serde(default)]
+    volume: Vec<VolumeSummary>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VolumeSummary {
+    pvc_ref: Option<PvcRef>,
+    used_bytes: Option<u64>,
+    available_bytes: Option<u64>,
+    capacity_bytes: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct PvcRef {
+    name: String,
+    namespace: String,
+}
+
+// stats of the claims mounted on the node by namespace/name, volumes without a claim
+// like emptyDir are left out
+pub async fn node_volume_stats(
+    client: &Client,
+    node: &str,
+) -> Result<HashMap<String, VolumeStats>> {
+    let request = http::Request::get(format!("/api/v1/nodes/{}/proxy/stats/summary", node))
+        .body(Vec::new())?;
+    parse_summary(&client.request_text(request).await?)
+}
+
+fn parse_summary(body: &str) -> Result<HashMap<String, VolumeStats>> {
+    let summary: Summary = serde_json::from_str(body)?;
+    Ok(summary
+        .pods
+        .into_iter()
+        .flat_map(|pod| pod.volume)
+        .filter_map(|volume| {
+            let claim = volume.pvc_ref?;
+            let stats = VolumeStats {
+                used: volume.used_bytes.unwrap_or(0),
+                available: volume.available_bytes.unwrap_or(0),
+                capacity: volume.capacity_bytes.unwrap_or(0),
+            };
+            Some((format!("{}/{}", claim.namespace, claim.name), stats))
+        })
+        .collect())
+}
+
+// Bound, Pending or Lost
+pub fn claim_phase(claim: &ClaimObject) -> &str {
+    claim
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.phase.as_deref())
+        .unwrap_or("Pending")
+}
+
+// Available, Bound, Released or Failed
+pub fn volume_phase(volume: &VolumeObject) -> &str {
+    volume
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.phase.as_deref())
+        .unwrap_or("Pending")
+}
+
+// capacity of the bound volume, a pending claim shows what it requests
+pub fn claim_capacity(claim: &ClaimObject) -> String {
+    let capacity = claim
+        .0
+        .status
+        .as_ref()
+        .and_then(|x| x.capacity.as_ref())
+        .and_then(|x| x.get("storage"));
+    let requested = claim
+        .0
+        .spec
+        .resources
+        .as_ref()
+        .and_then(|x| x.requests.as_ref())
+        .and_then(|x| x.get("storage"));
+    capacity
+        .or(requested)
+        .map_or("<none>".to_string(), |x| x.0.clone())
+}
+
+pub fn volume_capacity(volume: &VolumeObject) -> String {
+    volume
+        .0
+        .spec
+        .capacity
+        .as_ref()
+        .and_then(|x| x.get("storage"))
+        .map_or("<none>".to_string(), |x| x.0.clone())
+}
+
+// short names like the ACCESS MODES column of kubectl, e.g. RWO,ROX
+pub fn access_modes(modes: Option<&Vec<String>>) -> String {
+    modes
+        .into_iter()
+        .flatten()
+        .map(|mode| match mode.as_str() {
+            "ReadWriteOnce" => "RWO",
+            "ReadOnlyMany" => "ROX",
+            "ReadWriteMany" => "RWX",
+            "ReadWriteOncePod" => "RWOP",
+            mode => mode,
+        })
+        .collect::<Vec<&str>>()
+        .join(",")
+}
+
+// namespace/name of the claim a volume is bound to
+pub fn volume_claim(volume: &VolumeObject) -> Option<String> {
+    let claim = volume.0.spec.claim_ref.as_ref()?;
+    Some(format!(
+        "{}/{}",
+        claim.namespace.as_deref().unwrap_or_default(),
+        claim.name.as_deref().unwrap_or_default()
+    ))
+}
+
+// what backs the volume, e.g. csi ebs.csi.aws.com vol-0abc
+pub fn volume_source(volume: &VolumeObject) -> String {
+    let spec = &volume.0.spec;
+    if let Some(csi) = spec.csi.as_ref() {
+        format!("csi {} {}", csi.driver, csi.volume_handle)
+    } else if let Some(nfs) = spec.nfs.as_ref() {
+        format!("nfs {}:{}", nfs.server, nfs.path)
+    } else if let Some(host_path) = spec.host_path.as_ref() {
+        format!("hostPath {}", host_path.path)
+    } else if let Some(local) = spec.local.as_ref() {
+        format!("local {}", local.path)
+    } else {
+        "<other>".to_string()
+    }
+}
+
+// pods mounting the claim, by a persistentVolumeClaim volume or by a generic ephemeral
+// volume whose claim is named <pod>-<volume>
+pub fn claim_pods<'a>(
+    claim: &ClaimObject,
+    pods: impl Iterator<Item = &'a Rc<PodObject>>,
+) -> Vec<Rc<PodObject>> {
+    let namespace = claim.0.metadata.namespace.as_deref();
+    let name = claim.0.metadata.name.as_deref().unwrap_or_default();
+    let mut result = pods
+        .filter(|pod| pod.0.metadata.namespace.as_deref() == namespace)
+        .filter(|pod| {
+            let pod_name = pod.0.metadata.name.as_deref().unwrap_or_default();
+            pod.0
+                .spec
+                .volumes
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .any(|volume| match volume.persistent_volume_claim.as_ref() {
+                    Some(source) => source.claim_name == name,
+                    None => {
+                        volume.ephemeral.is_some()
+                            && format!("{}-{}", pod_name, volume.name) == name
+                    }
+                })
+        })
+        .cloned()
+        .collect::<Vec<Rc<PodObject>>>();
+    result.sort_by(|a, b| a.0.metadata.name.cmp(&b.0.metadata.name));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_summary() {
+        let body = r#"{
+            "node": {"nodeName": "node-1"},
+            "pods": [
+                {
+                    "podRef": {"name": "db-0", "namespace": "default"},
+                    "volume": [
+                        {"name": "data", "usedBytes": 750, "availableBytes": 250,
+                         "capacityBytes": 1000, "pvcRef": {"name": "data-db-0", "namespace": "default"}},
+                        {"name": "tmp", "usedBytes": 10}
+                    ]
+                },
+                {"podRef": {"name": "web", "namespace": "default"}}
+            ]
+        }"#;
+        let stats = parse_summary(body).unwrap();
+        assert_eq!(1, stats.len());
+        let data = stats["default/data-db-0"];
+        assert_eq!(
+            VolumeStats {
+                used: 750,
+                available: 250,
+                capacity: 1000
+            },
+            data
+        );
+        assert_eq!(75.0, data.percent_used());
+        assert_eq!(0.0, VolumeStats::default().percent_used());
+        assert!(parse_summary("not json").is_err());
+        assert_eq!(
+            "RWO,RWX",
+            access_modes(Some(&vec![
+                "ReadWriteOnce".to_string(),
+                "ReadWriteMany".to_string()
+            ]))
+        );
+    }
+}
@@ -1,6 +1,33 @@
+use chrono::Utc;
 use color_eyre::eyre::Result;
-use kube::Client;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::Time;
+use kube::{config::KubeConfigOptions, Client, Config};
 
-pub async fn default_kubernetes_client() -> Result<Client> {
-    Ok(Client::try_default().await.unwrap())
+// client of the given kubeconfig context, None infers the config like kubectl, e.g. in a pod
+pub async fn default_kubernetes_client(context: Option<&str>) -> Result<Client> {
+    let config = match context {
+        Some(context) => {
+            let options = KubeConfigOptions {
+                context: Some(context.to_string()),
+                ..Default::default()
+            };
+            Config::from_kubeconfig(&options).await?
+        }
+        None => Config::infer().await?,
+    };
+    Ok(Client::try_from(config)?)
+}
+
+// age formats like kubectl, 45s 12m 5h 3d
+pub fn age(time: Option<&Time>) -> String {
+    let Some(time) = time else {
+        return "<unknown>".to_string();
+    };
+    let seconds = (Utc::now() - time.0).num_seconds().max(0);
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m", seconds / 60),
+        3600..=86399 => format!("{}h", seconds / 3600),
+        _ => format!("{}d", seconds / 86400),
+    }
 }
@@ -8,12 +8,15 @@ type Indices<P, U> = HashMap<Rc<str>, HashMap<Rc<str>, Rc<RtObject<P, U>>>>;
 
 pub struct StoreIndex<P: Clone, U: Clone> {
     index: Indices<P, U>,
+    // bumped on every change, caches built from the store compare it to skip a rebuild
+    generation: u64,
 }
 
 impl<P: Clone, U: Clone> Default for StoreIndex<P, U> {
     fn default() -> Self {
         StoreIndex {
             index: HashMap::new(),
+            generation: 0,
         }
     }
 }
@@ -22,6 +25,7 @@ impl<P: Clone, U: Clone> StoreIndex<P, U> {
     pub fn new() -> Self {
         StoreIndex {
             index: HashMap::new(),
+            generation: 0,
         }
     }
 
@@ -30,6 +34,7 @@ impl<P: Clone, U: Clone> StoreIndex<P, U> {
     }
 
     pub fn delete(&mut self, namespace: &str, name: &str) -> Result<()> {
+        self.generation += 1;
         if self.index.get(namespace).is_none() {
             self.index.remove(namespace);
             return Ok(());
@@ -43,6 +48,7 @@ impl<P: Clone, U: Clone> StoreIndex<P, U> {
         Ok(())
     }
     pub fn update(&mut self, obj: RtObject<P, U>) -> Result<()> {
+        self.generation += 1;
         let namespace: Rc<str> = if let Some(namespace) = obj.0.metadata.namespace.as_deref() {
             Rc::from(namespace)
         } else {
@@ -58,6 +64,20 @@ impl<P: Clone, U: Clone> StoreIndex<P, U> {
         Ok(())
     }
 
+    pub fn get(&self, namespace: &str, name: &str) -> Option<Rc<RtObject<P, U>>> {
+        self.index.get(namespace)?.get(name).cloned()
+    }
+
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    // every object of every namespace
+    pub fn iter(&self) -> impl Iterator<Item = &Rc<RtObject<P, U>>> {
+        self.index.values().flat_map(|store| store.values())
+    }
+
     pub fn list(&self, namespace: &str) -> Vec<Rc<str>> {
         let mut result = Vec::<Rc<str>>::new();
         if namespace.eq("all") {
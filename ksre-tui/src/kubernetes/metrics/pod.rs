@@ -1,7 +1,8 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use kube::{api::ListParams, core::ObjectMeta, Api, Client};
+use tokio::sync::mpsc;
 
 #[derive(serde::Deserialize, Clone, Debug)]
 pub struct PodMetricsContainer {
@@ -53,8 +54,12 @@ pub struct MetricClient {
 
 // PodMetricsApi[#TODO] (should add some comments)
 impl MetricClient {
-    pub fn new(kube_client: Client) -> MetricClient {
-        let api = Api::<PodMetrics>::all(kube_client.clone());
+    // metrics of one namespace only when the user may not list every namespace
+    pub fn new(kube_client: Client, namespace: Option<&str>) -> MetricClient {
+        let api = match namespace {
+            Some(namespace) => Api::<PodMetrics>::namespaced(kube_client.clone(), namespace),
+            None => Api::<PodMetrics>::all(kube_client.clone()),
+        };
         MetricClient {
             kube_client,
             namespaced_api: HashMap::new(),
@@ -81,12 +86,25 @@ impl MetricClient {
             }
         }
     }
-    #[allow(dead_code)]
     pub async fn list(&mut self) -> Vec<PodMetrics> {
-        self.list_api
-            .list(&ListParams::default())
-            .await
-            .map(|x| x.items)
-            .unwrap_or_default()
+        match self.list_api.list(&ListParams::default()).await {
+            Ok(metrics) => metrics.items,
+            Err(err) => {
+                tracing::warn!("list pod metrics failed: {}", err);
+                Vec::new()
+            }
+        }
+    }
+
+    // list all pod metrics every period until the receiver is gone, metrics-server
+    // scrapes every 15s by default so polling faster gives nothing new
+    pub async fn poll(mut self, tx: mpsc::Sender<Vec<PodMetrics>>, period: Duration) {
+        let mut interval = tokio::time::interval(period);
+        loop {
+            interval.tick().await;
+            if tx.send(self.list().await).await.is_err() {
+                break;
+            }
+        }
     }
 }
@@ -4,4 +4,5 @@ pub(crate) mod api;
 pub(crate) mod helper;
 pub(crate) mod indexer;
 pub(crate) mod metrics;
+pub(crate) mod owner;
 pub(crate) mod reflector;
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, HashSet};
+
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+
+const REVISION_ANNOTATION: &str = "deployment.kubernetes.io/revision";
+
+// Health is the pod rollup of a node, a pod counts itself
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Health {
+    pub ready: usize,
+    pub total: usize,
+    pub restarts: i32,
+}
+
+impl Health {
+    #[inline]
+    fn add(&mut self, other: Health) {
+        self.ready += other.ready;
+        self.total += other.total;
+        self.restarts += other.restarts;
+    }
+}
+
+// TreeEntry is one watched object handed to OwnerTree::build, only pods carry health
+pub struct TreeEntry<'a> {
+    pub kind: &'static str,
+    pub meta: &'a ObjectMeta,
+    pub health: Option<Health>,
+}
+
+pub struct OwnerNode {
+    pub uid: String,
+    pub kind: &'static str,
+    pub name: String,
+    pub owner: Option<String>,
+    pub children: Vec<String>,
+    pub health: Health,
+    // rollout generation of replicasets
+    pub revision: Option<String>,
+    pub depth: usize,
+}
+
+// OwnerTree links objects by metadata.ownerReferences, owners that are not watched make roots
+#[derive(Default)]
+pub struct OwnerTree {
+    nodes: HashMap<String, OwnerNode>,
+    roots: Vec<String>,
+}
+
+impl OwnerTree {
+    pub fn build<'a>(entries: impl Iterator<Item = TreeEntry<'a>>) -> Self {
+        let mut nodes = HashMap::new();
+        let mut owners = Vec::new();
+        for entry in entries {
+            let Some(uid) = entry.meta.uid.clone() else {
+                continue;
+            };
+            let owner = entry.meta.owner_references.as_ref().and_then(|refs| {
+                refs.iter()
+                    .find(|x| x.controller.unwrap_or(false))
+                    .or_else(|| refs.first())
+                    .map(|x| x.uid.clone())
+            });
+            if let Some(owner) = owner {
+                owners.push((uid.clone(), owner));
+            }
+            let revision = entry
+                .meta
+                .annotations
+                .as_ref()
+                .and_then(|x| x.get(REVISION_ANNOTATION).cloned());
+            nodes.insert(
+                uid.clone(),
+                OwnerNode {
+                    uid,
+                    kind: entry.kind,
+                    name: entry.meta.name.clone().unwrap_or_default(),
+                    owner: None,
+                    children: Vec::new(),
+                    health: entry.health.unwrap_or_default(),
+                    revision,
+                    depth: 0,
+                },
+            );
+        }
+
+        for (uid, owner) in owners {
+            if let Some(parent) = nodes.get_mut(&owner) {
+                parent.children.push(uid.clone());
+                nodes.get_mut(&uid).unwrap().owner = Some(owner);
+            }
+        }
+
+        let mut roots = nodes
+            .values()
+            .filter(|node| node.owner.is_none())
+            .map(|node| node.uid.clone())
+            .collect::<Vec<String>>();
+        let mut tree = OwnerTree {
+            nodes,
+            roots: Vec::new(),
+        };
+        tree.sort(&mut roots);
+        for root in roots.iter() {
+            tree.rollup(root, 0);
+        }
+        tree.roots = roots;
+        tree
+    }
+
+    // kind then name, so deployments come before pods without owner
+    fn sort(&self, uids: &mut [String]) {
+        uids.sort_by(|a, b| {
+            let (a, b) = (&self.nodes[a], &self.nodes[b]);
+            (a.kind, &a.name).cmp(&(b.kind, &b.name))
+        });
+    }
+
+    fn rollup(&mut self, uid: &str, depth: usize) -> Health {
+        let mut children = std::mem::take(&mut self.nodes.get_mut(uid).unwrap().children);
+        self.sort(&mut children);
+        let mut health = self.nodes[uid].health;
+        for child in children.iter() {
+            health.add(self.rollup(child, depth + 1));
+        }
+        let node = self.nodes.get_mut(uid).unwrap();
+        node.children = children;
+        node.health = health;
+        node.depth = depth;
+        health
+    }
+
+    #[inline]
+    pub fn get(&self, uid: &str) -> Option<&OwnerNode> {
+        self.nodes.get(uid)
+    }
+
+    // rows in display order, children of collapsed nodes are hidden
+    pub fn visible(&self, collapsed: &HashSet<String>) -> Vec<String> {
+        let mut rows = Vec::with_capacity(self.nodes.len());
+        let mut stack = self.roots.iter().rev().collect::<Vec<&String>>();
+        while let Some(uid) = stack.pop() {
+            rows.push(uid.clone());
+            if !collapsed.contains(uid) {
+                stack.extend(self.nodes[uid].children.iter().rev());
+            }
+        }
+        rows
+    }
+
+    // owner chain from the direct owner to the root
+    pub fn ancestors(&self, uid: &str) -> Vec<String> {
+        let mut result = Vec::new();
+        let mut current = self.nodes.get(uid).and_then(|x| x.owner.clone());
+        while let Some(owner) = current {
+            current = self.nodes.get(&owner).and_then(|x| x.owner.clone());
+            result.push(owner);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::apimachinery::pkg::apis::meta::v1::OwnerReference;
+
+    use super::*;
+
+    fn meta(uid: &str, name: &str, owner: Option<&str>) -> ObjectMeta {
+        ObjectMeta {
+            uid: Some(uid.to_string()),
+            name: Some(name.to_string()),
+            owner_references: owner.map(|owner| {
+                vec![OwnerReference {
+                    uid: owner.to_string(),
+                    controller: Some(true),
+                    ..Default::default()
+                }]
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_owner_tree_rollup() {
+        let deploy = meta("d", "web", None);
+        let rs = meta("rs", "web-5d8f", Some("d"));
+        let ready = meta("p1", "web-5d8f-a", Some("rs"));
+        let crashing = meta("p2", "web-5d8f-b", Some("rs"));
+        let orphan = meta("p3", "debug", Some("gone"));
+        let pod_health = |ready, restarts| Health {
+            ready,
+            total: 1,
+            restarts,
+        };
+        let entries = vec![
+            TreeEntry {
+                kind: "Deployment",
+                meta: &deploy,
+                health: None,
+            },
+            TreeEntry {
+                kind: "ReplicaSet",
+                meta: &rs,
+                health: None,
+            },
+            TreeEntry {
+                kind: "Pod",
+                meta: &ready,
+                health: Some(pod_health(1, 0)),
+            },
+            TreeEntry {
+                kind: "Pod",
+                meta: &crashing,
+                health: Some(pod_health(0, 7)),
+            },
+            TreeEntry {
+                kind: "Pod",
+                meta: &orphan,
+                health: Some(pod_health(1, 0)),
+            },
+        ];
+        let tree = OwnerTree::build(entries.into_iter());
+
+        let health = tree.get("d").unwrap().health;
+        assert_eq!((1, 2, 7), (health.ready, health.total, health.restarts));
+        assert_eq!(
+            vec!["d", "rs", "p1", "p2", "p3"],
+            tree.visible(&HashSet::new())
+        );
+        let collapsed = HashSet::from(["rs".to_string()]);
+        assert_eq!(vec!["d", "rs", "p3"], tree.visible(&collapsed));
+        assert_eq!(vec!["rs", "d"], tree.ancestors("p2"));
+        assert_eq!(2, tree.get("p2").unwrap().depth);
+    }
+}
@@ -0,0 +1,90 @@
+use futures::{pin_mut, StreamExt};
+use kube::{
+    api::{DynamicObject, ListParams},
+    core::TypeMeta,
+    runtime::{watcher, watcher::Event},
+    Api, Client,
+};
+use serde_json::Value;
+use tokio::{sync::broadcast::Sender, task::JoinHandle};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    event::KubeEvent,
+    kubernetes::api::dynamic::{DynamicRtObject, ResourceKind},
+};
+
+pub type DynamicEvent = KubeEvent<Value, Value>;
+
+// DynamicReflector watches one kind chosen at runtime, objects are sent as RtObject<Value, Value>
+pub struct DynamicReflector {
+    pub kind: ResourceKind,
+    task: JoinHandle<()>,
+    cancellation_token: CancellationToken,
+}
+
+impl DynamicReflector {
+    pub fn new(client: Client, kind: ResourceKind, tx_event: Sender<DynamicEvent>) -> Self {
+        let api = Api::<DynamicObject>::all_with(client, &kind.resource);
+        let cancellation_token = CancellationToken::new();
+        let _cancellation_token = cancellation_token.clone();
+        let types = TypeMeta {
+            api_version: kind.resource.api_version.clone(),
+            kind: kind.resource.kind.clone(),
+        };
+        let into_object = move |mut obj: DynamicObject| -> DynamicRtObject {
+            // items of a list response carry no apiVersion and kind
+            obj.types = Some(types.clone());
+            obj.into()
+        };
+
+        let task = tokio::spawn(async move {
+            match api.list(&ListParams::default()).await {
+                Ok(objs) => {
+                    for obj in objs {
+                        let _ = tx_event.send(KubeEvent::OnAdd(into_object(obj)));
+                    }
+                }
+                Err(err) => tracing::warn!("list dynamic objects failed: {}", err),
+            }
+            let watch_event = watcher(api, watcher::Config::default()).fuse();
+            pin_mut!(watch_event);
+            loop {
+                tokio::select! {
+                    _ = _cancellation_token.cancelled() => break,
+                    event = watch_event.next() => {
+                        match event {
+                            Some(Ok(Event::Applied(obj))) => {
+                                let _ = tx_event.send(KubeEvent::OnAdd(into_object(obj)));
+                            }
+                            Some(Ok(Event::Deleted(obj))) => {
+                                let _ = tx_event.send(KubeEvent::OnDel(into_object(obj)));
+                            }
+                            // relists are ignored, objects were listed above
+                            Some(Ok(Event::Restarted(_))) => {}
+                            Some(Err(err)) => tracing::warn!("watch dynamic objects failed: {}", err),
+                            None => break,
+                        }
+                    }
+                }
+            }
+        });
+        Self {
+            kind,
+            task,
+            cancellation_token,
+        }
+    }
+
+    pub fn shutdown(&mut self) {
+        if !self.cancellation_token.is_cancelled() {
+            self.cancellation_token.cancel();
+        }
+    }
+}
+
+impl Drop for DynamicReflector {
+    fn drop(&mut self) {
+        self.shutdown()
+    }
+}
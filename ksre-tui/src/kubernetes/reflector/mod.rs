@@ -1 +1,239 @@
-pub mod pod;
+pub mod dynamic;
+
+use color_eyre::eyre::Result;
+use futures::{pin_mut, StreamExt};
+use k8s_openapi::api::{
+    apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+    autoscaling::v2::HorizontalPodAutoscaler,
+    batch::v1::{CronJob, Job},
+    core::v1::{ConfigMap, Node, PersistentVolume, PersistentVolumeClaim, Pod, Secret, Service},
+};
+use kube::{
+    api::ListParams,
+    runtime::{watcher, watcher::Event, WatchStreamExt},
+    Api, Client,
+};
+use tokio::{
+    sync::broadcast::{self, Receiver, Sender},
+    task::JoinHandle,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::{
+    event::KubeEvent,
+    kubernetes::api::{access::can_i, object::KubeObject},
+};
+
+type EventSender<K> = Sender<KubeEvent<<K as KubeObject>::Spec, <K as KubeObject>::Status>>;
+type EventReceiver<K> = Receiver<KubeEvent<<K as KubeObject>::Spec, <K as KubeObject>::Status>>;
+
+// Reflector list then watch one kind of resource, every change is broadcasted as KubeEvent
+pub struct Reflector<K: KubeObject> {
+    api: Api<K>,
+    task: JoinHandle<()>,
+    cancellation_token: CancellationToken,
+
+    tx_event: EventSender<K>,
+}
+
+impl<K: KubeObject> Reflector<K> {
+    pub fn new(client: Client) -> Result<(Self, EventReceiver<K>)> {
+        Self::with_api(Api::<K>::all(client))
+    }
+
+    pub fn with_api(api: Api<K>) -> Result<(Self, EventReceiver<K>)> {
+        let (mut reflector, rx_event) = Self::idle(api);
+        reflector.run()?;
+        Ok((reflector, rx_event))
+    }
+
+    // never lists nor watches, its receiver stays empty. for kinds the user may not watch
+    pub fn idle(api: Api<K>) -> (Self, EventReceiver<K>) {
+        let (tx_event, rx_event) = broadcast::channel(1024);
+        let reflector = Reflector {
+            api,
+            task: tokio::spawn(async {}),
+            cancellation_token: CancellationToken::new(),
+            tx_event,
+        };
+        (reflector, rx_event)
+    }
+
+    pub fn run(&mut self) -> Result<()> {
+        let api = self.api.clone();
+        let use_watchlist = std::env::var("WATCHLIST")
+            .map(|s| s == "1")
+            .unwrap_or(false);
+        let wc = if use_watchlist {
+            watcher::Config::default().streaming_lists()
+        } else {
+            watcher::Config::default()
+        };
+        let _cancellation_token = self.cancellation_token.clone();
+        let tx_event = self.tx_event.clone();
+
+        self.task = tokio::spawn(async move {
+            Reflector::list_all(&api, ListParams::default(), &tx_event).await;
+            let watch_event = watcher(api, wc).default_backoff().fuse();
+            pin_mut!(watch_event);
+            loop {
+                tokio::select! {
+                    _ = _cancellation_token.cancelled() => break,
+                    event = watch_event.next() => {
+                        match event {
+                            Some(Ok(watch_event)) => {
+                                Reflector::dispatch_events(&tx_event, watch_event).unwrap();
+                            }
+                            // the watcher backs off and retries by itself
+                            Some(Err(err)) => tracing::warn!("watch {} failed: {}", K::plural(&()), err),
+                            None => break,
+                        }
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    fn dispatch_events(sender: &EventSender<K>, watch_event: Event<K>) -> Result<()> {
+        match watch_event {
+            Event::Applied(applied) => {
+                sender
+                    .send(KubeEvent::OnAdd(applied.into_object()))
+                    .unwrap();
+            }
+            Event::Deleted(deleted) => {
+                sender
+                    .send(KubeEvent::OnDel(deleted.into_object()))
+                    .unwrap();
+            }
+            Event::Restarted(_) => {
+                // the objects thart restart ignored
+                tracing::info!("watch {} restarted", K::plural(&()));
+            }
+        }
+        Ok(())
+    }
+
+    async fn list_all(api: &Api<K>, list_opt: ListParams, sender: &EventSender<K>) {
+        // a forbidden kind (e.g. secrets without rbac) just stays empty
+        let objs = match api.list(&list_opt).await {
+            Ok(objs) => objs,
+            Err(err) => {
+                tracing::warn!("list {} failed: {}", K::plural(&()), err);
+                return;
+            }
+        };
+        for obj in objs {
+            sender.send(KubeEvent::OnAdd(obj.into_object())).unwrap();
+        }
+    }
+
+    pub fn shutdown(&mut self) -> Result<()> {
+        if !self.cancellation_token.is_cancelled() {
+            self.cancellation_token.cancel();
+        }
+        Ok(())
+    }
+}
+
+impl<K: KubeObject> Drop for Reflector<K> {
+    fn drop(&mut self) {
+        self.shutdown().unwrap()
+    }
+}
+
+// a watcher of a forbidden kind retries forever and fills the log with the same error.
+// a failed review lets it try, the api server has the last word
+async fn can_watch<K: KubeObject>(client: &Client) -> bool {
+    for verb in ["list", "watch"] {
+        match can_i(client.clone(), verb, &K::group(&()), &K::plural(&()), None).await {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::info!("{} {} is forbidden, not watched", verb, K::plural(&()));
+                return false;
+            }
+            Err(err) => {
+                tracing::warn!("access review of {} failed: {}", K::plural(&()), err);
+                return true;
+            }
+        }
+    }
+    true
+}
+
+// every resource kind ksre watches, one reflector and one event receiver per kind.
+// namespaced kinds are watched in one namespace only when a namespace is given,
+// cluster-scoped kinds only when the user may list and watch them
+macro_rules! cluster_reflectors {
+    (
+        cluster { $($cluster_field:ident: $cluster_kind:ty),* $(,)? }
+        namespaced { $($field:ident: $kind:ty),* $(,)? }
+    ) => {
+        pub struct ClusterReflectors {
+            $($cluster_field: Reflector<$cluster_kind>,)*
+            $($field: Reflector<$kind>,)*
+        }
+
+        pub struct ClusterEvents {
+            $(pub $cluster_field: EventReceiver<$cluster_kind>,)*
+            $(pub $field: EventReceiver<$kind>,)*
+        }
+
+        impl ClusterReflectors {
+            pub async fn new(
+                client: Client,
+                namespace: Option<&str>,
+            ) -> Result<(Self, ClusterEvents)> {
+                $(let $cluster_field = if can_watch::<$cluster_kind>(&client).await {
+                    Reflector::<$cluster_kind>::new(client.clone())?
+                } else {
+                    Reflector::<$cluster_kind>::idle(Api::all(client.clone()))
+                };)*
+                $(let $field = match namespace {
+                    Some(namespace) => {
+                        Reflector::<$kind>::with_api(Api::namespaced(client.clone(), namespace))?
+                    }
+                    None => Reflector::<$kind>::new(client.clone())?,
+                };)*
+                Ok((
+                    Self {
+                        $($cluster_field: $cluster_field.0,)*
+                        $($field: $field.0,)*
+                    },
+                    ClusterEvents {
+                        $($cluster_field: $cluster_field.1,)*
+                        $($field: $field.1,)*
+                    },
+                ))
+            }
+
+            pub fn shutdown(&mut self) -> Result<()> {
+                $(self.$cluster_field.shutdown()?;)*
+                $(self.$field.shutdown()?;)*
+                Ok(())
+            }
+        }
+    };
+}
+
+cluster_reflectors! {
+    cluster {
+        node: Node,
+        pv: PersistentVolume,
+    }
+    namespaced {
+        pod: Pod,
+        service: Service,
+        configmap: ConfigMap,
+        secret: Secret,
+        deployment: Deployment,
+        replicaset: ReplicaSet,
+        statefulset: StatefulSet,
+        daemonset: DaemonSet,
+        job: Job,
+        cronjob: CronJob,
+        pvc: PersistentVolumeClaim,
+        hpa: HorizontalPodAutoscaler,
+    }
+}
@@ -1,8 +1,12 @@
 pub(crate) mod app;
 pub(crate) mod event;
 pub(crate) mod kubernetes;
+pub(crate) mod logging;
 pub(crate) mod tui;
 
-pub use app::core::App;
-pub use kubernetes::{helper::default_kubernetes_client, reflector::pod::PodReflector};
+pub use app::{core::App, session::startup_context};
+pub use kubernetes::{
+    api::access::watch_namespace, helper::default_kubernetes_client, reflector::ClusterReflectors,
+};
+pub use logging::init_logging;
 pub use tui::Tui;
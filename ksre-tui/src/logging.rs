@@ -0,0 +1,119 @@
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use chrono::{DateTime, Local};
+use color_eyre::eyre::Result;
+use lazy_static::lazy_static;
+use tracing::{
+    field::{Field, Visit},
+    level_filters::LevelFilter,
+    Event, Level, Subscriber,
+};
+use tracing_appender::{non_blocking::WorkerGuard, rolling};
+use tracing_subscriber::{layer::Context, prelude::*, Layer};
+
+use crate::app::session::state_dir;
+
+// records kept for the debug pane
+const MAX_RECORDS: usize = 512;
+const LOG_FILE: &str = "ksre.log";
+
+lazy_static! {
+    static ref RECORDS: Mutex<VecDeque<Record>> = Mutex::new(VecDeque::new());
+}
+
+#[derive(Clone, Debug)]
+pub struct Record {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+// KSRE_LOG_FILE, KSRE_LOG_LEVEL (error..trace, default info) and
+// KSRE_LOG_ROTATION (daily, hourly or never, default daily) configure the log file.
+// the guard flushes the file when dropped, keep it until exit
+pub fn init_logging() -> Result<WorkerGuard> {
+    let level = std::env::var("KSRE_LOG_LEVEL")
+        .ok()
+        .and_then(|x| x.parse::<LevelFilter>().ok())
+        .unwrap_or(LevelFilter::INFO);
+    let path = std::env::var_os("KSRE_LOG_FILE")
+        .map(PathBuf::from)
+        // next to the session file
+        .or_else(|| Some(state_dir()?.join(LOG_FILE)))
+        .unwrap_or(PathBuf::from(LOG_FILE));
+    let dir = path.parent().unwrap_or(Path::new("."));
+    std::fs::create_dir_all(dir)?;
+    let file_name = path.file_name().unwrap_or(LOG_FILE.as_ref());
+    let appender = match std::env::var("KSRE_LOG_ROTATION").as_deref() {
+        Ok("hourly") => rolling::hourly(dir, file_name),
+        Ok("never") => rolling::never(dir, file_name),
+        _ => rolling::daily(dir, file_name),
+    };
+    let (writer, guard) = tracing_appender::non_blocking(appender);
+    tracing_subscriber::registry()
+        .with(
+            tracing_subscriber::fmt::layer()
+                .with_writer(writer)
+                .with_ansi(false)
+                .with_filter(level),
+        )
+        .with(DebugLayer.with_filter(level))
+        .try_init()?;
+    Ok(guard)
+}
+
+// the latest records, newest first
+pub fn recent() -> Vec<Record> {
+    RECORDS
+        .lock()
+        .map(|records| records.iter().rev().cloned().collect())
+        .unwrap_or_default()
+}
+
+pub fn clear() {
+    if let Ok(mut records) = RECORDS.lock() {
+        records.clear();
+    }
+}
+
+// DebugLayer keeps the last events in memory for the debug pane
+struct DebugLayer;
+
+impl<S: Subscriber> Layer<S> for DebugLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let record = Record {
+            time: Local::now(),
+            level: *event.metadata().level(),
+            target: event.metadata().target().to_string(),
+            message: visitor.0,
+        };
+        if let Ok(mut records) = RECORDS.lock() {
+            if records.len() == MAX_RECORDS {
+                records.pop_front();
+            }
+            records.push_back(record);
+        }
+    }
+}
+
+// message first, the other fields appended as key=value
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, "{:?}", value);
+        } else {
+            let _ = write!(self.0, " {}={:?}", field.name(), value);
+        }
+    }
+}
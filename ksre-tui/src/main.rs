@@ -3,21 +3,25 @@ use libksre::*;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let file_appender = tracing_appender::rolling::daily("./", "prefix.log");
-    let (non_blocking, _guard) = tracing_appender::non_blocking(file_appender);
-    tracing_subscriber::fmt().with_writer(non_blocking).init();
+    let _guard = init_logging()?;
 
-    let kube_client = default_kubernetes_client().await?;
-    // new instance pod informer, pod_informer will start new coroutine to dispath event from
+    let context = startup_context();
+    let kube_client = default_kubernetes_client(context.as_deref()).await?;
+    // with namespaced rbac everything is watched in the namespace of the kubeconfig context
+    let namespace = watch_namespace(kube_client.clone()).await;
+    // new instance reflectors, every reflector will start new coroutine to dispath event from
     // apiserver
-    let (mut pod_informer, rx_pod_event) = PodReflector::new(kube_client.clone()).unwrap();
+    let (mut reflectors, cluster_events) =
+        ClusterReflectors::new(kube_client.clone(), namespace.as_deref())
+            .await
+            .unwrap();
     // new instance tui, tui will start new coroutine to dispatch event from keyboard
     let tui = Tui::new()?;
     // new instance app
-    let mut app = App::new(tui, rx_pod_event, kube_client.clone());
+    let mut app = App::new(tui, cluster_events, kube_client.clone(), namespace, context);
 
     app.run().await.unwrap();
-    pod_informer.shutdown()?;
+    reflectors.shutdown()?;
 
     Ok(())
 }
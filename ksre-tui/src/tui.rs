@@ -2,7 +2,10 @@ use color_eyre::Result;
 use crossterm::terminal::LeaveAlternateScreen;
 use crossterm::{
     cursor,
-    event::{Event as CrosstermEvent, KeyEventKind},
+    event::{
+        DisableMouseCapture, EnableMouseCapture, Event as CrosstermEvent, KeyEventKind,
+        MouseEventKind,
+    },
     terminal::EnterAlternateScreen,
 };
 use futures::{FutureExt, StreamExt};
@@ -80,12 +83,15 @@ impl Tui {
                     _ = _cancellation_token.cancelled() => break,
                     maybe_event = crossterm_event => {
                         match maybe_event {
-                            Some(Ok(event)) => {
-                                if let CrosstermEvent::Key(key) = event {
-                                    if key.kind == KeyEventKind::Press {
-                                        _tx_event.send(Event::Key(key.into())).await.unwrap();
-                                    }
+                            Some(Ok(event)) => match event {
+                                CrosstermEvent::Key(key) if key.kind == KeyEventKind::Press => {
+                                    _tx_event.send(Event::Key(key.into())).await.unwrap();
                                 }
+                                // plain cursor moves are too noisy, only clicks/drags/wheel are forwarded
+                                CrosstermEvent::Mouse(mouse) if mouse.kind != MouseEventKind::Moved => {
+                                    _tx_event.send(Event::Mouse(mouse)).await.unwrap();
+                                }
+                                _ => {}
                             },
                             Some(Err(_)) => {_tx_event.send(Event::Error).await.unwrap();},
                             None => {_tx_event.send(Event::Error).await.unwrap();}
@@ -101,7 +107,12 @@ impl Tui {
 
     fn enter(&mut self) -> Result<()> {
         crossterm::terminal::enable_raw_mode()?;
-        crossterm::execute!(std::io::stderr(), EnterAlternateScreen, cursor::Hide)?;
+        crossterm::execute!(
+            std::io::stderr(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            cursor::Hide
+        )?;
         self.terminal.clear()?;
         Ok(())
     }
@@ -114,7 +125,12 @@ impl Tui {
             crossterm::terminal::disable_raw_mode()?;
         }
         if !DEBUG {
-            crossterm::execute!(std::io::stderr(), LeaveAlternateScreen, cursor::Show)?;
+            crossterm::execute!(
+                std::io::stderr(),
+                LeaveAlternateScreen,
+                DisableMouseCapture,
+                cursor::Show
+            )?;
         }
 
         Ok(())
@@ -149,7 +165,13 @@ impl Drop for Tui {
 fn initialize_panic_handler() {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
-        crossterm::execute!(std::io::stderr(), crossterm::terminal::LeaveAlternateScreen).unwrap();
+        tracing::error!("{}", panic_info);
+        crossterm::execute!(
+            std::io::stderr(),
+            crossterm::terminal::LeaveAlternateScreen,
+            DisableMouseCapture
+        )
+        .unwrap();
         crossterm::terminal::disable_raw_mode().unwrap();
         original_hook(panic_info);
     }));